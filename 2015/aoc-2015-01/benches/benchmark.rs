@@ -7,7 +7,7 @@ criterion_group!(benches, part_one, part_two);
 criterion_main!(benches);
 
 fn part_one(c: &mut Criterion) {
-    let input = load_input(default_test_input(DAY));
+    let input = load_input(default_test_input!(DAY));
     let input = input.trim().as_bytes();
 
     c.bench_with_input(BenchmarkId::new("day-01-p01", ""), &input, |b, i| {
@@ -16,7 +16,7 @@ fn part_one(c: &mut Criterion) {
 }
 
 fn part_two(c: &mut Criterion) {
-    let input = load_input(default_test_input(DAY));
+    let input = load_input(default_test_input!(DAY));
     let input = input.trim().as_bytes();
 
     c.bench_with_input(BenchmarkId::new("day-01-p02", ""), &input, |b, i| {
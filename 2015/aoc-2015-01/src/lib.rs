@@ -28,7 +28,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let input = input.trim().as_bytes();
 
         let solution = part_one::solve(input);
@@ -37,7 +37,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let input = input.trim().as_bytes();
 
         let solution = part_two::solve(input);
@@ -78,7 +78,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let data = parse_input(&input);
 
         let solution = part_one::solve(&data);
@@ -87,7 +87,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let data = parse_input(&input);
 
         let solution = part_two::solve(&data);
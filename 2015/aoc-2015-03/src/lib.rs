@@ -59,7 +59,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let input = parse_input(&input);
         let solution = part_one::solve(&input);
         assert_eq!(2565, solution);
@@ -67,7 +67,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let input = parse_input(&input);
         let solution = part_two::solve(&input);
         assert_eq!(2639, solution);
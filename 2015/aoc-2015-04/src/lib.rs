@@ -1,9 +1,8 @@
-use std::fmt::Write;
 use std::ops::Add;
 use std::path::Path;
 use std::time::Duration;
 
-use md5::{compute, Digest};
+use aoc_core::md5::{leading_zero_nibbles, matches, Digest};
 
 use aoc_2015_common::input::load_input;
 use aoc_2015_common::timing::measure;
@@ -28,64 +27,51 @@ pub fn solve_p2(input: &str) -> Option<u32> {
 }
 
 fn solve(input: &str, check_digest: fn(&Digest) -> bool) -> Option<u32> {
-    let mut buffer = String::with_capacity(input.len() + 6);
-    write!(&mut buffer, "{}", input).unwrap();
-
-    for key in 1..=u32::max_value() {
-        buffer.truncate(input.len());
-        write!(&mut buffer, "{}", key).unwrap();
-
-        let digest = compute(&buffer);
-        if check_digest(&digest) {
-            return Some(key);
-        }
-    }
-
-    None
+    matches(input, 1, |_, digest| check_digest(digest).then_some(()))
+        .map(|(key, _)| key)
+        .take_while(|&key| key <= u32::MAX as u64)
+        .next()
+        .map(|key| key as u32)
 }
 
 fn is_part_one_digest(digest: &Digest) -> bool {
-    let be_bytes = [digest.0[0], digest.0[1], digest.0[2], digest.0[3]];
-
-    let num = u32::from_be_bytes(be_bytes);
-    num <= 0x00_00_0F_FF
+    leading_zero_nibbles(digest) >= 5
 }
 
 fn is_part_two_digest(digest: &Digest) -> bool {
-    let be_bytes = [digest.0[0], digest.0[1], digest.0[2], digest.0[3]];
-
-    let num = u32::from_be_bytes(be_bytes);
-    num <= 0x00_00_00_FF
+    leading_zero_nibbles(digest) >= 6
 }
 
 #[cfg(test)]
 mod tests {
     use aoc_2015_common::input::default_test_input;
 
+    use aoc_core::md5::Prefix;
+
     use super::*;
 
     #[test]
     fn test_is_part_one_digest_ok() {
-        let digest = compute("abcdef609043");
+        let digest = Prefix::new("abcdef").hash_counter(609043);
         assert!(is_part_one_digest(&digest));
     }
 
     #[test]
     fn test_is_part_two_digest_ok() {
-        let digest = compute("bgvyzdsv1038736");
+        let digest = Prefix::new("bgvyzdsv").hash_counter(1038736);
         assert!(is_part_two_digest(&digest));
     }
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY)).trim().to_owned();
+        let input = load_input(default_test_input!(DAY)).trim().to_owned();
         let solution = solve_p1(&input);
         assert_eq!(Some(254575), solution);
     }
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY)).trim().to_owned();
+        let input = load_input(default_test_input!(DAY)).trim().to_owned();
         let solution = solve_p2(&input);
         assert_eq!(Some(1038736), solution);
     }
@@ -116,7 +116,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let instructions = parse_input(&input);
         let solution = part_one::solve(&instructions);
         assert_eq!(543903, solution);
@@ -124,7 +124,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let instructions = parse_input(&input);
         let solution = part_two::solve(&instructions);
         assert_eq!(14687245, solution);
@@ -7,7 +7,7 @@ criterion_group!(benches, part_one);
 criterion_main!(benches);
 
 fn part_one(c: &mut Criterion) {
-    let input = load_input(default_test_input(DAY));
+    let input = load_input(default_test_input!(DAY));
     let input = parse_input(&input);
 
     c.bench_with_input(BenchmarkId::new("day-07", ""), &input, |b, i| {
@@ -240,7 +240,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let wires = parse_input(&input);
 
         let answer = solve(&wires);
@@ -249,7 +249,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let mut wires = parse_input(&input);
 
         let wire_override = solve(&wires);
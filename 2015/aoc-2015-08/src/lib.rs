@@ -33,14 +33,14 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let count = solve_part_one(&input);
         assert_eq!(1350, count);
     }
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let count = solve_part_two(&input);
         assert_eq!(2085, count);
     }
@@ -7,7 +7,7 @@ criterion_group!(benches, input_parsing, bench_solver);
 criterion_main!(benches);
 
 fn input_parsing(c: &mut Criterion) {
-    let input = load_input(default_test_input(DAY));
+    let input = load_input(default_test_input!(DAY));
 
     c.bench_with_input(BenchmarkId::new("day-09", ""), &input, |b, i| {
         b.iter(|| black_box(parse_input(i)))
@@ -15,7 +15,7 @@ fn input_parsing(c: &mut Criterion) {
 }
 
 fn bench_solver(c: &mut Criterion) {
-    let input = load_input(default_test_input(DAY));
+    let input = load_input(default_test_input!(DAY));
     let input = parse_input(&input);
 
     c.bench_with_input(BenchmarkId::new("day-09: O(n!)", ""), &input, |b, i| {
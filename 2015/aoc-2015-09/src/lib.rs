@@ -97,7 +97,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let routes = parse_input(&input);
         let answer = solve_part_one(&routes);
 
@@ -106,7 +106,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let routes = parse_input(&input);
         let answer = solve_part_two(&routes);
 
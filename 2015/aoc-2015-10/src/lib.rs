@@ -102,7 +102,7 @@ mod tests {
 
     #[test]
     pub fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let input = parse_input(&input);
 
         let answer = look_and_say(input, 40);
@@ -111,7 +111,7 @@ mod tests {
 
     #[test]
     pub fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let input = parse_input(&input);
 
         let answer = look_and_say(input, 50);
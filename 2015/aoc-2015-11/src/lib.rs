@@ -143,7 +143,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let pwd = solve(&parse_input(&input));
         assert_eq!("vzbxxyzz", pwd);
     }
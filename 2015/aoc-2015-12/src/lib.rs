@@ -34,14 +34,14 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let answer = solve_p1_regex(&input);
         assert_eq!(191164, answer);
     }
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let answer = solve_p2_serde(&input);
         assert_eq!(87842, answer);
     }
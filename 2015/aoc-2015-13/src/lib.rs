@@ -142,7 +142,7 @@ mod tests {
     }
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let (knights, matrix) = parse_input(&input);
 
         let answer = solve(knights, &matrix);
@@ -151,7 +151,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let (knights, matrix) = parse_input(&input);
         let matrix = to_part_2_input(knights, &matrix);
         let answer = solve(knights + 1, &matrix);
@@ -72,7 +72,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let parsed = parse_input(&input);
 
         let answer = solve_part_one(&parsed);
@@ -81,7 +81,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let parsed = parse_input(&input);
 
         let answer = solve_part_two(&parsed);
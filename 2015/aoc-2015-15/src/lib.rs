@@ -145,7 +145,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let parsed = parse_input(&input);
         let answer = solve(&parsed, AMOUNT, P1_CALORIES);
         assert_eq!(222870, answer);
@@ -153,7 +153,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let parsed = parse_input(&input);
         let answer = solve(&parsed, AMOUNT, P2_CALORIES);
         assert_eq!(117936, answer);
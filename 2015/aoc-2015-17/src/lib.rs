@@ -84,7 +84,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let parsed = parse_line_delimited(&input);
         let answer = solve_part_one(&parsed, TARGET);
         assert_eq!(654, answer);
@@ -92,7 +92,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let parsed = parse_line_delimited(&input);
         let answer = solve_part_two(&parsed, TARGET);
         assert_eq!(57, answer);
@@ -152,7 +152,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let parsed = parse_input(&input, WIDTH);
         let answer = solve_part_one(&parsed, WIDTH);
 
@@ -161,7 +161,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let parsed = parse_input(&input, WIDTH);
         let answer = solve_part_two(&parsed, WIDTH);
 
@@ -79,7 +79,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let (molecule, replacements) = parse_input(&input);
 
         let answer = solve_part_one(&molecule, &replacements);
@@ -88,7 +88,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let (molecule, replacements) = parse_input(&input);
 
         let answer = solve_part_two(&molecule, &replacements);
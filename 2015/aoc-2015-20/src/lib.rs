@@ -61,7 +61,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let parsed = input.trim().parse().unwrap();
         let answer = solve_part_one(parsed);
         assert_eq!(831600, answer);
@@ -69,7 +69,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let parsed = input.trim().parse().unwrap();
         let answer = solve_part_two(parsed);
         assert_eq!(884520, answer);
@@ -172,7 +172,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let boss = parse_input(&input);
         let answer = solve_part_one(boss);
         assert_eq!(111, answer);
@@ -180,7 +180,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let boss = parse_input(&input);
         let answer = solve_part_two(boss);
         assert_eq!(188, answer);
@@ -1,3 +1,4 @@
+use std::fmt::{Display, Formatter};
 use std::path::Path;
 use std::time::Duration;
 
@@ -6,11 +7,11 @@ use aoc_2015_common::timing::measure;
 
 const DAY: usize = 22;
 const SPELLS: &[Spell] = &[
-    Spell::new(53, 4, 0, 0, 0, 0, 0),
-    Spell::new(73, 2, 0, 2, 0, 0, 0),
-    Spell::new(113, 0, 7, 0, 0, 0, 6),
-    Spell::new(173, 0, 0, 0, 3, 0, 6),
-    Spell::new(229, 0, 0, 0, 0, 101, 5),
+    Spell::new("Magic Missile", 53, 4, 0, 0, 0, 0, 0),
+    Spell::new("Drain", 73, 2, 0, 2, 0, 0, 0),
+    Spell::new("Shield", 113, 0, 7, 0, 0, 0, 6),
+    Spell::new("Poison", 173, 0, 0, 0, 3, 0, 6),
+    Spell::new("Recharge", 229, 0, 0, 0, 0, 101, 5),
 ];
 
 pub fn demo<P: AsRef<Path>>(path: P) -> Duration {
@@ -49,6 +50,7 @@ impl Mage {
 
 #[derive(Debug, Copy, Clone)]
 struct Spell {
+    name: &'static str,
     cost: u16,
     damage: u16,
     armor: u16,
@@ -59,7 +61,9 @@ struct Spell {
 }
 
 impl Spell {
+    #[allow(clippy::too_many_arguments)]
     const fn new(
+        name: &'static str,
         cost: u16,
         damage: u16,
         armor: u16,
@@ -69,6 +73,7 @@ impl Spell {
         duration: u8,
     ) -> Self {
         Spell {
+            name,
             cost,
             damage,
             armor,
@@ -126,22 +131,22 @@ fn solve_part_two(boss: Boss) -> Option<u16> {
 }
 
 fn solve(boss: Boss, handicap: u16) -> Option<u16> {
-    let mut answer = None;
-
-    for i in 0..SPELLS.len() {
-        if let Some(cost) = try_spell(i, State::new(boss), handicap) {
-            answer = answer.map_or(Some(cost), |answer: u16| Some(answer.min(cost)));
-        }
-    }
+    best_spells(State::new(boss), handicap).map(|(cost, _)| cost)
+}
 
-    answer
+enum Round {
+    /// The mage died or could not cast the spell
+    Lost,
+    Won(State),
+    Ongoing(State),
 }
 
-fn try_spell(idx: usize, mut state: State, handicap: u16) -> Option<u16> {
+// The mage's turn casting the spell `idx`, followed by the boss' turn
+fn play_round(idx: usize, mut state: State, handicap: u16) -> Round {
     // Handle "part 2"
     state.mage.hp = state.mage.hp.saturating_sub(handicap);
     if state.mage.hp == 0 {
-        return None;
+        return Round::Lost;
     }
 
     //apply effects at the beginning of the mage's turn
@@ -160,12 +165,12 @@ fn try_spell(idx: usize, mut state: State, handicap: u16) -> Option<u16> {
 
     // Not enough mana to cast a spell
     if spell.cost > state.mage.mana {
-        return None;
+        return Round::Lost;
     }
 
     // Cannot cast a spell for a second time while its effect is still active
     if state.effects[idx] > 1 {
-        return None;
+        return Round::Lost;
     }
 
     // mage's turn
@@ -190,7 +195,7 @@ fn try_spell(idx: usize, mut state: State, handicap: u16) -> Option<u16> {
 
     // The mage won
     if state.boss.hp == 0 {
-        return Some(spell.cost);
+        return Round::Won(state);
     }
 
     // boss' turn
@@ -199,20 +204,96 @@ fn try_spell(idx: usize, mut state: State, handicap: u16) -> Option<u16> {
 
     // The boss won
     if state.mage.hp == 0 {
-        return None;
+        return Round::Lost;
+    }
+
+    Round::Ongoing(state)
+}
+
+/// The cheapest way to win: the spells in the order they were cast and the state
+/// after each round. It is displayed as a round-by-round replay.
+#[derive(Debug, Clone)]
+pub struct Duel {
+    pub mana_spent: u16,
+    pub spells: Vec<&'static str>,
+    start: State,
+    rounds: Vec<State>,
+}
+
+impl Display for Duel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let describe = |state: &State| {
+            format!(
+                "mage {} hp, {} mana; boss {} hp",
+                state.mage.hp, state.mage.mana, state.boss.hp
+            )
+        };
+
+        writeln!(f, "Start: {}", describe(&self.start))?;
+        for (round, (spell, state)) in self.spells.iter().zip(self.rounds.iter()).enumerate() {
+            writeln!(f, "Round {}: {} -> {}", round + 1, spell, describe(state))?;
+        }
+
+        writeln!(
+            f,
+            "The boss is dead after spending {} mana",
+            self.mana_spent
+        )
+    }
+}
+
+/// Finds the cheapest winning sequence of spells for the boss in the input, on
+/// the hard difficulty for part two
+pub fn best_duel(input: &str, hard: bool) -> Option<Duel> {
+    let boss = parse_input(input);
+    let handicap = if hard { 1 } else { 0 };
+
+    let start = State::new(boss);
+    let (mana_spent, mut order) = best_spells(start, handicap)?;
+    order.reverse();
+
+    let mut rounds = vec![];
+    let mut state = start;
+    for idx in order.iter().copied() {
+        state = match play_round(idx, state, handicap) {
+            Round::Won(state) | Round::Ongoing(state) => state,
+            Round::Lost => unreachable!("the best spells lost the duel"),
+        };
+        rounds.push(state);
     }
 
-    let mut total_cost = None;
-    for idx in 0..SPELLS.len() {
-        if let Some(cost) = try_spell(idx, state, handicap) {
-            match total_cost {
-                None => total_cost = Some(cost),
-                Some(tc) => total_cost = Some(tc.min(cost)),
+    Some(Duel {
+        mana_spent,
+        spells: order.iter().map(|&idx| SPELLS[idx].name).collect(),
+        start,
+        rounds,
+    })
+}
+
+// The cheapest way to win from `state`: the mana spent and the spells cast, in
+// reverse order
+fn best_spells(state: State, handicap: u16) -> Option<(u16, Vec<usize>)> {
+    let mut best: Option<(u16, Vec<usize>)> = None;
+
+    for (idx, spell) in SPELLS.iter().enumerate() {
+        let cost = spell.cost;
+        let candidate = match play_round(idx, state, handicap) {
+            Round::Lost => continue,
+            Round::Won(_) => Some((cost, vec![idx])),
+            Round::Ongoing(next) => best_spells(next, handicap).map(|(rest, mut order)| {
+                order.push(idx);
+                (rest + cost, order)
+            }),
+        };
+
+        if let Some(candidate) = candidate {
+            if best.as_ref().is_none_or(|(best, _)| candidate.0 < *best) {
+                best = Some(candidate);
             }
         }
     }
 
-    total_cost.map(|c| c + spell.cost)
+    best
 }
 
 #[cfg(test)]
@@ -223,7 +304,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let boss = parse_input(&input);
 
         let answer = solve_part_one(boss);
@@ -232,10 +313,33 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let boss = parse_input(&input);
 
         let answer = solve_part_two(boss);
         assert_eq!(Some(1937), answer);
     }
+
+    #[test]
+    fn test_best_duel() {
+        let input = load_input(default_test_input!(DAY));
+
+        let duel = best_duel(&input, false).unwrap();
+        assert_eq!(1824, duel.mana_spent);
+        assert_eq!(
+            duel.mana_spent,
+            duel.spells
+                .iter()
+                .map(|&name| SPELLS.iter().find(|s| s.name == name).unwrap().cost)
+                .sum::<u16>()
+        );
+
+        let replay = duel.to_string();
+        assert!(replay.starts_with("Start: mage 50 hp, 500 mana; boss "));
+        assert!(replay.contains(" -> mage "));
+        assert!(replay.ends_with("boss 0 hp\nThe boss is dead after spending 1824 mana\n"));
+
+        let duel = best_duel(&input, true).unwrap();
+        assert_eq!(1937, duel.mana_spent);
+    }
 }
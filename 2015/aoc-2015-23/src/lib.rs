@@ -118,7 +118,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let instructions = parse_line_delimited(&input);
         let answer = solve_part_one(&instructions);
         assert_eq!(170, answer);
@@ -126,7 +126,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let instructions = parse_line_delimited(&input);
         let answer = solve_part_two(&instructions);
         assert_eq!(247, answer);
@@ -109,7 +109,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let weights = parse_line_delimited(&input);
         let answer = solve_part_one(&weights);
         assert_eq!(11266889531, answer);
@@ -117,7 +117,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let weights = parse_line_delimited(&input);
         let answer = solve_part_two(&weights);
         assert_eq!(77387711, answer);
@@ -1,23 +1,46 @@
-use std::fs::File;
-use std::io::Read;
 use std::path::Path;
 
-pub fn default_input(day: usize) -> String {
-    format!("puzzle-inputs/day-{:02}.txt", day)
+use anyhow::Context;
+
+pub use aoc_core::input::{normalize_input, InputKind, InputLocator, INPUT_DIR_ENV};
+
+const YEAR: u16 = 2015;
+
+/// The input of the day found by an [`InputLocator`] for the calling crate, or else
+/// its path relative to the workspace
+#[macro_export]
+macro_rules! default_input {
+    ($day:expr) => {
+        $crate::input::locate_or(env!("CARGO_MANIFEST_DIR"), $day, "puzzle-inputs")
+    };
+}
+
+/// The input of the day found by an [`InputLocator`] for the calling crate, or else
+/// its path relative to a day's crate
+#[macro_export]
+macro_rules! default_test_input {
+    ($day:expr) => {
+        $crate::input::locate_or(env!("CARGO_MANIFEST_DIR"), $day, "../puzzle-inputs")
+    };
 }
 
-pub fn default_test_input(day: usize) -> String {
-    format!("../puzzle-inputs/day-{:02}.txt", day)
+pub use crate::{default_input, default_test_input};
+
+// The macros take the manifest directory of the crate that expands them, so that
+// the inputs are found relative to the day's crate and not to this one
+#[doc(hidden)]
+pub fn locate_or(manifest_dir: &str, day: usize, fallback_dir: &str) -> String {
+    InputLocator::new(manifest_dir, YEAR, day as u8)
+        .locate()
+        .map(|path| path.to_string_lossy().into_owned())
+        .unwrap_or_else(|_| format!("{}/day-{:02}.txt", fallback_dir, day))
 }
 
 pub fn load_input<P: AsRef<Path>>(path: P) -> String {
+    try_load_input(path).unwrap()
+}
+
+pub fn try_load_input<P: AsRef<Path>>(path: P) -> anyhow::Result<String> {
     let path = path.as_ref();
-    let mut buffer = String::new();
-
-    File::open(path)
-        .map_err(|e| anyhow::Error::from(e).context(format!("Cannot open: {:?}", path)))
-        .unwrap()
-        .read_to_string(&mut buffer)
-        .unwrap();
-    buffer
+    std::fs::read_to_string(path).with_context(|| format!("Cannot open: {:?}", path))
 }
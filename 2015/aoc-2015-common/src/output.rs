@@ -1,13 +1,57 @@
+use std::cell::RefCell;
 use std::fmt::Debug;
 use std::time::Duration;
 
+thread_local! {
+    static CAPTURED: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
 pub fn display_measurement<A>(day: usize, comment: &str, duration: Duration, answer: &A)
 where
     A: Debug,
 {
     let duration = format!("{:.3?}", duration);
-    println!(
+    let line = format!(
         "Day {:02} | {:16} | {:>9} | {:?}",
         day, comment, duration, answer
     );
+
+    CAPTURED.with(|captured| match captured.borrow_mut().as_mut() {
+        Some(output) => {
+            output.push_str(&line);
+            output.push('\n');
+        }
+        None => println!("{}", line),
+    });
+}
+
+/// Runs `f` and returns the measurements it displayed on this thread instead of
+/// printing them, so that days running concurrently do not interleave their output
+pub fn capture_output<R, F>(f: F) -> (R, String)
+where
+    F: FnOnce() -> R,
+{
+    let previous = CAPTURED.with(|captured| captured.replace(Some(String::new())));
+    let result = f();
+    let output = CAPTURED.with(|captured| captured.replace(previous));
+
+    (result, output.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capture_output() {
+        let (answer, output) = capture_output(|| {
+            display_measurement(7, "part 1", Duration::from_millis(3), &42);
+            42
+        });
+
+        assert_eq!(42, answer);
+        assert_eq!(1, output.lines().count());
+        assert!(output.starts_with("Day 07 | part 1"));
+        assert!(output.trim_end().ends_with("| 42"));
+    }
 }
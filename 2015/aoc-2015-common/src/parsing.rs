@@ -1,20 +1,30 @@
 use std::fmt::Debug;
 use std::str::FromStr;
 
+use aoc_core::parsing::parse_trimmed;
+pub use aoc_core::parsing::ParseError;
+
 pub fn parse_line_delimited<I, R, E>(input: I) -> Vec<R>
 where
     I: AsRef<str>,
     E: Debug,
     R: FromStr<Err = E>,
+{
+    try_parse_line_delimited(input).unwrap()
+}
+
+pub fn try_parse_line_delimited<I, R>(input: I) -> Result<Vec<R>, ParseError<R::Err>>
+where
+    I: AsRef<str>,
+    R: FromStr,
 {
     input
         .as_ref()
         .lines()
-        .map(|l| l.trim())
-        .filter(|&l| !l.is_empty())
-        .map(|l| l.parse())
-        .collect::<Result<_, _>>()
-        .unwrap()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(idx, l)| parse_trimmed(l, idx))
+        .collect()
 }
 
 #[cfg(test)]
@@ -41,4 +51,11 @@ mod tests {
         let parsed = parse_line_delimited(lines);
         assert_eq!(&[1u32, 2, 3], parsed.as_slice());
     }
+
+    #[test]
+    fn test_try_parse_line_delimited_error() {
+        let err = try_parse_line_delimited::<_, u32>("1\n\n  x\n").unwrap_err();
+        assert_eq!((3, 3), (err.line, err.column));
+        assert_eq!("x", err.snippet);
+    }
 }
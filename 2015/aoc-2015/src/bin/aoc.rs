@@ -1,12 +1,21 @@
 use std::ops::AddAssign;
-use std::time::Duration;
+use std::thread::Builder;
+use std::time::{Duration, Instant};
 
 use aoc_2015_common::input::default_input;
+use aoc_2015_common::output::capture_output;
+
+// The same as the main thread, because some of the solutions are recursive
+const STACK_SIZE: usize = 8 * 1024 * 1024;
 
 fn main() {
-    let args = std::env::args().collect::<Vec<String>>();
+    let mut args = std::env::args().collect::<Vec<String>>();
+    let parallel = args.iter().any(|arg| arg == "--parallel");
+    args.retain(|arg| arg != "--parallel");
+
     if args.len() != 2 && args.len() != 3 {
         println!("usage: aoc <day> <puzzle_input>");
+        println!("       aoc all [--parallel]");
         return;
     }
 
@@ -39,10 +48,19 @@ fn main() {
     ];
 
     let selection = &args[1];
+    if selection == "all" && parallel {
+        let start = Instant::now();
+        let runtime = run_concurrently(&puzzles);
+        print_total_exec_time(runtime);
+        println!("Wall-clock time: {:.3?}", start.elapsed());
+
+        return;
+    }
+
     if selection == "all" {
         let mut runtime = Duration::default();
         for (day, puzzle) in puzzles.iter().enumerate() {
-            let elapsed = puzzle(default_input(day + 1));
+            let elapsed = puzzle(default_input!(day + 1));
             runtime.add_assign(elapsed);
         }
         print_total_exec_time(runtime);
@@ -59,7 +77,7 @@ fn main() {
         return;
     }
 
-    let mut puzzle_input = default_input(day);
+    let mut puzzle_input = default_input!(day);
     if args.len() == 3 {
         puzzle_input = args[2].to_owned();
     }
@@ -68,6 +86,39 @@ fn main() {
     print_total_exec_time(runtime);
 }
 
+// Runs each day on its own thread. The output of the days is printed in order once
+// all of them are done, and the total is the sum of their individual run times.
+fn run_concurrently(puzzles: &[fn(String) -> Duration]) -> Duration {
+    let results = std::thread::scope(|scope| {
+        let handles = puzzles
+            .iter()
+            .enumerate()
+            .map(|(day, puzzle)| {
+                Builder::new()
+                    .name(format!("day-{:02}", day + 1))
+                    .stack_size(STACK_SIZE)
+                    .spawn_scoped(scope, move || {
+                        capture_output(|| puzzle(default_input!(day + 1)))
+                    })
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+
+    let mut runtime = Duration::default();
+    for (elapsed, output) in results {
+        print!("{}", output);
+        runtime.add_assign(elapsed);
+    }
+
+    runtime
+}
+
 fn print_total_exec_time(runtime: Duration) {
     println!("---------");
     println!("Total execution time: {:.3?}", runtime);
@@ -1,4 +1,4 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use aoc_shared::parsing::parse_csv;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
@@ -13,7 +13,7 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2016, 1).load().unwrap();
 
     c.bench_function("parsing", |b| {
         b.iter(|| black_box(parse_csv::<_, Rot>(black_box(&input))));
@@ -21,7 +21,7 @@ fn benchmark_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2016, 1).load().unwrap();
     let parsed = parse_csv(&input);
 
     c.bench_function("part-1", |b| {
@@ -30,7 +30,7 @@ fn benchmark_part_one(c: &mut Criterion) {
 }
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2016, 1).load().unwrap();
     let parsed = parse_csv(&input);
 
     c.bench_function("part-2", |b| {
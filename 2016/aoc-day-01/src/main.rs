@@ -1,12 +1,16 @@
 use std::error::Error;
 
-use aoc_shared::input::load_text_input_from_autodetect;
-use aoc_shared::parsing::parse_csv;
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
+use aoc_shared::parsing::{try_parse_csv, ParseError};
 
 use aoc_day_01::{part_one, part_two};
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let input = parse_csv(load_text_input_from_autodetect());
+    let input = try_parse_csv(try_load_text_input_from_autodetect(input_locator!(
+        2016, 1
+    ))?)
+    .map_err(ParseError::into_boxed)?;
     println!("Part 1: {:?}", part_one(&input));
     println!("Part 2: {:?}", part_two(&input));
 
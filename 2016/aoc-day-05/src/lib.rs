@@ -1,7 +1,4 @@
-use std::fmt::Write;
-
-use md5::digest::FixedOutputReset;
-use md5::{Digest, Md5};
+use aoc_shared::md5::{leading_zero_nibbles, matches, nibble};
 
 const HEX: [char; 16] = [
     '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F',
@@ -11,45 +8,27 @@ const EMPTY: char = ' ';
 const PASS_LEN: usize = 8;
 
 pub fn part_one(input: &str) -> String {
-    let mut hasher = Md5::new();
-    let mut buffer = String::with_capacity(8);
-    let mut answer = String::with_capacity(PASS_LEN);
-
-    for idx in 0u32.. {
-        buffer.clear();
-        write!(buffer, "{}", idx).unwrap();
-
-        hasher.update(input);
-        hasher.update(&buffer);
-
-        let hash = hasher.finalize_fixed_reset();
-        if hash[0] == 0 && hash[1] == 0 && hash[2] <= 0x0F {
-            answer.push(HEX[hash[2] as usize]);
-            if answer.len() == PASS_LEN {
-                break;
-            }
-        }
-    }
-
-    answer
+    matches(input, 0, |_, hash| {
+        (leading_zero_nibbles(hash) >= 5).then(|| HEX[nibble(hash, 5) as usize])
+    })
+    .take(PASS_LEN)
+    .map(|(_, c)| c)
+    .collect()
 }
 
 pub fn part_two(input: &str) -> String {
-    let mut hasher = Md5::new();
-    let mut buffer = String::with_capacity(8);
     let mut answer = [EMPTY; PASS_LEN];
     let mut remaining = PASS_LEN;
 
-    for idx in 0u32.. {
-        buffer.clear();
-        write!(buffer, "{}", idx).unwrap();
-
-        hasher.update(input);
-        hasher.update(&buffer);
+    let found = matches(input, 0, |_, hash| {
+        let position = nibble(hash, 5) as usize;
+        (leading_zero_nibbles(hash) >= 5 && position < PASS_LEN)
+            .then(|| (position, HEX[nibble(hash, 6) as usize]))
+    });
 
-        let hash = hasher.finalize_fixed_reset();
-        if hash[0] == 0 && hash[1] == 0 && hash[2] <= 0x07 && answer[hash[2] as usize] == EMPTY {
-            answer[hash[2] as usize] = HEX[(hash[3] >> 4) as usize];
+    for (_, (position, c)) in found {
+        if answer[position] == EMPTY {
+            answer[position] = c;
 
             remaining -= 1;
             if remaining == 0 {
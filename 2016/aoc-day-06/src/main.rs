@@ -1,11 +1,12 @@
 use std::error::Error;
 
-use aoc_shared::input::load_line_delimited_input_from_autodetect;
+use aoc_shared::input::try_load_line_delimited_input_from_autodetect;
+use aoc_shared::input_locator;
 
 use aoc_day_06::{part_one, part_two};
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let input = load_line_delimited_input_from_autodetect();
+    let input = try_load_line_delimited_input_from_autodetect(input_locator!(2016, 6))?;
     println!("Part 1: {:?}", part_one(&input));
     println!("Part 2: {:?}", part_two(&input));
 
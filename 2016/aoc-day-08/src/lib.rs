@@ -1,3 +1,4 @@
+use aoc_shared::ocr::{recognize, OcrError};
 use regex::Regex;
 
 const WIDTH: usize = 50;
@@ -62,7 +63,14 @@ pub fn part_one(input: &[Instruction]) -> usize {
         .count()
 }
 
-pub fn part_two(input: &[Instruction]) -> String {
+pub fn part_two(input: &[Instruction]) -> Result<String, OcrError> {
+    let screen = process_input(input);
+    let grid = screen.map(|row| row.map(|x| x == FULL_BLOCK));
+    recognize(&grid)
+}
+
+/// Draws the screen, one line per row
+pub fn render_screen(input: &[Instruction]) -> String {
     let screen = process_input(input);
     screen
         .iter()
@@ -109,7 +117,7 @@ fn process_input(input: &[Instruction]) -> [[char; 50]; 6] {
 mod tests {
     use aoc_shared::input::load_text_input_from_file;
 
-    use crate::{parse_input, part_one, part_two};
+    use crate::{parse_input, part_one, part_two, render_screen};
 
     #[test]
     fn test_part_one() {
@@ -124,6 +132,14 @@ mod tests {
         let input = load_text_input_from_file("inputs/input.txt");
         let input = parse_input(&input);
         let answer = part_two(&input);
+        assert_eq!(Ok("AFBUPZBJPS".to_owned()), answer);
+    }
+
+    #[test]
+    fn test_render_screen() {
+        let input = load_text_input_from_file("inputs/input.txt");
+        let input = parse_input(&input);
+        let answer = render_screen(&input);
         assert_eq!(
             " ██  ████ ███  █  █ ███  ████ ███    ██ ███   ███ \n\
              █  █ █    █  █ █  █ █  █    █ █  █    █ █  █ █    \n\
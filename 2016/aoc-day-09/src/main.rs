@@ -1,11 +1,12 @@
 use std::error::Error;
 
-use aoc_shared::input::load_text_input_from_autodetect;
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
 
 use aoc_day_09::{part_one, part_two};
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let input = load_text_input_from_autodetect();
+    let input = try_load_text_input_from_autodetect(input_locator!(2016, 9))?;
     println!("Part 1: {:?}", part_one(&input.trim_end()));
     println!("Part 2: {:?}", part_two(input.trim_end()));
 
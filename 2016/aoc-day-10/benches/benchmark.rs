@@ -1,4 +1,4 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
 
 use aoc_day_10::{parse_input, part_one, part_two};
@@ -12,14 +12,14 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2016, 10).load().unwrap();
     c.bench_with_input(BenchmarkId::new("parsing", ""), &input.as_str(), |b, i| {
         b.iter_with_large_drop(|| parse_input(i))
     });
 }
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2016, 10).load().unwrap();
     let (start_node, graph) = parse_input(&input);
 
     c.bench_function("part-1", |b| {
@@ -32,7 +32,7 @@ fn benchmark_part_one(c: &mut Criterion) {
 }
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2016, 10).load().unwrap();
     let (start_node, graph) = parse_input(&input);
 
     c.bench_function("part-2", |b| {
@@ -1,4 +1,4 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
 
 use aoc_day_11::{parse_input, part_one, part_two};
@@ -12,7 +12,7 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2016, 11).load().unwrap();
 
     c.bench_with_input(BenchmarkId::new("parsing", ""), &input.as_str(), |b, i| {
         b.iter_with_large_drop(|| parse_input(i))
@@ -20,7 +20,7 @@ fn benchmark_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2016, 11).load().unwrap();
     let input = parse_input(&input);
 
     let mut g = c.benchmark_group("part-1");
@@ -29,14 +29,14 @@ fn benchmark_part_one(c: &mut Criterion) {
     g.bench_function("part-1", |b| {
         b.iter_batched(
             || input.clone(),
-            |input| part_one(input),
+            |input| part_one(&input),
             BatchSize::PerIteration,
         )
     });
 }
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2016, 11).load().unwrap();
     let input = parse_input(&input);
 
     let mut g = c.benchmark_group("part-2");
@@ -45,7 +45,7 @@ fn benchmark_part_two(c: &mut Criterion) {
     g.bench_function("part-2", |b| {
         b.iter_batched(
             || input.clone(),
-            |input| part_two(input),
+            |input| part_two(&input),
             BatchSize::PerIteration,
         )
     });
@@ -1,330 +1,455 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{Display, Formatter};
 
 use aoc_shared::hashing::{FnvHasher, HashBuilder};
 
+const PART_TWO_ELEMENTS: [&str; 2] = ["elerium", "dilithium"];
+
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-enum Direction {
+pub enum Direction {
     Up,
     Down,
 }
 
-pub fn parse_input(input: &str) -> ([u16; 4], [u16; 4]) {
+/// The floor of each generator and microchip. Element `i` has the generator
+/// `generators[i]` and the microchip `microchips[i]`.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Building {
+    pub floors: usize,
+    pub names: Vec<String>,
+    pub generators: Vec<usize>,
+    pub microchips: Vec<usize>,
+}
+
+impl Building {
+    /// Adds the pairs of another element on the first floor
+    pub fn add_pair(&mut self, name: &str) {
+        self.names.push(name.to_owned());
+        self.generators.push(0);
+        self.microchips.push(0);
+    }
+
+    fn initial_state(&self) -> State {
+        State {
+            elevator: 0,
+            pairs: self
+                .generators
+                .iter()
+                .copied()
+                .zip(self.microchips.iter().copied())
+                .map(|(generator, microchip)| (generator as u8, microchip as u8))
+                .collect(),
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Building {
     let rgx_gen = regex::Regex::new(r#"a (?<type>[a-z]+) generator"#).unwrap();
     let rgx_chip = regex::Regex::new(r#"a (?<type>[a-z]+)\-compatible"#).unwrap();
 
-    let mut generators = [0u16; 4];
-    let mut microchips = [0u16; 4];
-    let mut name_ids = HashMap::new();
+    let mut building = Building {
+        floors: 0,
+        names: vec![],
+        generators: vec![],
+        microchips: vec![],
+    };
 
-    for (idx, line) in input.lines().enumerate().take(4) {
-        for g in rgx_gen.captures_iter(line) {
-            let gen_type = g.name("type").unwrap();
+    let mut name_ids = HashMap::new();
+    let mut element_id = |building: &mut Building, name: &str| -> usize {
+        *name_ids.entry(name.to_owned()).or_insert_with(|| {
+            building.add_pair(name);
+            building.names.len() - 1
+        })
+    };
 
-            let next_id = name_ids.len();
-            let gen_id = *name_ids.entry(gen_type.as_str()).or_insert(next_id);
+    for (floor, line) in input.lines().filter(|l| !l.is_empty()).enumerate() {
+        building.floors += 1;
 
-            generators[idx] |= 1 << gen_id;
+        for g in rgx_gen.captures_iter(line) {
+            let id = element_id(&mut building, &g["type"]);
+            building.generators[id] = floor;
         }
 
         for g in rgx_chip.captures_iter(line) {
-            let gen_type = g.name("type").unwrap();
-
-            let next_id = name_ids.len();
-            let gen_id = *name_ids.entry(gen_type.as_str()).or_insert(next_id);
-
-            microchips[idx] |= 1 << gen_id;
+            let id = element_id(&mut building, &g["type"]);
+            building.microchips[id] = floor;
         }
     }
 
-    (generators, microchips)
+    building
 }
 
-pub fn part_one(input: ([u16; 4], [u16; 4])) -> u32 {
-    solve_bfs(input).expect("no solution")
+pub fn part_one(building: &Building) -> u32 {
+    solve_bfs(building).expect("no solution")
 }
 
-pub fn part_two(mut input: ([u16; 4], [u16; 4])) -> u32 {
-    let total: u32 = input.0.iter().copied().map(|x| x.count_ones()).sum();
-
-    input.0[0] |= 1 << total;
-    input.0[0] |= 1 << (total + 1);
-
-    input.1[0] |= 1 << total;
-    input.1[0] |= 1 << (total + 1);
-
-    solve_bfs(input).expect("no solution")
+pub fn part_two(building: &Building) -> u32 {
+    solve_bfs(&add_part_two_elements(building)).expect("no solution")
 }
 
-fn solve_bfs(input: ([u16; 4], [u16; 4])) -> Option<u32> {
-    let total: u32 = input.0.iter().copied().map(|x| x.count_ones()).sum();
-
-    let mut queue = VecDeque::new();
-    queue.push_back((input.0, input.1, 0usize));
-
-    let mut seen = HashSet::with_hasher(HashBuilder::<FnvHasher>::default());
-    let mut steps = 0;
-
-    while !queue.is_empty() {
-        for _ in 0..queue.len() {
-            let (gens, chips, elev) = queue.pop_front().unwrap();
-            for dir in [Direction::Up, Direction::Down].iter().copied() {
-                // cannot go below floor 0 and above floor 3
-                match dir {
-                    Direction::Up if elev >= 3 => continue,
-                    Direction::Down if elev == 0 => continue,
-                    _ => {}
-                }
+/// Adds the two extra pairs found on the first floor in part two
+pub fn add_part_two_elements(building: &Building) -> Building {
+    let mut building = building.clone();
+    for name in PART_TWO_ELEMENTS {
+        building.add_pair(name);
+    }
 
-                // move either one or two chips
-                for first in 0..total {
-                    let mask = 1 << first;
+    building
+}
 
-                    // we can move only chips that are present
-                    if chips[elev] & mask != mask {
-                        continue;
-                    }
+/// The elevator's position and the floors of the generator and the microchip of
+/// each element
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+struct State {
+    elevator: u8,
+    pairs: Vec<(u8, u8)>,
+}
 
-                    // try to move only one chip at first
-                    match move_chips(dir, gens, chips, elev, mask) {
-                        Some((chips, elev)) => {
-                            if seen.insert((gens, chips, elev)) {
-                                if is_answer(gens, chips, elev, total) {
-                                    return Some(steps + 1);
-                                }
-
-                                queue.push_back((gens, chips, elev));
-                            }
-                        }
-                        None => continue,
-                    }
+impl State {
+    // The elements are interchangeable, so the states that differ only in which
+    // element is where are equivalent and need to be explored only once
+    fn canonical(&self) -> State {
+        let mut pairs = self.pairs.clone();
+        pairs.sort_unstable();
 
-                    // if we are successful, try to move two chips up
-                    for second in first + 1..total {
-                        let mask = mask | (1 << second);
-
-                        // we can move only chips that are present
-                        if chips[elev] & mask != mask {
-                            continue;
-                        }
-
-                        // try to move both chips
-                        if let Some((chips, elev)) = move_chips(dir, gens, chips, elev, mask) {
-                            if seen.insert((gens, chips, elev)) {
-                                if is_answer(gens, chips, elev, total) {
-                                    return Some(steps + 1);
-                                }
-
-                                queue.push_back((gens, chips, elev));
-                            }
-                        }
-                    }
-                }
+        State {
+            elevator: self.elevator,
+            pairs,
+        }
+    }
 
-                // move either one or two generators
-                for first in 0..total {
-                    let mask = 1 << first;
+    fn is_safe(&self) -> bool {
+        // a microchip is fried if it is on a floor with generators, but not
+        // with its own one
+        self.pairs.iter().all(|&(generator, microchip)| {
+            generator == microchip || self.pairs.iter().all(|&(g, _)| g != microchip)
+        })
+    }
 
-                    // we can move only generators that are present
-                    if gens[elev] & mask != mask {
-                        continue;
-                    }
+    fn is_done(&self, floors: usize) -> bool {
+        let top = floors as u8 - 1;
+        self.pairs.iter().all(|&(g, m)| g == top && m == top)
+    }
+}
 
-                    // try to move only one generator
-                    if let Some((gens, elev)) = move_generators(dir, gens, chips, elev, mask) {
-                        if seen.insert((gens, chips, elev)) {
-                            if is_answer(gens, chips, elev, total) {
-                                return Some(steps + 1);
-                            }
+/// The generators and microchips taken by the elevator in one step
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Move {
+    pub direction: Direction,
+    pub generators: Vec<usize>,
+    pub microchips: Vec<usize>,
+}
 
-                            queue.push_back((gens, chips, elev));
-                        }
-                    }
+/// An optimal sequence of moves and the building after each of them
+#[derive(Clone, Debug)]
+pub struct Solution {
+    pub start: Building,
+    pub steps: Vec<(Move, Building)>,
+}
 
-                    // try to move two generators up
-                    for second in first + 1..total {
-                        let mask = mask | (1 << second);
-
-                        // we can move only generators that are present
-                        if gens[elev] & mask != mask {
-                            continue;
-                        }
-
-                        // try to move both generators
-                        if let Some((gens, elev)) = move_generators(dir, gens, chips, elev, mask) {
-                            if seen.insert((gens, chips, elev)) {
-                                if is_answer(gens, chips, elev, total) {
-                                    return Some(steps + 1);
-                                }
-
-                                queue.push_back((gens, chips, elev));
-                            }
-                        }
-                    }
-                }
+impl Solution {
+    pub fn step_count(&self) -> u32 {
+        self.steps.len() as u32
+    }
+}
 
-                // try to move both a generator & a chip
-                for idx in 0..total {
-                    let mask = 1 << idx;
+impl Display for Solution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_building(f, &self.start, 0)?;
+
+        let mut elevator = 0;
+        for (idx, (step, building)) in self.steps.iter().enumerate() {
+            let direction = match step.direction {
+                Direction::Up => "up",
+                Direction::Down => "down",
+            };
+            match step.direction {
+                Direction::Up => elevator += 1,
+                Direction::Down => elevator -= 1,
+            }
 
-                    // we can only move a matching chip & generator together
-                    if chips[elev] & mask != mask || gens[elev] & mask != mask {
-                        continue;
-                    }
+            let items = step
+                .generators
+                .iter()
+                .map(|&id| format!("{}G", symbol(&building.names[id])))
+                .chain(
+                    step.microchips
+                        .iter()
+                        .map(|&id| format!("{}M", symbol(&building.names[id]))),
+                )
+                .collect::<Vec<_>>();
+
+            writeln!(f)?;
+            writeln!(
+                f,
+                "Step {}: {} with {}",
+                idx + 1,
+                direction,
+                items.join(", ")
+            )?;
+            write_building(f, building, elevator)?;
+        }
 
-                    if let Some((gens, chips, elev)) = move_both(dir, gens, chips, elev, mask) {
-                        if seen.insert((gens, chips, elev)) {
-                            if is_answer(gens, chips, elev, total) {
-                                return Some(steps + 1);
-                            }
+        Ok(())
+    }
+}
 
-                            queue.push_back((gens, chips, elev));
-                        }
-                    }
-                }
+// Draws the floors like the puzzle description does, with one column per item
+fn write_building(f: &mut Formatter<'_>, building: &Building, elevator: usize) -> std::fmt::Result {
+    for floor in (0..building.floors).rev() {
+        write!(
+            f,
+            "F{:<2} {}",
+            floor + 1,
+            if elevator == floor { 'E' } else { '.' }
+        )?;
+
+        for (id, name) in building.names.iter().enumerate() {
+            match building.generators[id] == floor {
+                true => write!(f, " {}G", symbol(name))?,
+                false => write!(f, " .  ")?,
+            }
+            match building.microchips[id] == floor {
+                true => write!(f, " {}M", symbol(name))?,
+                false => write!(f, " .  ")?,
             }
         }
 
-        steps += 1;
+        writeln!(f)?;
     }
 
-    None
+    Ok(())
 }
 
-fn is_answer(gens: [u16; 4], chips: [u16; 4], elev: usize, total: u32) -> bool {
-    elev == 3 && (gens[3] & chips[3]).count_ones() == total
+// A two-letter symbol, e.g. "Co" for cobalt and "Cu" for curium
+fn symbol(name: &str) -> String {
+    let mut chars = name.chars();
+    let first = chars.next().map_or(' ', |c| c.to_ascii_uppercase());
+    let second = chars.next().unwrap_or(' ');
+    format!("{}{}", first, second)
 }
 
-fn move_chips(
-    dir: Direction,
-    gens: [u16; 4],
-    mut chips: [u16; 4],
-    elev: usize,
-    mask: u16,
-) -> Option<([u16; 4], usize)> {
-    // and move them to the next
-    let next_elev = match dir {
-        Direction::Up => elev + 1,
-        Direction::Down => elev - 1,
-    };
+/// Like `part_one`/`part_two`, but also returns the moves of an optimal solution
+pub fn solve_with_path(building: &Building) -> Option<Solution> {
+    let start = building.initial_state();
+
+    let mut parents = HashMap::with_hasher(HashBuilder::<FnvHasher>::default());
+    let (_, end) = bfs(building, |from, step, to| {
+        parents.insert(to.canonical(), (from.clone(), step));
+    })?;
+
+    let mut steps = vec![];
+    let mut state = end;
+    while state != start {
+        let (previous, step) = parents.remove(&state.canonical()).unwrap();
+        steps.push((step, to_building(building, &state)));
+        state = previous;
+    }
+    steps.reverse();
 
-    chips[elev] &= !mask;
-    chips[next_elev] |= mask;
+    Some(Solution {
+        start: building.clone(),
+        steps,
+    })
+}
 
-    // we can move a chip only if the floor does not have any
-    // generators, or if the chip's matching generator is there
-    if gens[next_elev] != 0 && (gens[next_elev] & chips[next_elev]) != chips[next_elev] {
-        return None;
+fn to_building(building: &Building, state: &State) -> Building {
+    Building {
+        floors: building.floors,
+        names: building.names.clone(),
+        generators: state.pairs.iter().map(|&(g, _)| g as usize).collect(),
+        microchips: state.pairs.iter().map(|&(_, m)| m as usize).collect(),
     }
+}
 
-    debug_assert!(check_state(gens, chips));
-    Some((chips, next_elev))
+fn solve_bfs(building: &Building) -> Option<u32> {
+    bfs(building, |_, _, _| {}).map(|(steps, _)| steps)
 }
 
-fn move_generators(
-    dir: Direction,
-    mut gens: [u16; 4],
-    chips: [u16; 4],
-    elev: usize,
-    mask: u16,
-) -> Option<([u16; 4], usize)> {
-    // and move them to the next
-    let next_elev = match dir {
-        Direction::Up => elev + 1,
-        Direction::Down => elev - 1,
-    };
+// Returns the number of steps and the final state. `on_visit` is called with
+// the previous state and the move for every newly reached state.
+fn bfs<F>(building: &Building, mut on_visit: F) -> Option<(u32, State)>
+where
+    F: FnMut(&State, Move, &State),
+{
+    let start = building.initial_state();
+    if start.is_done(building.floors) {
+        return Some((0, start));
+    }
 
-    gens[elev] &= !mask;
-    gens[next_elev] |= mask;
+    let mut queue = VecDeque::new();
+    queue.push_back(start.clone());
 
-    // we can move a generator only if the floor does not have any
-    // chips, or if all chips' matching generators are there
-    if chips[next_elev] & gens[next_elev] != chips[next_elev] {
-        return None;
-    }
+    let mut seen = HashMap::with_hasher(HashBuilder::<FnvHasher>::default());
+    seen.insert(start.canonical(), ());
+    let mut steps = 0;
 
-    // when we move a generator, we must not leave an unprotected chip
-    // on the current floor
-    if gens[elev] != 0 && chips[elev] & gens[elev] != chips[elev] {
-        return None;
-    }
+    while !queue.is_empty() {
+        let mut answer = None;
 
-    debug_assert!(check_state(gens, chips));
-    Some((gens, next_elev))
-}
+        for _ in 0..queue.len() {
+            let state = queue.pop_front().unwrap();
+            successors(&state, building.floors, |step, next| {
+                if answer.is_some() || seen.insert(next.canonical(), ()).is_some() {
+                    return;
+                }
 
-fn move_both(
-    dir: Direction,
-    mut gens: [u16; 4],
-    mut chips: [u16; 4],
-    elev: usize,
-    mask: u16,
-) -> Option<([u16; 4], [u16; 4], usize)> {
-    //determine the next floor
-    let next_elev = match dir {
-        Direction::Up => elev + 1,
-        Direction::Down => elev - 1,
-    };
+                on_visit(&state, step, &next);
 
-    gens[elev] &= !mask;
-    chips[elev] &= !mask;
+                if next.is_done(building.floors) {
+                    answer = Some(next);
+                } else {
+                    queue.push_back(next);
+                }
+            });
 
-    gens[next_elev] |= mask;
-    chips[next_elev] |= mask;
+            if let Some(end) = answer {
+                return Some((steps + 1, end));
+            }
+        }
 
-    // when we move a generator, we must not endanger an unprotected chip
-    if chips[next_elev] & gens[next_elev] != chips[next_elev] {
-        return None;
+        steps += 1;
     }
 
-    debug_assert!(check_state(gens, chips));
-    Some((gens, chips, next_elev))
+    None
 }
 
-fn print_state(gens: [u16; 4], chips: [u16; 4]) {
-    println!(
-        "G: {:05b} {:05b} {:05b} {:05b}",
-        gens[0], gens[1], gens[2], gens[3]
-    );
-    println!(
-        "C: {:05b} {:05b} {:05b} {:05b}",
-        chips[0], chips[1], chips[2], chips[3]
-    );
-    println!();
-}
+fn successors<F>(state: &State, floors: usize, mut visit: F)
+where
+    F: FnMut(Move, State),
+{
+    let elevator = state.elevator;
+
+    // the items on the elevator's floor: (element, is microchip)
+    let items = state
+        .pairs
+        .iter()
+        .enumerate()
+        .flat_map(|(id, &(g, m))| [(id, false, g), (id, true, m)])
+        .filter(|&(_, _, floor)| floor == elevator)
+        .map(|(id, is_chip, _)| (id, is_chip))
+        .collect::<Vec<_>>();
+
+    for direction in [Direction::Up, Direction::Down] {
+        let next_floor = match direction {
+            Direction::Up if (elevator as usize) + 1 < floors => elevator + 1,
+            Direction::Down if elevator > 0 => elevator - 1,
+            _ => continue,
+        };
+
+        // the elevator takes one or two items
+        for first in 0..items.len() {
+            for second in first..items.len() {
+                let mut next = State {
+                    elevator: next_floor,
+                    pairs: state.pairs.clone(),
+                };
+
+                let mut step = Move {
+                    direction,
+                    generators: vec![],
+                    microchips: vec![],
+                };
+
+                let taken = if first == second {
+                    &items[first..=first]
+                } else {
+                    &[items[first], items[second]][..]
+                };
+
+                for &(id, is_chip) in taken {
+                    if is_chip {
+                        next.pairs[id].1 = next_floor;
+                        step.microchips.push(id);
+                    } else {
+                        next.pairs[id].0 = next_floor;
+                        step.generators.push(id);
+                    }
+                }
 
-fn check_state(gens: [u16; 4], chips: [u16; 4]) -> bool {
-    for floor in 0..3 {
-        if gens[floor] > 0 && gens[floor] & chips[floor] != chips[floor] {
-            println!("INVALID STATE");
-            print_state(gens, chips);
-            return false;
+                if next.is_safe() {
+                    visit(step, next);
+                }
+            }
         }
     }
-
-    true
 }
 
 #[cfg(test)]
 mod tests {
     use aoc_shared::input::load_text_input_from_file;
 
-    use crate::{parse_input, part_one, part_two};
+    use crate::{parse_input, part_one, part_two, solve_with_path};
+
+    const EXAMPLE: &str = "\
+The first floor contains a hydrogen-compatible microchip and a lithium-compatible microchip.
+The second floor contains a hydrogen generator.
+The third floor contains a lithium generator.
+The fourth floor contains nothing relevant.
+";
 
     #[test]
     fn test_part_one() {
         let input = load_text_input_from_file("inputs/input.txt");
-        let items = parse_input(&input);
+        let building = parse_input(&input);
 
-        let answer = part_one(items);
+        let answer = part_one(&building);
         assert_eq!(33, answer);
     }
 
     #[test]
     fn test_part_two() {
         let input = load_text_input_from_file("inputs/input.txt");
-        let items = parse_input(&input);
+        let building = parse_input(&input);
 
-        let answer = part_two(items);
+        let answer = part_two(&building);
         assert_eq!(57, answer);
     }
+
+    #[test]
+    fn test_example() {
+        let building = parse_input(EXAMPLE);
+        assert_eq!(4, building.floors);
+        assert_eq!(vec!["hydrogen", "lithium"], building.names);
+        assert_eq!(vec![1, 2], building.generators);
+        assert_eq!(vec![0, 0], building.microchips);
+
+        assert_eq!(11, part_one(&building));
+    }
+
+    #[test]
+    fn test_other_buildings() {
+        // a single pair has to be carried up together, one floor per step
+        let input = "a cobalt generator and a cobalt-compatible microchip\n.\n.\n.\n.\n";
+        let building = parse_input(input);
+        assert_eq!(5, building.floors);
+        assert_eq!(4, part_one(&building));
+
+        // more elements than fit in the 16 bits of the old solver
+        let mut building = parse_input(input);
+        for idx in 0..16 {
+            building.add_pair(&format!("element{}", idx));
+            building.generators[idx + 1] = 4;
+            building.microchips[idx + 1] = 4;
+        }
+        assert_eq!(4, part_one(&building));
+    }
+
+    #[test]
+    fn test_solve_with_path() {
+        let input = load_text_input_from_file("inputs/input.txt");
+        let building = parse_input(&input);
+
+        let solution = solve_with_path(&building).unwrap();
+        assert_eq!(33, solution.step_count());
+
+        let last = &solution.steps.last().unwrap().1;
+        assert!(last.generators.iter().all(|&floor| floor == 3));
+        assert!(last.microchips.iter().all(|&floor| floor == 3));
+
+        let replay = solution.to_string();
+        assert!(replay.starts_with("F4  . .   .   .   .   .   .   .   .   .   .  \n"));
+        assert!(replay.contains("F1  E PrG PrM"));
+        assert!(replay.contains("Step 33: up with "));
+    }
 }
@@ -1,15 +1,16 @@
 use std::error::Error;
 
-use aoc_shared::input::load_text_input_from_autodetect;
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
 
 use aoc_day_11::{parse_input, part_one, part_two};
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let input = load_text_input_from_autodetect();
-    let items = parse_input(&input);
+    let input = try_load_text_input_from_autodetect(input_locator!(2016, 11))?;
+    let building = parse_input(&input);
 
-    println!("Part 1: {:?}", part_one(items));
-    println!("Part 2: {:?}", part_two(items));
+    println!("Part 1: {:?}", part_one(&building));
+    println!("Part 2: {:?}", part_two(&building));
 
     Ok(())
 }
@@ -1,4 +1,4 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{criterion_group, criterion_main, Criterion};
 
 use aoc_day_13::{part_one, part_two};
@@ -7,14 +7,14 @@ criterion_group!(benches, benchmark_part_one, benchmark_part_two);
 criterion_main!(benches);
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2016, 13).load().unwrap();
     let input = input.trim().parse().unwrap();
 
     c.bench_function("part-1", |b| b.iter(|| part_one(input)));
 }
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2016, 13).load().unwrap();
     let input = input.trim().parse().unwrap();
 
     c.bench_function("part-2", |b| b.iter(|| part_two(input)));
@@ -1,12 +1,14 @@
 use std::error::Error;
 
-use aoc_shared::input::load_text_input_from_autodetect;
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
+use aoc_shared::parsing::parse_trimmed;
 
 use aoc_day_13::{part_one, part_two};
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let input = load_text_input_from_autodetect();
-    let input = input.trim().parse().unwrap();
+    let input = try_load_text_input_from_autodetect(input_locator!(2016, 13))?;
+    let input = parse_trimmed(&input, 0)?;
 
     println!("Part 1: {:?}", part_one(input));
     println!("Part 2: {:?}", part_two(input));
@@ -1,133 +1,34 @@
-use std::collections::{HashMap, VecDeque};
-use std::fmt::Write;
+use aoc_shared::md5::{HexDigest, StretchedHashes};
 
-use aoc_shared::hashing::{FnvHasher, HashBuilder};
-use md5::{Digest, Md5};
-
-const HEX: [u8; 16] = [
-    b'0', b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8', b'9', b'a', b'b', b'c', b'd', b'e', b'f',
-];
 const NTH_KEY: usize = 64;
+const LOOKAHEAD: u32 = 1000;
+const STRETCH_ROUNDS: usize = 2016;
 
 pub fn part_one(input: &str) -> u32 {
-    let mut hasher = Md5::new();
-    let mut raw_hash = Default::default();
-    let mut hex_hash = vec![0; Md5::output_size() * 2];
-
-    let mut sequence_string = String::with_capacity(8);
-    let mut triples = HashMap::with_hasher(HashBuilder::<FnvHasher>::default());
-    let mut remaining = NTH_KEY;
-
-    for sequence in 0u32.. {
-        sequence_string.clear();
-        write!(sequence_string, "{}", sequence).unwrap();
-
-        hasher.update(input);
-        hasher.update(&sequence_string);
-
-        hasher.finalize_into_reset(&mut raw_hash);
-        for idx in 0..raw_hash.len() {
-            hex_hash[idx * 2] = HEX[(raw_hash[idx] >> 4) as usize];
-            hex_hash[idx * 2 + 1] = HEX[(raw_hash[idx] & 0x0F) as usize];
-        }
-
-        if let Some(pos) = hex_hash
-            .windows(3)
-            .position(|w| w[0] == w[1] && w[1] == w[2])
-        {
-            triples
-                .entry(hex_hash[pos])
-                .or_insert(VecDeque::new())
-                .push_back(sequence);
-        }
-
-        if let Some(pos) = hex_hash
-            .windows(5)
-            .position(|w| w[0] == w[1] && w[0] == w[2] && w[0] == w[3] && w[0] == w[4])
-        {
-            if let Some(positions) = triples.get_mut(&hex_hash[pos]) {
-                while let Some(pos) = positions.front().copied() {
-                    if sequence == pos {
-                        break;
-                    }
-
-                    if sequence - pos <= 1000 {
-                        remaining -= 1;
-                        if remaining == 0 {
-                            return pos;
-                        }
-                    }
-
-                    positions.pop_front();
-                }
-            }
-        }
-    }
-
-    unreachable!()
+    find_nth_key(StretchedHashes::new(input, 0))
 }
 
 pub fn part_two(input: &str) -> u32 {
-    let mut hasher = Md5::new();
-    let mut raw_hash = Default::default();
-    let mut hex_hash = vec![0; Md5::output_size() * 2];
+    find_nth_key(StretchedHashes::new(input, STRETCH_ROUNDS))
+}
 
-    let mut sequence_string = String::with_capacity(8);
-    let mut triples = HashMap::with_hasher(HashBuilder::<FnvHasher>::default());
+fn find_nth_key(mut hashes: StretchedHashes) -> u32 {
     let mut remaining = NTH_KEY;
 
-    for sequence in 0u32.. {
-        sequence_string.clear();
-        write!(sequence_string, "{}", sequence).unwrap();
+    for index in 0u32.. {
+        hashes.evict_before(index as u64);
 
-        hasher.update(input);
-        hasher.update(&sequence_string);
-
-        hasher.finalize_into_reset(&mut raw_hash);
-        for idx in 0..raw_hash.len() {
-            hex_hash[idx * 2] = HEX[(raw_hash[idx] >> 4) as usize];
-            hex_hash[idx * 2 + 1] = HEX[(raw_hash[idx] & 0x0F) as usize];
-        }
+        let Some(symbol) = first_triple(hashes.get(index as u64)) else {
+            continue;
+        };
 
-        for _ in 0..2016 {
-            hasher.update(&hex_hash);
-            hasher.finalize_into_reset(&mut raw_hash);
+        let is_key = (index + 1..=index + LOOKAHEAD)
+            .any(|next| has_quintuple(hashes.get(next as u64), symbol));
 
-            for idx in 0..raw_hash.len() {
-                hex_hash[idx * 2] = HEX[(raw_hash[idx] >> 4) as usize];
-                hex_hash[idx * 2 + 1] = HEX[(raw_hash[idx] & 0x0F) as usize];
-            }
-        }
-
-        if let Some(pos) = hex_hash
-            .windows(3)
-            .position(|w| w[0] == w[1] && w[1] == w[2])
-        {
-            triples
-                .entry(hex_hash[pos])
-                .or_insert(VecDeque::new())
-                .push_back(sequence);
-        }
-
-        if let Some(pos) = hex_hash
-            .windows(5)
-            .position(|w| w[0] == w[1] && w[0] == w[2] && w[0] == w[3] && w[0] == w[4])
-        {
-            if let Some(positions) = triples.get_mut(&hex_hash[pos]) {
-                while let Some(pos) = positions.front().copied() {
-                    if sequence == pos {
-                        break;
-                    }
-
-                    if sequence - pos <= 1000 {
-                        remaining -= 1;
-                        if remaining == 0 {
-                            return pos;
-                        }
-                    }
-
-                    positions.pop_front();
-                }
+        if is_key {
+            remaining -= 1;
+            if remaining == 0 {
+                return index;
             }
         }
     }
@@ -135,6 +36,16 @@ pub fn part_two(input: &str) -> u32 {
     unreachable!()
 }
 
+fn first_triple(hash: &HexDigest) -> Option<u8> {
+    hash.windows(3)
+        .find(|w| w[0] == w[1] && w[1] == w[2])
+        .map(|w| w[0])
+}
+
+fn has_quintuple(hash: &HexDigest, symbol: u8) -> bool {
+    hash.windows(5).any(|w| w.iter().all(|&x| x == symbol))
+}
+
 #[cfg(test)]
 mod tests {
     use aoc_shared::input::load_text_input_from_file;
@@ -1,6 +1,7 @@
 use std::iter::once;
 use std::str::FromStr;
 
+use aoc_shared::math::crt;
 use lazy_static::lazy_static;
 use regex::Regex;
 
@@ -61,62 +62,28 @@ impl FromStr for Disc {
 }
 
 pub fn part_one(input: &[Disc]) -> u32 {
-    let mut time = 0;
-    loop {
-        let mut increment = 1;
-        let mut discs = input.len();
-
-        for (delay, disc) in input.iter().copied().enumerate() {
-            if (disc.position + time + 1 + delay as u32) % disc.positions != 0 {
-                break;
-            }
-
-            // works, because the positions are co-prime
-            increment *= disc.positions;
-            discs -= 1;
-        }
-
-        if discs == 0 {
-            break time;
-        }
-
-        time += increment;
-    }
+    solve(input.iter().copied())
 }
 
 pub fn part_two(input: &[Disc]) -> u32 {
-    let mut time = 0;
-    let total_discs = input.len() + 1;
-
-    loop {
-        let input = input
-            .iter()
-            .copied()
-            .chain(once(Disc {
-                positions: 11,
-                position: 0,
-            }))
-            .enumerate();
-
-        let mut increment = 1;
-        let mut discs = total_discs;
-
-        for (delay, disc) in input {
-            if (disc.position + time + 1 + delay as u32) % disc.positions != 0 {
-                break;
-            }
-
-            // works, because the positions are co-prime
-            increment *= disc.positions;
-            discs -= 1;
-        }
-
-        if discs == 0 {
-            break time;
-        }
-
-        time += increment;
-    }
+    let extra = Disc {
+        positions: 11,
+        position: 0,
+    };
+
+    solve(input.iter().copied().chain(once(extra)))
+}
+
+fn solve(discs: impl Iterator<Item = Disc>) -> u32 {
+    // The capsule reaches the disc with index `delay` at `time + delay + 1`,
+    // so we need: position + time + delay + 1 = 0 (mod positions)
+    let congruences = discs.enumerate().map(|(delay, disc)| {
+        let offset = disc.position as i128 + delay as i128 + 1;
+        (-offset, disc.positions as i128)
+    });
+
+    let (time, _) = crt(congruences).unwrap();
+    time as u32
 }
 
 #[cfg(test)]
@@ -1,27 +1,24 @@
 use std::collections::VecDeque;
 
-use md5::Digest;
-
-// (row, column, raw_hex[index], bit-shift, path-letter)
-const DIR: &[(i32, i32, usize, u8, u8)] = &[
-    (-1, 0, 0, 4, b'U'),
-    (1, 0, 0, 0, b'D'),
-    (0, -1, 1, 4, b'L'),
-    (0, 1, 1, 0, b'R'),
+use aoc_shared::md5::{nibble, Prefix};
+
+// (row, column, hex digit, path-letter)
+const DIR: &[(i32, i32, usize, u8)] = &[
+    (-1, 0, 0, b'U'),
+    (1, 0, 1, b'D'),
+    (0, -1, 2, b'L'),
+    (0, 1, 3, b'R'),
 ];
 
 pub fn part_one(input: &str) -> String {
     let mut queue = VecDeque::new();
-    let mut hasher = md5::Md5::default();
-    let mut raw_hash = Default::default();
+    let passcode = Prefix::new(input);
 
     queue.push_back((0u32, 0u32, vec![]));
     while let Some((r, c, path)) = queue.pop_front() {
-        hasher.update(input);
-        hasher.update(&path);
-        hasher.finalize_into_reset(&mut raw_hash);
+        let hash = passcode.hash(&path);
 
-        for (dr, dc, idx, shift, p) in DIR.iter().copied() {
+        for (dr, dc, idx, p) in DIR.iter().copied() {
             let Some(rx) = r.checked_add_signed(dr) else {
                 continue;
             };
@@ -32,7 +29,7 @@ pub fn part_one(input: &str) -> String {
                 continue;
             }
 
-            let value = (raw_hash[idx] >> shift) & 0x0F;
+            let value = nibble(&hash, idx);
             if !(0x0B..=0x0F).contains(&value) {
                 continue;
             }
@@ -60,16 +57,13 @@ pub fn part_two(input: &str) -> usize {
     let mut attempts = MAX_ATTEMPTS;
 
     let mut queue = VecDeque::new();
-    let mut hasher = md5::Md5::default();
-    let mut raw_hash = Default::default();
+    let passcode = Prefix::new(input);
 
     queue.push_back((0u32, 0u32, vec![]));
     while let Some((r, c, path)) = queue.pop_front() {
-        hasher.update(input);
-        hasher.update(&path);
-        hasher.finalize_into_reset(&mut raw_hash);
+        let hash = passcode.hash(&path);
 
-        for (dr, dc, idx, shift, p) in DIR.iter().copied() {
+        for (dr, dc, idx, p) in DIR.iter().copied() {
             let Some(rx) = r.checked_add_signed(dr) else {
                 continue;
             };
@@ -80,7 +74,7 @@ pub fn part_two(input: &str) -> usize {
                 continue;
             }
 
-            let value = (raw_hash[idx] >> shift) & 0x0F;
+            let value = nibble(&hash, idx);
             if !(0x0B..=0x0F).contains(&value) {
                 continue;
             }
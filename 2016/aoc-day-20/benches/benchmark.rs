@@ -1,4 +1,5 @@
-use aoc_shared::input::load_line_delimited_input_from_file;
+use aoc_shared::input_locator;
+use aoc_shared::parsing::parse_line_delimited;
 use criterion::{criterion_group, criterion_main, Criterion};
 
 use aoc_day_20::{part_one, part_two};
@@ -7,13 +8,13 @@ criterion_group!(benches, benchmark_part_one, benchmark_part_two);
 criterion_main!(benches);
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_line_delimited_input_from_file("inputs/input.txt");
+    let input = parse_line_delimited(input_locator!(2016, 20).load().unwrap());
 
     c.bench_function("part-1", |b| b.iter(|| part_one(&input)));
 }
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_line_delimited_input_from_file("inputs/input.txt");
+    let input = parse_line_delimited(input_locator!(2016, 20).load().unwrap());
 
     c.bench_function("part-2", |b| b.iter(|| part_two(&input)));
 }
@@ -1,4 +1,4 @@
-use aoc_shared::input::{load_line_delimited_input_from_file, load_text_input_from_file};
+use aoc_shared::input_locator;
 use aoc_shared::parsing::parse_line_delimited;
 use criterion::{criterion_group, criterion_main, Criterion};
 
@@ -13,17 +13,17 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2016, 21).load().unwrap();
     c.bench_function("parsing", |b| {
         b.iter(|| parse_line_delimited::<_, Instruction>(&input))
     });
 }
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_line_delimited_input_from_file("inputs/input.txt");
+    let input = parse_line_delimited(input_locator!(2016, 21).load().unwrap());
     c.bench_function("part-1", |b| b.iter(|| part_one(&input)));
 }
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_line_delimited_input_from_file("inputs/input.txt");
+    let input = parse_line_delimited(input_locator!(2016, 21).load().unwrap());
     c.bench_function("part-2", |b| b.iter(|| part_two(&input)));
 }
@@ -1,4 +1,4 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use aoc_shared::parsing::parse_line_delimited_after_row;
 use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
 
@@ -8,13 +8,13 @@ criterion_group!(benches, benchmark_parsing, benchmark_part_one,);
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2016, 22).load().unwrap();
     c.bench_function("parsing", |b| {
         b.iter(|| parse_line_delimited_after_row::<_, Info>(&input, 2))
     });
 }
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2016, 22).load().unwrap();
     let mut input = parse_line_delimited_after_row(input, 2);
 
     let mut g = c.benchmark_group("part-1");
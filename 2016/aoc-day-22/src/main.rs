@@ -1,13 +1,14 @@
 use std::error::Error;
 
-use aoc_shared::input::load_text_input_from_autodetect;
-use aoc_shared::parsing::parse_line_delimited_after_row;
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
+use aoc_shared::parsing::{try_parse_line_delimited_after_row, ParseError};
 
 use aoc_day_22::{part_one_v1, part_two};
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let input = load_text_input_from_autodetect();
-    let input = parse_line_delimited_after_row(input, 2);
+    let input = try_load_text_input_from_autodetect(input_locator!(2016, 22))?;
+    let input = try_parse_line_delimited_after_row(input, 2).map_err(ParseError::into_boxed)?;
     println!("Part 1: {:?}", part_one_v1(&input));
 
     part_two(&input);
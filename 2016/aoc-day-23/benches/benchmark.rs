@@ -1,4 +1,4 @@
-use aoc_shared::input::{load_line_delimited_input_from_file, load_text_input_from_file};
+use aoc_shared::input_locator;
 use aoc_shared::parsing::parse_line_delimited;
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
 
@@ -13,7 +13,7 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2016, 23).load().unwrap();
 
     c.bench_with_input(
         BenchmarkId::new("parsing", ""),
@@ -23,13 +23,13 @@ fn benchmark_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_line_delimited_input_from_file("inputs/input.txt");
+    let input = parse_line_delimited(input_locator!(2016, 23).load().unwrap());
 
     c.bench_function("part-1", |b| b.iter(|| part_one(input.as_slice())));
 }
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_line_delimited_input_from_file("inputs/input.txt");
+    let input = parse_line_delimited(input_locator!(2016, 23).load().unwrap());
 
     let mut g = c.benchmark_group("part 2");
     g.sample_size(10);
@@ -1,12 +1,13 @@
 use std::error::Error;
 
-use aoc_shared::input::load_text_input_from_autodetect;
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
 use aoc_shared::parsing::parse_u8_grid;
 
 use aoc_day_24::{part_one, part_two};
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let input = load_text_input_from_autodetect();
+    let input = try_load_text_input_from_autodetect(input_locator!(2016, 24))?;
     let input = parse_u8_grid(input);
 
     println!("Part 1: {:?}", part_one(&input));
@@ -1,3 +1,7 @@
-pub mod hashing;
+pub use aoc_core::{bitset, grid, hashing, input_locator, math, ocr, parallel, union_find};
+
+#[cfg(feature = "md5")]
+pub use aoc_core::md5;
+
 pub mod input;
 pub mod parsing;
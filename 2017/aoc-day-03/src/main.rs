@@ -1,9 +1,10 @@
 use aoc_day_03::{part_one, part_two};
-use aoc_shared::input::load_text_input_from_autodetect;
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
 use std::error::Error;
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let input = load_text_input_from_autodetect();
+    let input = try_load_text_input_from_autodetect(input_locator!(2017, 3))?;
     let input = input.trim().parse()?;
 
     println!("Part 1: {:?}", part_one(input));
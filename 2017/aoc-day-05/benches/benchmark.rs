@@ -1,4 +1,5 @@
-use aoc_shared::input::load_line_delimited_input_from_file;
+use aoc_shared::input_locator;
+use aoc_shared::parsing::parse_line_delimited;
 use criterion::{criterion_group, criterion_main, Criterion};
 
 use aoc_day_05::{part_one, part_two};
@@ -7,7 +8,7 @@ criterion_group!(benches, benchmark_part_one, benchmark_part_two);
 criterion_main!(benches);
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_line_delimited_input_from_file("inputs/input.txt");
+    let input = parse_line_delimited(input_locator!(2017, 5).load().unwrap());
 
     c.bench_function("part-1", |b| {
         b.iter(|| part_one(&input));
@@ -15,7 +16,7 @@ fn benchmark_part_one(c: &mut Criterion) {
 }
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_line_delimited_input_from_file("inputs/input.txt");
+    let input = parse_line_delimited(input_locator!(2017, 5).load().unwrap());
 
     c.bench_function("part-2", |b| {
         b.iter(|| part_two(&input));
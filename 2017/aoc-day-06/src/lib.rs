@@ -1,94 +1,45 @@
-use std::collections::{HashMap, HashSet};
+use aoc_shared::algo::cycle::{brent, Cycle};
 
 pub fn part_one(input: &[u32]) -> usize {
-    let mut seen = HashSet::new();
-    seen.insert(input.to_vec());
+    let cycle = find_cycle(input);
 
-    let mut buf = input.to_vec();
-    let mut max_val = 0;
-    let mut max_pos = 0;
-
-    for idx in 0..buf.len() {
-        if buf[idx] > max_val {
-            max_val = buf[idx];
-            max_pos = idx;
-        }
-    }
-
-    loop {
-        let val = buf[max_pos];
-        buf[max_pos] = 0;
-
-        let increment = val / buf.len() as u32;
-        let mut remaining = val - increment * buf.len() as u32;
-
-        let offset = max_pos + 1;
-        max_val = buf[0];
-        max_pos = 0;
-
-        for idx in 0..buf.len() {
-            let pos = (offset + idx) % buf.len();
-
-            buf[pos] += increment + (remaining > 0) as u32;
-            remaining = remaining.saturating_sub(1);
-
-            if buf[pos] > max_val || (buf[pos] == max_val && pos < max_pos) {
-                max_val = buf[pos];
-                max_pos = pos;
-            }
-        }
+    // The number of redistributions until a configuration repeats
+    cycle.mu + cycle.lambda
+}
 
-        if !seen.insert(buf.to_vec()) {
-            break;
-        }
-    }
+pub fn part_two(input: &[u32]) -> usize {
+    find_cycle(input).lambda
+}
 
-    seen.len()
+fn find_cycle(input: &[u32]) -> Cycle {
+    brent(input.to_vec(), |banks| redistribute(banks))
 }
 
-pub fn part_two(input: &[u32]) -> usize {
-    let mut seen = HashMap::new();
-    seen.insert(input.to_vec(), 0);
+fn redistribute(banks: &[u32]) -> Vec<u32> {
+    let mut buf = banks.to_vec();
 
-    let mut buf = input.to_vec();
-    let mut max_val = 0;
     let mut max_pos = 0;
-
-    for idx in 0..buf.len() {
-        if buf[idx] > max_val {
-            max_val = buf[idx];
+    for idx in 1..buf.len() {
+        if buf[idx] > buf[max_pos] {
             max_pos = idx;
         }
     }
 
-    loop {
-        let val = buf[max_pos];
-        buf[max_pos] = 0;
-
-        let increment = val / buf.len() as u32;
-        let mut remaining = val - increment * buf.len() as u32;
-
-        let offset = max_pos + 1;
-        max_val = buf[0];
-        max_pos = 0;
-
-        for idx in 0..buf.len() {
-            let pos = (offset + idx) % buf.len();
+    let val = buf[max_pos];
+    buf[max_pos] = 0;
 
-            buf[pos] += increment + (remaining > 0) as u32;
-            remaining = remaining.saturating_sub(1);
+    let increment = val / buf.len() as u32;
+    let mut remaining = val - increment * buf.len() as u32;
 
-            if buf[pos] > max_val || (buf[pos] == max_val && pos < max_pos) {
-                max_val = buf[pos];
-                max_pos = pos;
-            }
-        }
+    let offset = max_pos + 1;
+    for idx in 0..buf.len() {
+        let pos = (offset + idx) % buf.len();
 
-        let sequence = seen.len();
-        if let Some(start) = seen.insert(buf.to_vec(), sequence) {
-            return sequence - start;
-        }
+        buf[pos] += increment + (remaining > 0) as u32;
+        remaining = remaining.saturating_sub(1);
     }
+
+    buf
 }
 
 #[cfg(test)]
@@ -121,4 +72,10 @@ mod tests {
 
         assert_eq!(1610, answer);
     }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(5, part_one(&[0, 2, 7, 0]));
+        assert_eq!(4, part_two(&[0, 2, 7, 0]));
+    }
 }
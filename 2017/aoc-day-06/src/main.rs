@@ -1,15 +1,16 @@
 use std::error::Error;
 
-use aoc_shared::input::load_text_input_from_autodetect;
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
 
 use aoc_day_06::{part_one, part_two};
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let input = load_text_input_from_autodetect();
+    let input = try_load_text_input_from_autodetect(input_locator!(2017, 6))?;
     let input = input
         .split_whitespace()
-        .map(|x| x.parse().unwrap())
-        .collect::<Vec<_>>();
+        .map(|x| x.parse())
+        .collect::<Result<Vec<_>, _>>()?;
 
     println!("Part 1: {:?}", part_one(&input));
     println!("Part 2: {:?}", part_two(&input));
@@ -1,4 +1,4 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{criterion_group, criterion_main, Criterion};
 
 use aoc_day_08::{parse_input, part_one, part_two};
@@ -12,7 +12,7 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2017, 8).load().unwrap();
 
     c.bench_function("parsing", |b| {
         b.iter_with_large_drop(|| parse_input(&input));
@@ -20,7 +20,7 @@ fn benchmark_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2017, 8).load().unwrap();
     let input = parse_input(&input);
 
     c.bench_function("part-1", |b| {
@@ -29,7 +29,7 @@ fn benchmark_part_one(c: &mut Criterion) {
 }
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2017, 8).load().unwrap();
     let input = parse_input(&input);
 
     c.bench_function("part-2", |b| {
@@ -1,4 +1,4 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{criterion_group, criterion_main, Criterion};
 
 use aoc_day_09::part_one_and_two;
@@ -7,7 +7,7 @@ criterion_group!(benches, benchmark_part_one_and_two,);
 criterion_main!(benches);
 
 fn benchmark_part_one_and_two(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2017, 9).load().unwrap();
     let input = input.trim();
 
     c.bench_function("part-1&2", |b| {
@@ -1,13 +1,14 @@
 use std::error::Error;
 
-use aoc_shared::input::load_text_input_from_autodetect;
-use aoc_shared::parsing::parse_csv;
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
+use aoc_shared::parsing::{try_parse_csv, ParseError};
 
 use aoc_day_11::{part_one, part_two};
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let input = load_text_input_from_autodetect();
-    let parsed = parse_csv(input);
+    let input = try_load_text_input_from_autodetect(input_locator!(2017, 11))?;
+    let parsed = try_parse_csv(input).map_err(ParseError::into_boxed)?;
 
     println!("Part 1: {:?}", part_one(&parsed));
     println!("Part 2: {:?}", part_two(&parsed));
@@ -1,9 +1,10 @@
 use aoc_day_15::{parse_input, part_one, part_two};
-use aoc_shared::input::load_text_input_from_autodetect;
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
 use std::error::Error;
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let input = load_text_input_from_autodetect();
+    let input = try_load_text_input_from_autodetect(input_locator!(2017, 15))?;
     let (a, b) = parse_input(&input);
 
     println!("Part 1: {:?}", part_one(a, b));
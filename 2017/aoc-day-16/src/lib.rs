@@ -1,7 +1,8 @@
-use std::collections::HashMap;
 use std::error::Error;
 use std::str::FromStr;
 
+use aoc_shared::algo::cycle::find_by_key;
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum Op {
     Spin(usize),
@@ -14,7 +15,7 @@ impl FromStr for Op {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s.is_empty() {
-            return Err(format!("the empty string is not a valid operation").into());
+            return Err("the empty string is not a valid operation".into());
         }
 
         Ok(match &s[..1] {
@@ -63,33 +64,26 @@ pub fn part_one(input: &[Op]) -> String {
 
 pub fn part_two(input: &[Op]) -> String {
     const LEN: usize = 16;
-    let mut data: [usize; LEN] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
-    let mut index: [usize; LEN] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    const DANCES: usize = 1_000_000_000;
 
-    // There is a cycle in the output of the "dance" function, thus when we find out
-    // its length, we can immediately return the result
-    let mut seen = HashMap::new();
-    seen.insert(data, 0);
+    let data: [usize; LEN] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    let index: [usize; LEN] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
 
-    let mut cycle_len = 0;
-    for cycle in 0..1_000_000_000 {
+    // There is a cycle in the output of the "dance" function, thus when we find out
+    // its length, we can skip all the full cycles and dance only the remaining rounds
+    let mut state = (data, index);
+    let cycle = find_by_key(
+        &mut state,
+        |(data, index)| dance(input, data, index),
+        |&(data, _)| data,
+    );
+
+    let (mut data, mut index) = state;
+    for _ in 0..cycle.remaining(cycle.mu + cycle.lambda, DANCES) {
         dance(input, &mut data, &mut index);
-
-        if let Some(prev_cycle) = seen.insert(data, cycle + 1) {
-            cycle_len = cycle + 1 - prev_cycle;
-            break;
-        }
     }
 
-    let target = 1_000_000_000 % cycle_len;
-    let answer = seen
-        .iter()
-        .find(|(_, &cyc)| cyc == target)
-        .map(|(array, _)| array.clone())
-        .unwrap();
-
-    answer
-        .into_iter()
+    data.into_iter()
         .map(|x| x as u8 + b'a')
         .map(|x| x as char)
         .collect()
@@ -138,4 +132,14 @@ mod tests {
         let answer = part_two(&parsed);
         assert_eq!("bfcdeakhijmlgopn", answer);
     }
+
+    #[test]
+    fn test_skipped_dances() {
+        // rotates a, b and c, and swaps d and e, so the dances repeat every 6 rounds
+        let parsed: Vec<Op> = parse_csv("x0/1,x1/2,pd/e");
+
+        assert_eq!("bcaedfghijklmnop", part_one(&parsed));
+        // 1_000_000_000 % 6 == 4
+        assert_eq!("bcadefghijklmnop", part_two(&parsed));
+    }
 }
@@ -1,4 +1,4 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{criterion_group, criterion_main, Criterion};
 
 use aoc_day_17::{part_one_deque, part_one_list, part_two_deque, part_two_idxs};
@@ -7,7 +7,7 @@ criterion_group!(benches, benchmark_part_one, benchmark_part_two);
 criterion_main!(benches);
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2017, 17).load().unwrap();
     let input = input.trim().parse().unwrap();
 
     c.bench_function("part-1: list", |b| {
@@ -20,7 +20,7 @@ fn benchmark_part_one(c: &mut Criterion) {
 }
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2017, 17).load().unwrap();
     let input = input.trim().parse().unwrap();
 
     let mut g = c.benchmark_group("part 2");
@@ -1,4 +1,4 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use aoc_shared::parsing::parse_u8_grid;
 use criterion::{criterion_group, criterion_main, Criterion};
 
@@ -8,7 +8,7 @@ criterion_group!(benches, benchmark_solution);
 criterion_main!(benches);
 
 fn benchmark_solution(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2017, 19).load().unwrap();
     let input = parse_u8_grid(input);
 
     c.bench_function("solution", |b| {
@@ -1,4 +1,4 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{criterion_group, criterion_main, Criterion};
 
 use aoc_day_25::{parse_input, part_one_v1, part_one_v2};
@@ -7,7 +7,7 @@ criterion_group!(benches, benchmark_parsing, benchmark_part_one,);
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2017, 25).load().unwrap();
 
     c.bench_function("parsing", |b| {
         b.iter(|| parse_input(&input));
@@ -15,7 +15,7 @@ fn benchmark_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2017, 25).load().unwrap();
     let input = parse_input(&input).unwrap();
 
     let mut g = c.benchmark_group("part-one");
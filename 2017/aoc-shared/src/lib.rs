@@ -1,3 +1,5 @@
-pub mod hashing;
+pub use aoc_core::{bitset, grid, hashing, input_locator, parallel, union_find};
+
+pub mod algo;
 pub mod input;
 pub mod parsing;
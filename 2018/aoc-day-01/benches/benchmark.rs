@@ -1,4 +1,5 @@
-use aoc_shared::input::load_line_delimited_input_from_file;
+use aoc_shared::input_locator;
+use aoc_shared::parsing::parse_line_delimited;
 use criterion::{Criterion, criterion_group, criterion_main};
 
 use aoc_day_01::{part_one, part_two};
@@ -12,7 +13,7 @@ criterion_main!(benches);
 
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_line_delimited_input_from_file("inputs/input.txt");
+    let input = parse_line_delimited(input_locator!(2018, 1).load().unwrap());
 
     c.bench_function("part-1", |b| {
         b.iter(|| part_one(&input));
@@ -20,7 +21,7 @@ fn benchmark_part_one(c: &mut Criterion) {
 }
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_line_delimited_input_from_file("inputs/input.txt");
+    let input = parse_line_delimited(input_locator!(2018, 1).load().unwrap());
 
     c.bench_function("part-2", |b| {
         b.iter(|| part_two(&input));
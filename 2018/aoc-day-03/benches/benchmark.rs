@@ -1,4 +1,4 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{criterion_group, criterion_main, Criterion};
 use std::hint::black_box;
 
@@ -13,12 +13,12 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2018, 3).load().unwrap();
 
     let mut g = c.benchmark_group("parsing");
     g.sample_size(10);
 
-    g.bench_function("with-regex", |b| {
+    g.bench_function("with-scan", |b| {
         b.iter(|| parse_input(black_box(input.as_str())));
     });
 
@@ -26,7 +26,7 @@ fn benchmark_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2018, 3).load().unwrap();
     let parsed = parse_input(&input).unwrap();
 
     let mut g = c.benchmark_group("part-one");
@@ -40,7 +40,7 @@ fn benchmark_part_one(c: &mut Criterion) {
 }
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2018, 3).load().unwrap();
     let parsed = parse_input(&input).unwrap();
 
     let mut g = c.benchmark_group("part-two");
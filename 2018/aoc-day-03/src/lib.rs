@@ -1,8 +1,5 @@
-use regex::Regex;
+use aoc_shared::scan;
 use std::error::Error;
-use std::sync::LazyLock;
-
-const INPUT_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"^#(?<id>\d+) @ (?<left>\d+),(?<top>\d+): (?<width>\d+)x(?<height>\d+)$"#).unwrap());
 
 #[derive(Debug)]
 pub struct InputLine {
@@ -17,13 +14,8 @@ pub fn parse_input(input: &str) -> Result<Vec<InputLine>, Box<dyn Error>> {
     let mut answer = vec![];
 
     for line in input.lines() {
-        let captures = INPUT_REGEX.captures(line).unwrap();
-
-        let id = captures.name("id").ok_or_else(|| "'id' not found")?.as_str().parse()?;
-        let left = captures.name("left").ok_or_else(|| "'left' not found")?.as_str().parse()?;
-        let top = captures.name("top").ok_or_else(|| "'top' not found")?.as_str().parse()?;
-        let width = captures.name("width").ok_or_else(|| "'width' not found")?.as_str().parse()?;
-        let height = captures.name("height").ok_or_else(|| "'height' not found")?.as_str().parse()?;
+        let (id, left, top, width, height) =
+            scan!(line, "#{} @ {},{}: {}x{}" => (usize, usize, usize, usize, usize))?;
 
         answer.push(InputLine { id, left, top, width, height });
     }
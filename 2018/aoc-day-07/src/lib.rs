@@ -1,34 +1,23 @@
-use regex::Regex;
+use aoc_shared::scan;
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 use std::error::Error;
-use std::sync::LazyLock;
 
 const ASCII_LEN: usize = (b'Z' - b'A' + 1) as usize;
 
-static REGEX_INPUT_LINE: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r#"^Step (?<FROM>[A-Z]) must be finished before step (?<TO>[A-Z]) can begin\.$"#)
-        .unwrap()
-});
-
 pub fn parse_input(input: &str) -> Result<Vec<Vec<usize>>, Box<dyn Error>> {
     let mut graph = vec![vec![]; ASCII_LEN];
 
     for line in input.lines() {
-        let Some(captures) = REGEX_INPUT_LINE.captures(line) else {
-            return Err(format!("invalid input: {}", line).into());
-        };
+        let (from, to) =
+            scan!(line, "Step {} must be finished before step {} can begin." => (char, char))?;
 
-        let Some(from) = captures.name("FROM") else {
+        if !from.is_ascii_uppercase() || !to.is_ascii_uppercase() {
             return Err(format!("invalid input: {}", line).into());
-        };
-
-        let Some(to) = captures.name("TO") else {
-            return Err(format!("invalid input: {}", line).into());
-        };
+        }
 
-        let from: usize = (from.as_str().as_bytes()[0] - b'A') as usize;
-        let to: usize = (to.as_str().as_bytes()[0] - b'A') as usize;
+        let from = (from as u8 - b'A') as usize;
+        let to = (to as u8 - b'A') as usize;
 
         graph[from].push(to);
     }
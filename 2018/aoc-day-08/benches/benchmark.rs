@@ -1,4 +1,4 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{criterion_group, criterion_main, Criterion};
 
 use aoc_day_08::{parse_input, part_one_iter, part_one_rec, part_two_iter, part_two_rec};
@@ -12,7 +12,7 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2018, 8).load().unwrap();
 
     c.bench_function("parsing", |b| {
         b.iter(|| parse_input(input.as_str()));
@@ -20,7 +20,7 @@ fn benchmark_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2018, 8).load().unwrap();
     let input = parse_input(&input).unwrap();
 
     c.bench_function("part-1/iterative", |b| {
@@ -33,7 +33,7 @@ fn benchmark_part_one(c: &mut Criterion) {
 }
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2018, 8).load().unwrap();
     let input = parse_input(&input).unwrap();
 
     c.bench_function("part-2/iterative", |b| {
@@ -1,9 +1,10 @@
 use aoc_day_08::{parse_input, part_one_iter, part_two_iter};
-use aoc_shared::input::load_text_input_from_autodetect;
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
 use std::error::Error;
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let input = load_text_input_from_autodetect();
+    let input = try_load_text_input_from_autodetect(input_locator!(2018, 8))?;
     let parsed = parse_input(&input)?;
 
     println!("Part 1: {:?}", part_one_iter(&parsed));
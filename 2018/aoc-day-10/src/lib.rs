@@ -1,3 +1,4 @@
+use aoc_shared::ocr::{recognize_text, OcrError};
 use regex::Regex;
 use std::error::Error;
 use std::sync::LazyLock;
@@ -68,7 +69,12 @@ pub fn parse_input(input: &str) -> Result<(Vec<Point>, Vec<Velocity>), Box<dyn E
     Ok((points, velocities))
 }
 
-pub fn part_one(points: &[Point], velocities: &[Velocity]) -> String {
+pub fn part_one(points: &[Point], velocities: &[Velocity]) -> Result<String, OcrError> {
+    recognize_text(&render_message(points, velocities))
+}
+
+/// Draws the stars at the moment they are the closest together
+pub fn render_message(points: &[Point], velocities: &[Velocity]) -> String {
     assert_eq!(velocities.len(), points.len());
 
     let mut points = points.to_vec();
@@ -194,6 +200,15 @@ mod tests {
 
     #[test]
     fn test_part_one() {
+        let input = load_text_input_from_file("inputs/input.txt");
+        let (points, velocities) = parse_input(&input).unwrap();
+
+        let answer = part_one(&points, &velocities);
+        assert_eq!(Ok("KZHGRJGZ".to_owned()), answer);
+    }
+
+    #[test]
+    fn test_render_message() {
         const EXPECTED: &str = "\
 █    █  ██████  █    █   ████   █████      ███   ████   ██████\n\
 █   █        █  █    █  █    █  █    █      █   █    █       █\n\
@@ -210,7 +225,7 @@ mod tests {
         let input = load_text_input_from_file("inputs/input.txt");
         let (points, velocities) = parse_input(&input).unwrap();
 
-        let answer = part_one(&points, &velocities);
+        let answer = render_message(&points, &velocities);
         assert_eq!(EXPECTED, answer);
     }
 
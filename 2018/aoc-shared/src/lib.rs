@@ -1,3 +1,4 @@
-pub mod hashing;
+pub use aoc_core::{bitset, grid, hashing, input_locator, ocr, parallel, scan, union_find};
+
 pub mod input;
 pub mod parsing;
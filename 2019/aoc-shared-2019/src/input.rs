@@ -1,16 +1,38 @@
-use crate::parsing::{parse_csv, parse_line_delimited};
+use crate::parsing::{
+    parse_csv, parse_line_delimited, try_parse_csv, try_parse_line_delimited, ParseError,
+};
+use std::error::Error;
 use std::fmt::Debug;
 use std::fs::File;
 use std::io::{BufRead, BufReader, Read};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
-pub fn load_text_input_from_autodetect() -> String {
-    load_text_input(auto_select_input())
+pub use aoc_core::input::{normalize_input, InputKind, InputLocator, INPUT_DIR_ENV};
+
+pub fn load_text_input_from_autodetect(locator: InputLocator) -> String {
+    try_load_text_input_from_autodetect(locator).unwrap()
+}
+
+pub fn try_load_text_input_from_autodetect(locator: InputLocator) -> std::io::Result<String> {
+    try_load_text_input(try_auto_select_input(locator)?)
 }
 
-pub fn load_line_delimited_input_from_autodetect<O: FromStr<Err = impl Debug>>() -> Vec<O> {
-    parse_line_delimited(load_text_input_from_autodetect())
+pub fn load_line_delimited_input_from_autodetect<O: FromStr<Err = impl Debug>>(
+    locator: InputLocator,
+) -> Vec<O> {
+    parse_line_delimited(load_text_input_from_autodetect(locator))
+}
+
+pub fn try_load_line_delimited_input_from_autodetect<O>(
+    locator: InputLocator,
+) -> Result<Vec<O>, Box<dyn Error>>
+where
+    O: FromStr,
+    O::Err: Into<Box<dyn Error>>,
+{
+    let input = try_load_text_input_from_autodetect(locator)?;
+    Ok(try_parse_line_delimited(input).map_err(ParseError::into_boxed)?)
 }
 
 pub fn load_line_delimited_input_from_file<O: FromStr<Err = impl Debug>, P>(path: P) -> Vec<O>
@@ -20,8 +42,21 @@ where
     parse_line_delimited(load_text_input_from_file(path))
 }
 
-pub fn load_csv_input_from_autodetect<O: FromStr<Err = impl Debug>>() -> Vec<O> {
-    parse_csv(load_text_input_from_autodetect())
+pub fn load_csv_input_from_autodetect<O: FromStr<Err = impl Debug>>(
+    locator: InputLocator,
+) -> Vec<O> {
+    parse_csv(load_text_input_from_autodetect(locator))
+}
+
+pub fn try_load_csv_input_from_autodetect<O>(
+    locator: InputLocator,
+) -> Result<Vec<O>, Box<dyn Error>>
+where
+    O: FromStr,
+    O::Err: Into<Box<dyn Error>>,
+{
+    let input = try_load_text_input_from_autodetect(locator)?;
+    Ok(try_parse_csv(input).map_err(ParseError::into_boxed)?)
 }
 
 pub fn load_csv_input_from_file<O: FromStr<Err = impl Debug>, P>(path: P) -> Vec<O>
@@ -36,20 +71,44 @@ pub fn load_text_input_from_stdin() -> String {
 }
 
 pub fn load_text_input_from_file<P: AsRef<Path>>(path: P) -> String {
-    load_text_input(File::open(path).unwrap())
+    try_load_text_input_from_file(path).unwrap()
 }
 
-pub fn auto_select_input() -> Box<dyn BufRead> {
-    match std::env::args().skip(1).next() {
-        None => Box::new(BufReader::new(std::io::stdin())),
-        Some(path) => Box::new(BufReader::new(
-            File::open(&path).expect(&format!("file path: {}", &path)),
+pub fn try_load_text_input_from_file<P: AsRef<Path>>(path: P) -> std::io::Result<String> {
+    let path = path.as_ref();
+    File::open(path)
+        .and_then(try_load_text_input)
+        .map_err(|e| std::io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))
+}
+
+pub fn auto_select_input(locator: InputLocator) -> Box<dyn BufRead> {
+    try_auto_select_input(locator).unwrap()
+}
+
+/// Reads from the file passed as first argument, from stdin if the argument is `-`,
+/// or from the input file found by `locator` if there is no argument
+pub fn try_auto_select_input(locator: InputLocator) -> std::io::Result<Box<dyn BufRead>> {
+    let path = match std::env::args().nth(1) {
+        Some(arg) if arg == "-" => return Ok(Box::new(BufReader::new(std::io::stdin()))),
+        Some(arg) => PathBuf::from(arg),
+        None => locator.locate()?,
+    };
+
+    match File::open(&path) {
+        Ok(file) => Ok(Box::new(BufReader::new(file))),
+        Err(e) => Err(std::io::Error::new(
+            e.kind(),
+            format!("{}: {}", path.display(), e),
         )),
     }
 }
 
-pub fn load_text_input<R: Read>(mut input: R) -> String {
+pub fn load_text_input<R: Read>(input: R) -> String {
+    try_load_text_input(input).unwrap()
+}
+
+pub fn try_load_text_input<R: Read>(mut input: R) -> std::io::Result<String> {
     let mut buffer = String::new();
-    input.read_to_string(&mut buffer).unwrap();
-    buffer
+    input.read_to_string(&mut buffer)?;
+    Ok(buffer)
 }
@@ -1,8 +1,9 @@
 use aoc_shared_2019::input::load_text_input_from_autodetect;
+use aoc_shared_2019::input_locator;
 use day_04::{parse_input, part_one, part_two};
 
 fn main() {
-    let (lo, hi) = parse_input(load_text_input_from_autodetect());
+    let (lo, hi) = parse_input(load_text_input_from_autodetect(input_locator!(2019, 4)));
     println!("Part 1: {}", part_one(lo, hi));
     println!("Part 2: {}", part_two(lo, hi));
 }
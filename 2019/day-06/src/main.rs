@@ -1,8 +1,9 @@
 use aoc_shared_2019::input::load_text_input_from_autodetect;
+use aoc_shared_2019::input_locator;
 use day_06::{parse_input, part_one, part_two};
 
 fn main() {
-    let raw_input = load_text_input_from_autodetect();
+    let raw_input = load_text_input_from_autodetect(input_locator!(2019, 6));
     let orbits = parse_input(&raw_input);
 
     println!("Part 1: {}", part_one(&orbits));
@@ -1,8 +1,9 @@
 use aoc_shared_2019::input::load_text_input_from_autodetect;
+use aoc_shared_2019::input_locator;
 use day_08::{parse_input, part_one, part_two, COLS, ROWS};
 
 fn main() {
-    let input = parse_input(load_text_input_from_autodetect());
+    let input = parse_input(load_text_input_from_autodetect(input_locator!(2019, 8)));
     println!("Part 1: {}", part_one(&input, ROWS, COLS));
     println!("Part 2:\n{}", part_two(&input, ROWS, COLS));
 }
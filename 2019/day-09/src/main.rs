@@ -1,8 +1,9 @@
 use aoc_shared_2019::input::load_csv_input_from_autodetect;
+use aoc_shared_2019::input_locator;
 use day_09::{part_one, part_two};
 
 fn main() {
-    let input = load_csv_input_from_autodetect();
+    let input = load_csv_input_from_autodetect(input_locator!(2019, 9));
     println!("Part 1: {}", part_one(&input));
     println!("Part 2: {}", part_two(&input));
 }
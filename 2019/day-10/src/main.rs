@@ -1,8 +1,9 @@
 use aoc_shared_2019::input::load_text_input_from_autodetect;
+use aoc_shared_2019::input_locator;
 use day_10::{parse_input, part_one, part_two};
 
 fn main() {
-    let input = load_text_input_from_autodetect();
+    let input = load_text_input_from_autodetect(input_locator!(2019, 10));
     let parsed = parse_input(&input);
     println!("Part 1: {}", part_one(&parsed));
     println!("Part 2: {}", part_two(&parsed));
@@ -42,7 +42,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let input = parse_line_delimited(&input);
 
         let solution = part_one::solve_bruteforce(&input);
@@ -54,7 +54,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let input = parse_line_delimited(&input);
 
         let solution = part_two::solve_with_bruteforce(&input);
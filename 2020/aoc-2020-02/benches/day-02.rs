@@ -7,7 +7,7 @@ criterion_group!(benches, part_one, part_two);
 criterion_main!(benches);
 
 fn part_one(c: &mut Criterion) {
-    let input = load_input(default_test_input(DAY));
+    let input = load_input(default_test_input!(DAY));
 
     c.bench_with_input(BenchmarkId::new("day-02-p01", ""), &input, |b, i| {
         b.iter(|| black_box(part_one::solve(i)))
@@ -15,7 +15,7 @@ fn part_one(c: &mut Criterion) {
 }
 
 fn part_two(c: &mut Criterion) {
-    let input = load_input(default_test_input(DAY));
+    let input = load_input(default_test_input!(DAY));
 
     c.bench_with_input(BenchmarkId::new("day-02-p02", ""), &input, |b, i| {
         b.iter(|| black_box(part_two::solve(i)))
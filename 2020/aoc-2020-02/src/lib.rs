@@ -27,14 +27,14 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let solution = part_one::solve(&input);
         assert_eq!(548, solution);
     }
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let solution = part_two::solve(&input);
         assert_eq!(502, solution);
     }
@@ -32,14 +32,14 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let solution = part_one::solve(input.as_bytes());
         assert_eq!(211, solution);
     }
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let solution = part_two::solve(input.as_bytes());
         assert_eq!(3584591857, solution);
     }
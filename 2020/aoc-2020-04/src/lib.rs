@@ -23,7 +23,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let solution = part_two::solve(&input);
         assert_eq!(188, solution);
     }
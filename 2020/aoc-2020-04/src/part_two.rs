@@ -1,3 +1,4 @@
+use aoc_2020_common::blocks::blocks;
 use nom::branch::{alt, permutation};
 use nom::bytes::complete::take_while;
 use nom::character::complete::char;
@@ -11,9 +12,8 @@ use nom::{
 
 pub fn solve(input: &str) -> usize {
     let mut valid_passports = 0;
-    let passports = input.split("\n\n");
-    for data in passports {
-        match parse_passport(data.trim()) {
+    for data in blocks(input) {
+        match parse_passport(data.text) {
             Ok((_, passport)) => {
                 if passport.is_valid() {
                     valid_passports += 1;
@@ -26,7 +26,7 @@ pub fn solve(input: &str) -> usize {
 
             Err(_e) => {
                 if cfg!(debug_assertions) {
-                    println!("Malformed passport data: {:?}:\n{}\n", _e, data);
+                    println!("Malformed passport data: {:?}:\n{}\n", _e, data.text);
                 }
             }
         }
@@ -45,6 +45,7 @@ const TAG_HEIGHT: &str = "hgt";
 const KV_SEPARATOR: char = ':';
 const SEP_SPACE: char = ' ';
 const SEP_NEW_LINE: char = '\n';
+const SEP_CRLF: &str = "\r\n";
 
 #[derive(Debug, Eq, PartialEq)]
 struct Year {
@@ -342,6 +343,7 @@ fn is_digit(c: char) -> bool {
 
 fn remove_trailing_whitespace(input: &str) -> IResult<&str, ()> {
     let (remaining, _) = alt((
+        tag(SEP_CRLF),
         take_while_m_n(1, 1, |x| x == SEP_NEW_LINE),
         take_while(|x| x == SEP_SPACE),
     ))(input)?;
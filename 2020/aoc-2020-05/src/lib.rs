@@ -36,14 +36,14 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let solution = part_one::solve(input.as_bytes());
         assert_eq!(906, solution);
     }
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
 
         let solution = part_two::solve_v1(input.as_bytes());
         assert_eq!(Some(519), solution);
@@ -114,7 +114,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
 
         let solution = part_one::solve_iter(input.as_str());
         assert_eq!(6443, solution);
@@ -125,7 +125,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
 
         let solution = part_two::solve_iter(input.as_str());
         assert_eq!(3232, solution);
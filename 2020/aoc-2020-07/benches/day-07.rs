@@ -7,7 +7,7 @@ criterion_group!(benches, input_parsing, part_one, part_two);
 criterion_main!(benches);
 
 fn input_parsing(c: &mut Criterion) {
-    let input = load_input(default_test_input(DAY));
+    let input = load_input(default_test_input!(DAY));
 
     c.bench_with_input(
         BenchmarkId::new("day-07-p01-v1_recursive-parsing", ""),
@@ -29,7 +29,7 @@ fn input_parsing(c: &mut Criterion) {
 }
 
 fn part_one(c: &mut Criterion) {
-    let input = load_input(default_test_input(DAY));
+    let input = load_input(default_test_input!(DAY));
 
     let input_v1 = part_one_recursive::parse_input(&input);
     c.bench_with_input(
@@ -45,7 +45,7 @@ fn part_one(c: &mut Criterion) {
 }
 
 fn part_two(c: &mut Criterion) {
-    let input = load_input(DEFAULT_INPUT_PATH);
+    let input = load_input(default_test_input!(DAY));
 
     let input = part_two::parse_input(&input);
     c.bench_with_input(BenchmarkId::new("day-07-p02", ""), &input, |b, i| {
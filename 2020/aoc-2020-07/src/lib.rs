@@ -38,7 +38,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
 
         let parsed = part_one_recursive::parse_input(&input);
         let solution = part_one_recursive::solve_v1(&parsed);
@@ -51,7 +51,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
 
         let parsed = part_two::parse_input(&input);
         let solution = part_two::solve(&parsed);
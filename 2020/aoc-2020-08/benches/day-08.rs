@@ -7,7 +7,7 @@ criterion_group!(benches, input_parsing, part_one, part_two);
 criterion_main!(benches);
 
 fn input_parsing(c: &mut Criterion) {
-    let input = load_input(default_test_input(DAY));
+    let input = load_input(default_test_input!(DAY));
 
     c.bench_with_input(
         BenchmarkId::new("day-08-p01-parsing", ""),
@@ -23,7 +23,7 @@ fn input_parsing(c: &mut Criterion) {
 }
 
 fn part_one(c: &mut Criterion) {
-    let input = load_input(default_test_input(DAY));
+    let input = load_input(default_test_input!(DAY));
 
     let input_v1 = part_one::parse_input(&input);
     c.bench_with_input(BenchmarkId::new("day-08-p01", ""), &input_v1, |b, i| {
@@ -32,7 +32,7 @@ fn part_one(c: &mut Criterion) {
 }
 
 fn part_two(c: &mut Criterion) {
-    let raw_input = load_input(default_test_input(DAY));
+    let raw_input = load_input(default_test_input!(DAY));
 
     let input = part_two::parse_input(&raw_input);
     c.bench_with_input(BenchmarkId::new("day-08-p02-plain", ""), &input, |b, i| {
@@ -8,7 +8,7 @@ criterion_group!(benches, part_one, part_two);
 criterion_main!(benches);
 
 fn part_one(c: &mut Criterion) {
-    let input = load_input(default_test_input(DAY));
+    let input = load_input(default_test_input!(DAY));
     let input = parse_line_delimited(&input);
 
     c.bench_with_input(BenchmarkId::new("day-09-p01", ""), &input, |b, i| {
@@ -17,7 +17,7 @@ fn part_one(c: &mut Criterion) {
 }
 
 fn part_two(c: &mut Criterion) {
-    let input = load_input(default_test_input(DAY));
+    let input = load_input(default_test_input!(DAY));
     let input = parse_line_delimited(&input);
 
     let key = part_one::solve(&input).unwrap();
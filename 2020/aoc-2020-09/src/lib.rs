@@ -36,7 +36,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let input = parse_line_delimited(&input);
 
         let solution = part_one::solve(&input);
@@ -45,7 +45,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let input = parse_line_delimited(&input);
 
         let solution = part_two::solve(&input, PART_ONE_KEY);
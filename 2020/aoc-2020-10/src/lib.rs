@@ -36,7 +36,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let input = parse_line_delimited(&input);
 
         let solution = part_one::solve_v1(&mut input.clone());
@@ -48,7 +48,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let input = parse_line_delimited(&input);
 
         let solution = part_two::solve_v1(&mut input.clone());
@@ -7,7 +7,7 @@ criterion_group!(benches, input_parsing, part_one, part_two);
 criterion_main!(benches);
 
 fn input_parsing(c: &mut Criterion) {
-    let input = load_input(default_test_input(DAY));
+    let input = load_input(default_test_input!(DAY));
 
     c.bench_with_input(
         BenchmarkId::new("day-11-p01-parsing", ""),
@@ -23,7 +23,7 @@ fn input_parsing(c: &mut Criterion) {
 }
 
 fn part_one(c: &mut Criterion) {
-    let raw_input = load_input(default_test_input(DAY));
+    let raw_input = load_input(default_test_input!(DAY));
     let input = parse_input(&raw_input);
 
     c.bench_with_input(BenchmarkId::new("day-11-p01-v1", ""), &input, |b, i| {
@@ -41,7 +41,7 @@ fn part_one(c: &mut Criterion) {
 }
 
 fn part_two(c: &mut Criterion) {
-    let input = load_input(default_test_input(DAY));
+    let input = load_input(default_test_input!(DAY));
     let input = parse_input(&input);
 
     c.bench_with_input(BenchmarkId::new("day-11-p02", ""), &input, |b, i| {
@@ -83,7 +83,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
 
         let mut grid = parse_input(&input);
         let solution = part_one_v1::solve(&mut grid);
@@ -100,7 +100,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
 
         let mut grid = parse_input(&input);
         let solution = part_two::solve(&mut grid);
@@ -30,7 +30,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
 
         let commands = part_one::parse_input_data(&input);
         let solution = part_one::solve(&commands);
@@ -39,7 +39,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
 
         let directions = part_two::parse_input_data(&input);
         let solution = part_two::solve(&directions);
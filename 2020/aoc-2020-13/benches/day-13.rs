@@ -7,7 +7,7 @@ criterion_group!(benches, part_one, part_two);
 criterion_main!(benches);
 
 fn part_one(c: &mut Criterion) {
-    let input = load_input(default_test_input(DAY));
+    let input = load_input(default_test_input!(DAY));
     let (arrival, schedule) = part_one::parse_input_data(&input);
 
     c.bench_with_input(
@@ -18,7 +18,7 @@ fn part_one(c: &mut Criterion) {
 }
 
 fn part_two(c: &mut Criterion) {
-    let input = load_input(default_test_input(DAY));
+    let input = load_input(default_test_input!(DAY));
     let input = part_two::parse_input_data(&input);
 
     c.bench_with_input(BenchmarkId::new("day-13-p02", ""), &input, |b, i| {
@@ -30,7 +30,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let raw_input = load_input(default_test_input(DAY));
+        let raw_input = load_input(default_test_input!(DAY));
 
         let (arrival_time, schedule) = part_one::parse_input_data(&raw_input);
         let solution = part_one::solve(arrival_time, &schedule);
@@ -39,7 +39,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let raw_input = load_input(default_test_input(DAY));
+        let raw_input = load_input(default_test_input!(DAY));
 
         let schedule = part_two::parse_input_data(&raw_input);
         let solution = part_two::solve(&schedule);
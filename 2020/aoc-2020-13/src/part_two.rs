@@ -1,4 +1,4 @@
-use num_integer::lcm;
+use aoc_2020_common::math::crt;
 
 #[derive(Debug, Copy, Clone)]
 pub struct Bus {
@@ -34,18 +34,12 @@ pub fn parse_input_data(input: &str) -> Vec<Bus> {
 }
 
 pub fn solve(busses: &[Bus]) -> u64 {
-    let mut solution = busses[0].interval;
-    let mut step = busses[0].interval;
-
-    for bus in busses.iter().skip(1).copied() {
-        while (solution + bus.offset) % bus.interval != 0 {
-            solution += step;
-        }
-
-        // the solution must be increments of LCM of all encountered
-        // intervals up to this point
-        step = lcm(step, bus.interval);
-    }
-
-    solution
+    // Each bus must depart `offset` minutes after the solution, i.e.:
+    // solution + offset = 0 (mod interval)
+    let congruences = busses
+        .iter()
+        .map(|bus| (-(bus.offset as i128), bus.interval as i128));
+
+    let (solution, _) = crt(congruences).unwrap();
+    solution as u64
 }
@@ -145,7 +145,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let input = parse_input(&input);
 
         let solution = part_one::solve(&input);
@@ -154,7 +154,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let input = parse_input(&input);
 
         let solution = part_two::solve(&input);
@@ -127,7 +127,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let input = parse_csv(&input);
 
         let solution = solve_v1(&input, MAX_TURNS_PART_ONE);
@@ -142,7 +142,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let input = parse_csv(&input);
 
         let solution = solve_v1(&input, MAX_TURNS_PART_TWO);
@@ -136,7 +136,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let mut input = parse_input(&input);
 
         let solution = part_one::solve(&mut input);
@@ -145,7 +145,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let mut input = parse_input(&input);
         part_one::solve(&mut input); // removes the invalid entries
 
@@ -30,7 +30,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
 
         let mut data = part_one::parse_input(&input);
         let solution = part_one::solve(&mut data);
@@ -39,7 +39,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
 
         let mut data = part_two::parse_input(&input);
         let solution = part_two::solve(&mut data);
@@ -0,0 +1,123 @@
+use std::fmt::{Display, Formatter};
+
+use crate::{apply_function, ExpressionError, Function, Operand, Reduce};
+
+/// An expression as a tree of functions
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Ast {
+    Operand(Operand),
+    Unary {
+        function: Function,
+        /// The column of the function in the expression
+        column: usize,
+        operand: Box<Ast>,
+    },
+    Binary {
+        function: Function,
+        column: usize,
+        left: Box<Ast>,
+        right: Box<Ast>,
+    },
+}
+
+impl Ast {
+    pub fn evaluate(&self) -> Result<Operand, ExpressionError> {
+        match self {
+            Ast::Operand(value) => Ok(*value),
+            Ast::Unary {
+                column, operand, ..
+            } => operand
+                .evaluate()?
+                .checked_neg()
+                .ok_or(ExpressionError::Arithmetic {
+                    column: *column,
+                    reason: "overflow",
+                }),
+            Ast::Binary {
+                function,
+                column,
+                left,
+                right,
+            } => apply_function(*function, *column, left.evaluate()?, right.evaluate()?),
+        }
+    }
+
+    // Writes the tree with brackets around every function, except the outermost
+    fn write(&self, f: &mut Formatter<'_>, outermost: bool) -> std::fmt::Result {
+        if !outermost && !matches!(self, Ast::Operand(_)) {
+            write!(f, "(")?;
+        }
+
+        match self {
+            Ast::Operand(value) => write!(f, "{}", value)?,
+            Ast::Unary {
+                function, operand, ..
+            } => {
+                write!(f, "{}", function.symbol())?;
+                operand.write(f, false)?;
+            }
+            Ast::Binary {
+                function,
+                left,
+                right,
+                ..
+            } => {
+                left.write(f, false)?;
+                write!(f, " {} ", function.symbol())?;
+                right.write(f, false)?;
+            }
+        }
+
+        if !outermost && !matches!(self, Ast::Operand(_)) {
+            write!(f, ")")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Shows the implicit brackets, e.g. `1 + 2 * 3` is `1 + (2 * 3)` with the usual
+/// precedence
+impl Display for Ast {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.write(f, true)
+    }
+}
+
+pub(crate) struct Build;
+
+impl Reduce for Build {
+    type Output = Ast;
+
+    fn operand(&mut self, value: Operand) -> Ast {
+        Ast::Operand(value)
+    }
+
+    fn unary(
+        &mut self,
+        function: Function,
+        column: usize,
+        operand: Ast,
+    ) -> Result<Ast, ExpressionError> {
+        Ok(Ast::Unary {
+            function,
+            column,
+            operand: Box::new(operand),
+        })
+    }
+
+    fn binary(
+        &mut self,
+        function: Function,
+        column: usize,
+        left: Ast,
+        right: Ast,
+    ) -> Result<Ast, ExpressionError> {
+        Ok(Ast::Binary {
+            function,
+            column,
+            left: Box::new(left),
+            right: Box::new(right),
+        })
+    }
+}
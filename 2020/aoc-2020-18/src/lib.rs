@@ -1,4 +1,6 @@
-use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
 use std::ops::Add;
 use std::path::Path;
 use std::time::Duration;
@@ -6,11 +8,13 @@ use std::time::Duration;
 use aoc_2020_common::input::load_input;
 use aoc_2020_common::timing::measure;
 
+pub use ast::Ast;
+
+mod ast;
 pub mod part_one;
 pub mod part_two;
 
 pub type Operand = isize;
-type PrecedenceFunction = fn(Function, Function) -> Ordering;
 
 pub const DAY: usize = 18;
 
@@ -33,7 +37,30 @@ pub enum Bracket {
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum Function {
     Add,
+    Sub,
     Mul,
+    Div,
+    Pow,
+    /// Unary minus
+    Neg,
+}
+
+const FUNCTIONS: usize = 6;
+
+impl Function {
+    pub fn is_unary(self) -> bool {
+        self == Function::Neg
+    }
+
+    pub fn symbol(self) -> char {
+        match self {
+            Function::Add => '+',
+            Function::Sub | Function::Neg => '-',
+            Function::Mul => '*',
+            Function::Div => '/',
+            Function::Pow => '^',
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -48,190 +75,418 @@ pub enum Token {
     Operator(Operator),
 }
 
-pub fn parse_input(input: &str) -> Vec<Vec<Token>> {
-    let mut expressions = vec![];
-    for (ln, line) in input.lines().enumerate() {
-        let line = line.trim();
-        if line.is_empty() {
-            continue;
+/// A token and the 1-based column where it starts
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Spanned {
+    pub column: usize,
+    pub token: Token,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Associativity {
+    Left,
+    Right,
+}
+
+/// The precedence level and associativity of each function. Functions with a
+/// higher level are applied first.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Precedence {
+    table: [(u8, Associativity); FUNCTIONS],
+}
+
+impl Precedence {
+    /// All functions have the same precedence and are left-associative
+    pub fn flat() -> Self {
+        Precedence {
+            table: [(0, Associativity::Left); FUNCTIONS],
         }
+    }
 
-        let mut expression = vec![];
+    /// The usual rules: `^` is right-associative and binds tighter than unary
+    /// minus, which binds tighter than `*` and `/`, which bind tighter than `+`
+    /// and `-`
+    pub fn arithmetic() -> Self {
+        Precedence::flat()
+            .with(Function::Mul, 1, Associativity::Left)
+            .with(Function::Div, 1, Associativity::Left)
+            .with(Function::Neg, 2, Associativity::Right)
+            .with(Function::Pow, 3, Associativity::Right)
+    }
 
-        let mut end_of_last_match = 0;
-        for (idx, matched) in line.match_indices(match_split_symbol) {
-            if end_of_last_match != idx {
-                let token = line[end_of_last_match..idx].trim();
-                expression.push(parse_token(token, ln));
-            }
-            end_of_last_match = idx + matched.len();
+    pub fn with(mut self, function: Function, level: u8, associativity: Associativity) -> Self {
+        self.table[function as usize] = (level, associativity);
+        self
+    }
+
+    pub fn level(&self, function: Function) -> u8 {
+        self.table[function as usize].0
+    }
 
-            let token = matched.trim();
-            if !token.is_empty() {
-                expression.push(parse_token(token, ln));
+    pub fn associativity(&self, function: Function) -> Associativity {
+        self.table[function as usize].1
+    }
+
+    // Whether `top` on the operator stack has to be applied before `next` is pushed
+    fn applies_before(&self, top: Function, next: Function) -> bool {
+        match self.level(top).cmp(&self.level(next)) {
+            std::cmp::Ordering::Greater => true,
+            std::cmp::Ordering::Less => false,
+            std::cmp::Ordering::Equal => self.associativity(next) == Associativity::Left,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ExpressionError {
+    UnexpectedCharacter {
+        column: usize,
+        character: char,
+    },
+    /// An operand or an operator is in the wrong place, e.g. `1 + * 2`
+    UnexpectedToken {
+        column: usize,
+    },
+    /// An operand is missing before the column, e.g. `(1 +)`
+    MissingOperand {
+        column: usize,
+    },
+    UnmatchedOpening {
+        column: usize,
+    },
+    UnmatchedClosing {
+        column: usize,
+    },
+    /// The function at the column cannot be applied, e.g. a division by zero
+    Arithmetic {
+        column: usize,
+        reason: &'static str,
+    },
+}
+
+impl Display for ExpressionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExpressionError::UnexpectedCharacter { column, character } => {
+                write!(f, "column {}: unexpected character {:?}", column, character)
+            }
+            ExpressionError::UnexpectedToken { column } => {
+                write!(f, "column {}: unexpected token", column)
+            }
+            ExpressionError::MissingOperand { column } => {
+                write!(f, "column {}: missing operand", column)
+            }
+            ExpressionError::UnmatchedOpening { column } => {
+                write!(f, "column {}: unmatched opening bracket", column)
+            }
+            ExpressionError::UnmatchedClosing { column } => {
+                write!(f, "column {}: unmatched closing bracket", column)
+            }
+            ExpressionError::Arithmetic { column, reason } => {
+                write!(f, "column {}: {}", column, reason)
             }
         }
+    }
+}
 
-        let remaining = line[end_of_last_match..].trim();
-        if !remaining.is_empty() {
-            expression.push(parse_token(remaining, ln));
+impl Error for ExpressionError {}
+
+pub fn parse_input(input: &str) -> Vec<Vec<Spanned>> {
+    let mut expressions = vec![];
+    for (ln, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
         }
 
-        expressions.push(expression);
+        match tokenize(line) {
+            Ok(expression) => expressions.push(expression),
+            Err(e) => panic!("Unexpected token on line {}: {}", ln, e),
+        }
     }
 
     expressions
 }
 
-fn match_split_symbol(c: char) -> bool {
-    c == ' ' || c == '(' || c == ')' || c == '+' || c == '*'
-}
+/// Splits the line into numbers, brackets and functions. A `-` is unary minus
+/// when it is at the start, or after an opening bracket or another function.
+pub fn tokenize(line: &str) -> Result<Vec<Spanned>, ExpressionError> {
+    let mut expression: Vec<Spanned> = vec![];
+    let mut chars = line.char_indices().peekable();
+
+    while let Some((idx, ch)) = chars.next() {
+        let column = idx + 1;
+        let token = match ch {
+            ' ' | '\t' => continue,
+            '(' => Token::Operator(Operator::Bracket(Bracket::Opening)),
+            ')' => Token::Operator(Operator::Bracket(Bracket::Closing)),
+            '+' => Token::Operator(Operator::Function(Function::Add)),
+            '*' => Token::Operator(Operator::Function(Function::Mul)),
+            '/' => Token::Operator(Operator::Function(Function::Div)),
+            '^' => Token::Operator(Operator::Function(Function::Pow)),
+            '-' => {
+                let unary = match expression.last() {
+                    None => true,
+                    Some(last) => matches!(
+                        last.token,
+                        Token::Operator(Operator::Bracket(Bracket::Opening))
+                            | Token::Operator(Operator::Function(_))
+                    ),
+                };
+
+                match unary {
+                    true => Token::Operator(Operator::Function(Function::Neg)),
+                    false => Token::Operator(Operator::Function(Function::Sub)),
+                }
+            }
+            '0'..='9' => {
+                let mut end = idx + 1;
+                while let Some(&(next, '0'..='9')) = chars.peek() {
+                    end = next + 1;
+                    chars.next();
+                }
+
+                match line[idx..end].parse() {
+                    Ok(value) => Token::Operand(value),
+                    Err(_) => {
+                        return Err(ExpressionError::Arithmetic {
+                            column,
+                            reason: "the number is too large",
+                        })
+                    }
+                }
+            }
+            character => {
+                return Err(ExpressionError::UnexpectedCharacter { column, character });
+            }
+        };
 
-fn parse_token(t: &str, ln: usize) -> Token {
-    match t {
-        "+" => Token::Operator(Operator::Function(Function::Add)),
-        "*" => Token::Operator(Operator::Function(Function::Mul)),
-        "(" => Token::Operator(Operator::Bracket(Bracket::Opening)),
-        ")" => Token::Operator(Operator::Bracket(Bracket::Closing)),
-        token => match token.parse() {
-            Ok(value) => Token::Operand(value),
-            Err(_e) => panic!("Unexpected token on line {}: \"{}\"", ln, token),
-        },
+        expression.push(Spanned { column, token });
     }
+
+    Ok(expression)
 }
 
-fn evaluate_expressions(expressions: &[Vec<Token>], precedence: PrecedenceFunction) -> Operand {
+fn evaluate_expressions(expressions: &[Vec<Spanned>], precedence: &Precedence) -> Operand {
     expressions
         .iter()
-        .map(|e| evaluate_expression(e, precedence))
+        .map(|e| evaluate_expression(e, precedence).unwrap())
         .sum()
 }
 
-pub fn evaluate_expression(expression: &Vec<Token>, precedence: PrecedenceFunction) -> Operand {
-    let mut operands = vec![];
-    let mut operators = vec![];
-
-    for token in expression.iter().copied() {
-        handle_token(token, &mut operands, &mut operators, precedence);
-    }
+pub fn evaluate_expression(
+    expression: &[Spanned],
+    precedence: &Precedence,
+) -> Result<Operand, ExpressionError> {
+    shunting_yard(expression, precedence, &mut Evaluate)
+}
 
-    handle_remaining(&mut operands, &mut operators);
+/// Builds the syntax tree, which shows in what order the functions are applied
+pub fn parse_expression(
+    expression: &[Spanned],
+    precedence: &Precedence,
+) -> Result<Ast, ExpressionError> {
+    shunting_yard(expression, precedence, &mut ast::Build)
+}
 
-    assert_eq!(1, operands.len());
-    operands.pop().unwrap()
+/// What the shunting-yard algorithm produces from the operands and functions
+trait Reduce {
+    type Output;
+
+    fn operand(&mut self, value: Operand) -> Self::Output;
+
+    fn unary(
+        &mut self,
+        function: Function,
+        column: usize,
+        operand: Self::Output,
+    ) -> Result<Self::Output, ExpressionError>;
+
+    fn binary(
+        &mut self,
+        function: Function,
+        column: usize,
+        left: Self::Output,
+        right: Self::Output,
+    ) -> Result<Self::Output, ExpressionError>;
 }
 
-#[inline]
-fn handle_token(
-    token: Token,
-    operands: &mut Vec<Operand>,
-    operators: &mut Vec<Operator>,
-    precedence: PrecedenceFunction,
-) {
-    match token {
-        Token::Operand(operand) => handle_number(operands, operand),
-        Token::Operator(operator) => handle_operator(operands, operators, operator, precedence),
+struct Evaluate;
+
+impl Reduce for Evaluate {
+    type Output = Operand;
+
+    fn operand(&mut self, value: Operand) -> Operand {
+        value
     }
-}
 
-#[inline]
-fn handle_number(operands: &mut Vec<Operand>, operand: Operand) {
-    operands.push(operand);
+    fn unary(
+        &mut self,
+        function: Function,
+        column: usize,
+        operand: Operand,
+    ) -> Result<Operand, ExpressionError> {
+        debug_assert_eq!(Function::Neg, function);
+        operand.checked_neg().ok_or(ExpressionError::Arithmetic {
+            column,
+            reason: "overflow",
+        })
+    }
+
+    fn binary(
+        &mut self,
+        function: Function,
+        column: usize,
+        left: Operand,
+        right: Operand,
+    ) -> Result<Operand, ExpressionError> {
+        apply_function(function, column, left, right)
+    }
 }
 
-#[inline]
-fn handle_operator(
-    operands: &mut Vec<Operand>,
-    operators: &mut Vec<Operator>,
-    operator: Operator,
-    precedence: PrecedenceFunction,
-) {
-    match operator {
-        Operator::Bracket(bracket) => handle_bracket(operands, operators, bracket),
-        Operator::Function(function) => handle_function(operands, operators, function, precedence),
+fn apply_function(
+    function: Function,
+    column: usize,
+    a: Operand,
+    b: Operand,
+) -> Result<Operand, ExpressionError> {
+    let error = |reason| ExpressionError::Arithmetic { column, reason };
+
+    match function {
+        Function::Add => a.checked_add(b).ok_or_else(|| error("overflow")),
+        Function::Sub => a.checked_sub(b).ok_or_else(|| error("overflow")),
+        Function::Mul => a.checked_mul(b).ok_or_else(|| error("overflow")),
+        Function::Div if b == 0 => Err(error("division by zero")),
+        Function::Div => a.checked_div(b).ok_or_else(|| error("overflow")),
+        Function::Pow if b < 0 => Err(error("negative exponent")),
+        Function::Pow => u32::try_from(b)
+            .ok()
+            .and_then(|b| a.checked_pow(b))
+            .ok_or_else(|| error("overflow")),
+        Function::Neg => unreachable!("unary minus has one operand"),
     }
 }
 
-#[inline]
-fn handle_bracket(operands: &mut Vec<Operand>, operators: &mut Vec<Operator>, bracket: Bracket) {
-    match bracket {
-        Bracket::Opening => operators.push(Operator::Bracket(bracket)),
-        Bracket::Closing => {
-            let mut has_left_bracket = false;
-            while let Some(op) = operators.pop() {
-                match op {
-                    Operator::Bracket(Bracket::Opening) => {
-                        has_left_bracket = true;
-                        break;
-                    }
-                    Operator::Bracket(Bracket::Closing) => {
-                        panic!("Closing brackets should never be present in the operator stack!")
-                    }
-                    Operator::Function(function) => {
-                        apply_function(operands, function);
+fn shunting_yard<R: Reduce>(
+    expression: &[Spanned],
+    precedence: &Precedence,
+    reduce: &mut R,
+) -> Result<R::Output, ExpressionError> {
+    let mut operands = vec![];
+    // the operators and their columns
+    let mut operators: Vec<(Operator, usize)> = vec![];
+    let mut expect_operand = true;
+
+    for &Spanned { column, token } in expression {
+        match token {
+            Token::Operand(operand) => {
+                if !expect_operand {
+                    return Err(ExpressionError::UnexpectedToken { column });
+                }
+
+                operands.push(reduce.operand(operand));
+                expect_operand = false;
+            }
+
+            Token::Operator(Operator::Bracket(Bracket::Opening)) => {
+                if !expect_operand {
+                    return Err(ExpressionError::UnexpectedToken { column });
+                }
+
+                operators.push((Operator::Bracket(Bracket::Opening), column));
+            }
+
+            Token::Operator(Operator::Bracket(Bracket::Closing)) => {
+                if expect_operand {
+                    return Err(ExpressionError::MissingOperand { column });
+                }
+
+                loop {
+                    match operators.pop() {
+                        Some((Operator::Bracket(Bracket::Opening), _)) => break,
+                        Some((Operator::Function(function), at)) => {
+                            apply(reduce, &mut operands, function, at)?
+                        }
+                        Some((Operator::Bracket(Bracket::Closing), _)) => {
+                            panic!(
+                                "Closing brackets should never be present in the operator stack!"
+                            )
+                        }
+                        None => return Err(ExpressionError::UnmatchedClosing { column }),
                     }
                 }
             }
-            assert!(has_left_bracket);
-        }
-    }
-}
 
-fn handle_function(
-    operands: &mut Vec<Operand>,
-    operators: &mut Vec<Operator>,
-    function: Function,
-    precedence: PrecedenceFunction,
-) {
-    if !operators.is_empty() {
-        while let Some(operator) = operators.pop() {
-            match operator {
-                Operator::Bracket(Bracket::Opening) => {
-                    // preserve the opening bracket
-                    operators.push(operator);
-                    break;
+            Token::Operator(Operator::Function(function)) if function.is_unary() => {
+                if !expect_operand {
+                    return Err(ExpressionError::UnexpectedToken { column });
                 }
-                Operator::Bracket(Bracket::Closing) => {
-                    panic!("Closing brackets should never be present in the operator stack!")
+
+                // a prefix function never applies the ones before it
+                operators.push((Operator::Function(function), column));
+            }
+
+            Token::Operator(Operator::Function(function)) => {
+                if expect_operand {
+                    return Err(ExpressionError::UnexpectedToken { column });
                 }
-                Operator::Function(last_fn) => {
-                    if precedence(last_fn, function) == Ordering::Less {
-                        //preserve the last function
-                        operators.push(operator);
+
+                while let Some(&(Operator::Function(top), at)) = operators.last() {
+                    if !precedence.applies_before(top, function) {
                         break;
                     }
 
-                    apply_function(operands, last_fn);
+                    operators.pop();
+                    apply(reduce, &mut operands, top, at)?;
                 }
+
+                operators.push((Operator::Function(function), column));
+                expect_operand = true;
             }
         }
     }
-    operators.push(Operator::Function(function))
-}
 
-#[inline]
-fn handle_remaining(operands: &mut Vec<Operand>, operators: &mut Vec<Operator>) {
-    while let Some(op) = operators.pop() {
-        match op {
-            Operator::Function(function) => apply_function(operands, function),
-            _ => panic!("Unexpected operator: {:?}", op),
+    if expect_operand {
+        let column = expression
+            .last()
+            .map_or(1, |last| last.column + token_width(last.token));
+        return Err(ExpressionError::MissingOperand { column });
+    }
+
+    while let Some((operator, column)) = operators.pop() {
+        match operator {
+            Operator::Function(function) => apply(reduce, &mut operands, function, column)?,
+            Operator::Bracket(_) => return Err(ExpressionError::UnmatchedOpening { column }),
         }
     }
 
     assert_eq!(1, operands.len());
+    Ok(operands.pop().unwrap())
 }
 
-fn apply_function(operands: &mut Vec<Operand>, function: Function) {
-    assert!(operands.len() >= 2);
-
-    let a = operands.pop().unwrap();
-    let b = operands.pop().unwrap();
+fn token_width(token: Token) -> usize {
+    match token {
+        Token::Operand(value) => value.to_string().len(),
+        Token::Operator(_) => 1,
+    }
+}
 
-    let r = match function {
-        Function::Add => a + b,
-        Function::Mul => a * b,
+fn apply<R: Reduce>(
+    reduce: &mut R,
+    operands: &mut Vec<R::Output>,
+    function: Function,
+    column: usize,
+) -> Result<(), ExpressionError> {
+    let result = if function.is_unary() {
+        let operand = operands.pop().unwrap();
+        reduce.unary(function, column, operand)?
+    } else {
+        let right = operands.pop().unwrap();
+        let left = operands.pop().unwrap();
+        reduce.binary(function, column, left, right)?
     };
-    operands.push(r);
+
+    operands.push(result);
+    Ok(())
 }
 
 #[cfg(test)]
@@ -240,9 +495,19 @@ mod tests {
 
     use super::*;
 
+    fn evaluate(line: &str, precedence: &Precedence) -> Result<Operand, ExpressionError> {
+        evaluate_expression(&tokenize(line)?, precedence)
+    }
+
+    fn pretty(line: &str, precedence: &Precedence) -> String {
+        parse_expression(&tokenize(line).unwrap(), precedence)
+            .unwrap()
+            .to_string()
+    }
+
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let expressions = parse_input(&input);
 
         let solution = part_one::solve(&expressions);
@@ -251,10 +516,97 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let expressions = parse_input(&input);
 
         let solution = part_two::solve(&expressions);
         assert_eq!(88534268715686, solution);
     }
+
+    #[test]
+    fn test_arithmetic() {
+        let precedence = Precedence::arithmetic();
+
+        assert_eq!(Ok(7), evaluate("1 + 2 * 3", &precedence));
+        assert_eq!(Ok(-4), evaluate("-2 ^ 2", &precedence));
+        assert_eq!(Ok(512), evaluate("2 ^ 3 ^ 2", &precedence));
+        assert_eq!(Ok(2), evaluate("10 - 4 - 4", &precedence));
+        assert_eq!(Ok(5), evaluate("100 / 10 / 2", &precedence));
+        assert_eq!(Ok(3), evaluate("1 - -2", &precedence));
+        assert_eq!(Ok(-9), evaluate("-(1 + 2) * 3", &precedence));
+
+        assert_eq!("-(2 ^ 2)", pretty("-2 ^ 2", &precedence));
+        assert_eq!("2 ^ (3 ^ 2)", pretty("2^3^2", &precedence));
+        assert_eq!("(1 - 2) - (3 * 4)", pretty("1 - 2 - 3 * 4", &precedence));
+        assert_eq!("(-1) + 2", pretty("-1 + 2", &precedence));
+
+        let tokens = tokenize("2 * (3 + -4) ^ 2").unwrap();
+        let ast = parse_expression(&tokens, &precedence).unwrap();
+        assert_eq!(evaluate_expression(&tokens, &precedence), ast.evaluate());
+        assert_eq!(Ok(2), ast.evaluate());
+    }
+
+    #[test]
+    fn test_custom_precedence() {
+        let flat = Precedence::flat();
+        assert_eq!(Ok(71), evaluate("1 + 2 * 3 + 4 * 5 + 6", &flat));
+        assert_eq!(
+            "((((1 + 2) * 3) + 4) * 5) + 6",
+            pretty("1 + 2 * 3 + 4 * 5 + 6", &flat)
+        );
+
+        let reversed = Precedence::flat().with(Function::Add, 1, Associativity::Left);
+        assert_eq!(Ok(231), evaluate("1 + 2 * 3 + 4 * 5 + 6", &reversed));
+        assert_eq!(
+            "((1 + 2) * (3 + 4)) * (5 + 6)",
+            pretty("1 + 2 * 3 + 4 * 5 + 6", &reversed)
+        );
+
+        let right = Precedence::flat().with(Function::Sub, 0, Associativity::Right);
+        assert_eq!(Ok(6), evaluate("10 - 8 - 4", &right));
+    }
+
+    #[test]
+    fn test_errors() {
+        let precedence = Precedence::arithmetic();
+
+        assert_eq!(
+            Err(ExpressionError::UnmatchedOpening { column: 5 }),
+            evaluate("1 + (2 * (3 + 4)", &precedence)
+        );
+        assert_eq!(
+            Err(ExpressionError::UnmatchedClosing { column: 8 }),
+            evaluate("(1 + 2)) * 3", &precedence)
+        );
+        assert_eq!(
+            Err(ExpressionError::MissingOperand { column: 4 }),
+            evaluate("(1+)", &precedence)
+        );
+        assert_eq!(
+            Err(ExpressionError::MissingOperand { column: 8 }),
+            evaluate("1 + 2 *", &precedence)
+        );
+        assert_eq!(
+            Err(ExpressionError::UnexpectedToken { column: 5 }),
+            evaluate("1 + * 2", &precedence)
+        );
+        assert_eq!(
+            Err(ExpressionError::UnexpectedCharacter {
+                column: 3,
+                character: 'x'
+            }),
+            evaluate("1 x 2", &precedence)
+        );
+        assert_eq!(
+            Err(ExpressionError::Arithmetic {
+                column: 8,
+                reason: "division by zero"
+            }),
+            evaluate("1 + (2 / (1 - 1))", &precedence)
+        );
+        assert_eq!(
+            "column 5: unmatched opening bracket",
+            evaluate("1 + (2", &precedence).unwrap_err().to_string()
+        );
+    }
 }
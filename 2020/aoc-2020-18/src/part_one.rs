@@ -1,12 +1,10 @@
-use std::cmp::Ordering;
+use crate::{evaluate_expressions, Operand, Precedence, Spanned};
 
-use crate::{evaluate_expressions, Function, Operand, Token};
-
-pub fn solve(input: &[Vec<Token>]) -> Operand {
-    evaluate_expressions(input, precedence_part_one)
+pub fn solve(input: &[Vec<Spanned>]) -> Operand {
+    evaluate_expressions(input, &precedence_part_one())
 }
 
-#[inline(always)]
-fn precedence_part_one(_first: Function, _second: Function) -> Ordering {
-    Ordering::Equal
+// All functions are evaluated from left to right
+pub fn precedence_part_one() -> Precedence {
+    Precedence::flat()
 }
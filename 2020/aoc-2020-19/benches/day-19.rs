@@ -7,7 +7,7 @@ criterion_group!(benches, input_parsing, part_one, part_two);
 criterion_main!(benches);
 
 fn input_parsing(c: &mut Criterion) {
-    let input = load_input(default_test_input(DAY));
+    let input = load_input(default_test_input!(DAY));
 
     c.bench_with_input(BenchmarkId::new("day-19-parsing", ""), &input, |b, i| {
         b.iter(|| black_box(parse_input(i)))
@@ -15,26 +15,26 @@ fn input_parsing(c: &mut Criterion) {
 }
 
 fn part_one(c: &mut Criterion) {
-    let input = load_input(default_test_input(DAY));
+    let input = load_input(default_test_input!(DAY));
     let input = parse_input(&input);
 
     c.bench_with_input(BenchmarkId::new("day-19-p01", ""), &input, |b, i| {
-        let (rules, msgs) = i;
-        b.iter(|| black_box(solve(rules, msgs)))
+        let (grammar, msgs) = i;
+        b.iter(|| black_box(solve(grammar, msgs)))
     });
 }
 
 fn part_two(c: &mut Criterion) {
-    let input = load_input(default_test_input(DAY));
-    let (mut rules, msgs) = parse_input(&input);
-    modify_input(&mut rules);
+    let input = load_input(default_test_input!(DAY));
+    let (mut grammar, msgs) = parse_input(&input);
+    modify_input(&mut grammar);
 
     c.bench_with_input(
         BenchmarkId::new("day-19-p02", ""),
-        &(rules, msgs),
+        &(grammar, msgs),
         |b, i| {
-            let (rules, msgs) = i;
-            b.iter(|| black_box(solve(rules, msgs)))
+            let (grammar, msgs) = i;
+            b.iter(|| black_box(solve(grammar, msgs)))
         },
     );
 }
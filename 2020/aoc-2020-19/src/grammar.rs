@@ -0,0 +1,524 @@
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+use anyhow::{anyhow, bail, Context};
+
+/// The rule that has to match the whole message
+pub const START: usize = 0;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Rule {
+    /// Matches a single byte
+    Byte(u8),
+    /// Matches any of the sequences of rules
+    Either(Vec<Vec<usize>>),
+}
+
+/// Parses a rule such as `8: 42 | 42 8` or `4: "a"`
+pub fn parse_rule(line: &str) -> anyhow::Result<(usize, Rule)> {
+    let (number, body) = line
+        .split_once(':')
+        .ok_or_else(|| anyhow!("missing ':' in rule {:?}", line))?;
+
+    let number = number
+        .trim()
+        .parse()
+        .with_context(|| format!("invalid rule number in {:?}", line))?;
+
+    let body = body.trim();
+    if let Some(byte) = body.strip_prefix('"') {
+        return match byte.as_bytes() {
+            [b, b'"'] => Ok((number, Rule::Byte(*b))),
+            _ => bail!("rule {}: expected a single quoted byte", number),
+        };
+    }
+
+    let mut alternatives = vec![];
+    for alternative in body.split('|') {
+        let sequence = alternative
+            .split_whitespace()
+            .map(|n| n.parse())
+            .collect::<Result<Vec<_>, _>>()
+            .with_context(|| format!("rule {}: invalid reference in {:?}", number, body))?;
+
+        if sequence.is_empty() {
+            bail!("rule {}: empty alternative", number);
+        }
+
+        alternatives.push(sequence);
+    }
+
+    Ok((number, Rule::Either(alternatives)))
+}
+
+/// The rules of the messages, which can be recursive in any way
+#[derive(Debug, Clone, Default)]
+pub struct Grammar {
+    rules: Vec<Option<Rule>>,
+}
+
+/// Why a message does not match
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Mismatch {
+    /// The offset of the first byte that cannot be matched. It's the length of the
+    /// message if the message is too short.
+    pub position: usize,
+    /// The rules that could have matched at `position`
+    pub expected: Vec<usize>,
+    /// The rules that were being matched when they failed
+    pub in_rules: Vec<usize>,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.expected.is_empty() {
+            return write!(
+                f,
+                "byte {}: unexpected input after rule {}",
+                self.position, START
+            );
+        }
+
+        write!(
+            f,
+            "byte {}: expected rule {} in rule {}",
+            self.position,
+            join(&self.expected, " or "),
+            join(&self.in_rules, ", ")
+        )
+    }
+}
+
+fn join(rules: &[usize], separator: &str) -> String {
+    rules
+        .iter()
+        .map(|r| r.to_string())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// The rules that matched each part of a message
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseTree {
+    pub rule: usize,
+    pub span: Range<usize>,
+    /// The rules of the matched sequence. Empty for a byte.
+    pub children: Vec<ParseTree>,
+}
+
+impl Display for ParseTree {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.rule)?;
+        if self.children.is_empty() {
+            return Ok(());
+        }
+
+        write!(f, "(")?;
+        for (idx, child) in self.children.iter().enumerate() {
+            if idx > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", child)?;
+        }
+        write!(f, ")")
+    }
+}
+
+// An Earley item: the alternative `alt` of `rule`, started at `origin` and
+// matched up to, but not including, the symbol at `dot`
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+struct Item {
+    rule: usize,
+    alt: usize,
+    dot: usize,
+    origin: usize,
+}
+
+// The items at each position of the message
+struct Chart {
+    items: Vec<Vec<Item>>,
+    seen: Vec<HashSet<Item>>,
+}
+
+impl Chart {
+    fn add(&mut self, position: usize, item: Item) {
+        if self.seen[position].insert(item) {
+            self.items[position].push(item);
+        }
+    }
+
+    fn contains(&self, position: usize, item: &Item) -> bool {
+        self.seen[position].contains(item)
+    }
+}
+
+impl Grammar {
+    pub fn rule(&self, number: usize) -> Option<&Rule> {
+        self.rules.get(number).and_then(|rule| rule.as_ref())
+    }
+
+    pub fn insert(&mut self, number: usize, rule: Rule) {
+        if self.rules.len() <= number {
+            self.rules.resize(number + 1, None);
+        }
+
+        self.rules[number] = Some(rule);
+    }
+
+    /// Parses the rule and replaces the one with the same number, e.g. `8: 42 | 42 8`
+    pub fn replace_rule(&mut self, line: &str) -> anyhow::Result<()> {
+        let (number, rule) = parse_rule(line)?;
+        self.insert(number, rule);
+        Ok(())
+    }
+
+    pub fn matches(&self, message: &[u8]) -> bool {
+        self.check(message).is_ok()
+    }
+
+    /// Matches the whole message with the start rule and reports where it failed
+    pub fn check(&self, message: &[u8]) -> Result<(), Mismatch> {
+        let chart = self.recognize(message);
+        if self.is_complete(&chart, START, 0, message.len()) {
+            return Ok(());
+        }
+
+        Err(self.mismatch(&chart, message))
+    }
+
+    pub fn parse_tree(&self, message: &[u8]) -> Result<ParseTree, Mismatch> {
+        let chart = self.recognize(message);
+        let mut stack = vec![];
+        self.build(&chart, message, START, 0..message.len(), &mut stack)
+            .ok_or_else(|| self.mismatch(&chart, message))
+    }
+
+    fn sequence(&self, rule: usize, alt: usize) -> &[usize] {
+        match self.rule(rule) {
+            Some(Rule::Either(alternatives)) => &alternatives[alt],
+            _ => unreachable!(),
+        }
+    }
+
+    fn recognize(&self, message: &[u8]) -> Chart {
+        let mut chart = Chart {
+            items: vec![vec![]; message.len() + 1],
+            seen: vec![HashSet::new(); message.len() + 1],
+        };
+
+        self.predict(&mut chart, START, 0);
+
+        for position in 0..=message.len() {
+            let mut idx = 0;
+            while idx < chart.items[position].len() {
+                let item = chart.items[position][idx];
+                idx += 1;
+
+                let sequence = self.sequence(item.rule, item.alt);
+                let advanced = Item {
+                    dot: item.dot + 1,
+                    ..item
+                };
+
+                if item.dot == sequence.len() {
+                    // complete every item waiting for this rule. There are no empty
+                    // alternatives, so they are all at earlier positions.
+                    for idx in 0..chart.items[item.origin].len() {
+                        let waiting = chart.items[item.origin][idx];
+                        if self.sequence(waiting.rule, waiting.alt).get(waiting.dot)
+                            == Some(&item.rule)
+                        {
+                            chart.add(
+                                position,
+                                Item {
+                                    dot: waiting.dot + 1,
+                                    ..waiting
+                                },
+                            );
+                        }
+                    }
+                    continue;
+                }
+
+                match self.rule(sequence[item.dot]) {
+                    Some(Rule::Byte(b)) if message.get(position) == Some(b) => {
+                        chart.add(position + 1, advanced);
+                    }
+                    Some(Rule::Either(_)) => self.predict(&mut chart, sequence[item.dot], position),
+                    // a byte that does not match or an undefined rule
+                    _ => {}
+                }
+            }
+        }
+
+        chart
+    }
+
+    fn predict(&self, chart: &mut Chart, rule: usize, position: usize) {
+        if let Some(Rule::Either(alternatives)) = self.rule(rule) {
+            for alt in 0..alternatives.len() {
+                chart.add(
+                    position,
+                    Item {
+                        rule,
+                        alt,
+                        dot: 0,
+                        origin: position,
+                    },
+                );
+            }
+        }
+    }
+
+    // Whether `rule` matches exactly the bytes between `origin` and `position`
+    fn is_complete(&self, chart: &Chart, rule: usize, origin: usize, position: usize) -> bool {
+        match self.rule(rule) {
+            Some(Rule::Either(alternatives)) => (0..alternatives.len()).any(|alt| {
+                chart.contains(
+                    position,
+                    &Item {
+                        rule,
+                        alt,
+                        dot: alternatives[alt].len(),
+                        origin,
+                    },
+                )
+            }),
+            _ => false,
+        }
+    }
+
+    // The furthest position that the items reached and what they expected there
+    fn mismatch(&self, chart: &Chart, message: &[u8]) -> Mismatch {
+        let position = (0..=message.len())
+            .rev()
+            .find(|&p| !chart.items[p].is_empty())
+            .unwrap_or(0);
+
+        let mut expected = vec![];
+        let mut in_rules = vec![];
+        for item in chart.items[position].iter() {
+            let sequence = self.sequence(item.rule, item.alt);
+            if let Some(&next) = sequence.get(item.dot) {
+                if !matches!(self.rule(next), Some(Rule::Either(_))) {
+                    expected.push(next);
+                    in_rules.push(item.rule);
+                }
+            }
+        }
+
+        expected.sort_unstable();
+        expected.dedup();
+        in_rules.sort_unstable();
+        in_rules.dedup();
+
+        Mismatch {
+            position,
+            expected,
+            in_rules,
+        }
+    }
+
+    // `stack` holds the rules and spans being built, so that cyclic rules such as
+    // `1: 2 | "a"` and `2: 1` do not recurse forever
+    fn build(
+        &self,
+        chart: &Chart,
+        message: &[u8],
+        rule: usize,
+        span: Range<usize>,
+        stack: &mut Vec<(usize, Range<usize>)>,
+    ) -> Option<ParseTree> {
+        match self.rule(rule)? {
+            Rule::Byte(b) => {
+                if span.len() == 1 && message[span.start] == *b {
+                    return Some(ParseTree {
+                        rule,
+                        span,
+                        children: vec![],
+                    });
+                }
+                return None;
+            }
+            Rule::Either(_) => {}
+        }
+
+        if stack.contains(&(rule, span.clone())) {
+            return None;
+        }
+
+        stack.push((rule, span.clone()));
+        let mut tree = None;
+        for alt in 0..self.sequence_count(rule) {
+            let item = Item {
+                rule,
+                alt,
+                dot: self.sequence(rule, alt).len(),
+                origin: span.start,
+            };
+
+            if !chart.contains(span.end, &item) {
+                continue;
+            }
+
+            if let Some(children) = self.build_sequence(chart, message, item, span.end, stack) {
+                tree = Some(ParseTree {
+                    rule,
+                    span: span.clone(),
+                    children,
+                });
+                break;
+            }
+        }
+        stack.pop();
+
+        tree
+    }
+
+    fn sequence_count(&self, rule: usize) -> usize {
+        match self.rule(rule) {
+            Some(Rule::Either(alternatives)) => alternatives.len(),
+            _ => 0,
+        }
+    }
+
+    // The trees of the symbols before the dot of `item`, which end at `end`
+    fn build_sequence(
+        &self,
+        chart: &Chart,
+        message: &[u8],
+        item: Item,
+        end: usize,
+        stack: &mut Vec<(usize, Range<usize>)>,
+    ) -> Option<Vec<ParseTree>> {
+        if item.dot == 0 {
+            return if end == item.origin {
+                Some(vec![])
+            } else {
+                None
+            };
+        }
+
+        let symbol = self.sequence(item.rule, item.alt)[item.dot - 1];
+        let previous = Item {
+            dot: item.dot - 1,
+            ..item
+        };
+
+        // the symbol spans `split..end` and the rest of the sequence is before it
+        for split in (item.origin..end).rev() {
+            if !chart.contains(split, &previous) {
+                continue;
+            }
+
+            let matched = match self.rule(symbol) {
+                Some(Rule::Byte(_)) => split + 1 == end,
+                _ => self.is_complete(chart, symbol, split, end),
+            };
+            if !matched {
+                continue;
+            }
+
+            let Some(last) = self.build(chart, message, symbol, split..end, stack) else {
+                continue;
+            };
+
+            if let Some(mut children) = self.build_sequence(chart, message, previous, split, stack)
+            {
+                children.push(last);
+                return Some(children);
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grammar::{parse_rule, Grammar, Mismatch, Rule};
+
+    fn grammar(rules: &[&str]) -> Grammar {
+        let mut grammar = Grammar::default();
+        for rule in rules {
+            grammar.replace_rule(rule).unwrap();
+        }
+        grammar
+    }
+
+    #[test]
+    fn test_parse_rule() {
+        assert_eq!((4, Rule::Byte(b'a')), parse_rule("4: \"a\"").unwrap());
+        assert_eq!(
+            (8, Rule::Either(vec![vec![42], vec![42, 8]])),
+            parse_rule("8: 42 | 42 8").unwrap()
+        );
+
+        assert!(parse_rule("8 42").is_err());
+        assert!(parse_rule("8: 42 |").is_err());
+        assert!(parse_rule("x: 1").is_err());
+        assert!(parse_rule("4: \"ab\"").is_err());
+    }
+
+    #[test]
+    fn test_recursion() {
+        // left recursion, right recursion and nesting
+        let g = grammar(&["0: 0 1 | 1", "1: \"a\""]);
+        assert!(g.matches(b"aaaa"));
+        assert!(!g.matches(b""));
+
+        let g = grammar(&["0: 1 0 2 | 1 2", "1: \"a\"", "2: \"b\""]);
+        assert!(g.matches(b"aaabbb"));
+        assert!(!g.matches(b"aaabb"));
+        assert!(!g.matches(b"abab"));
+    }
+
+    #[test]
+    fn test_mismatch() {
+        let g = grammar(&["0: 1 2 | 1 3", "1: \"a\"", "2: \"b\"", "3: 1 1"]);
+
+        assert_eq!(
+            Err(Mismatch {
+                position: 1,
+                expected: vec![1, 2],
+                in_rules: vec![0, 3],
+            }),
+            g.check(b"ac")
+        );
+
+        let mismatch = g.check(b"aa").unwrap_err();
+        assert_eq!(2, mismatch.position);
+        assert_eq!("byte 2: expected rule 1 in rule 3", mismatch.to_string());
+
+        let mismatch = g.check(b"abb").unwrap_err();
+        assert_eq!(
+            "byte 2: unexpected input after rule 0",
+            mismatch.to_string()
+        );
+
+        // the rule 4 is not defined
+        let g = grammar(&["0: 1 4", "1: \"a\""]);
+        assert_eq!(vec![4], g.check(b"ab").unwrap_err().expected);
+    }
+
+    #[test]
+    fn test_parse_tree() {
+        let g = grammar(&[
+            "0: 8 11",
+            "8: 42 | 42 8",
+            "11: 42 31 | 42 11 31",
+            "42: \"a\"",
+            "31: \"b\"",
+        ]);
+
+        let tree = g.parse_tree(b"aaabb").unwrap();
+        assert_eq!(0..5, tree.span);
+        assert_eq!("0(8(42) 11(42 11(42 31) 31))", tree.to_string());
+        assert!(g.parse_tree(b"aabbb").is_err());
+
+        // a cycle of rules that match the same bytes
+        let g = grammar(&["0: 1", "1: 2 | 3", "2: 1", "3: \"a\""]);
+        assert_eq!("0(1(3))", g.parse_tree(b"a").unwrap().to_string());
+    }
+}
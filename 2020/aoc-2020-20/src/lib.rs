@@ -93,7 +93,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let grids = parse_input(&input);
 
         let (solution, _) = part_one::solve(&grids).unwrap();
@@ -102,7 +102,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let grids = parse_input(&input);
 
         let (_, mut image) = part_one::solve(&grids).unwrap();
@@ -70,7 +70,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let foods = parse_input(&input);
 
         let solution = part_one::solve(&foods);
@@ -79,7 +79,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let foods = parse_input(&input);
 
         let solution = part_two::solve(&foods);
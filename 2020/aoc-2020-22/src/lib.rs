@@ -39,7 +39,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let (a, b) = parse_input(&input);
 
         let solution = part_one::solve(&a, &b);
@@ -48,7 +48,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let (a, b) = parse_input(&input);
 
         let solution = part_two::solve(&a, &b);
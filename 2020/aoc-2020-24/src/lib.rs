@@ -189,7 +189,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let tile_directions = parse_input(&input);
 
         let (solution, _) = part_one::solve(&tile_directions);
@@ -198,7 +198,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let tile_directions = parse_input(&input);
 
         let (_, tiles) = part_one::solve(&tile_directions);
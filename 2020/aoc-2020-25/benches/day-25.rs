@@ -8,7 +8,7 @@ criterion_group!(benches, part_one);
 criterion_main!(benches);
 
 fn part_one(c: &mut Criterion) {
-    let input = load_input(default_test_input(DAY));
+    let input = load_input(default_test_input!(DAY));
     let input = parse_line_delimited(&input);
 
     c.bench_with_input(BenchmarkId::new("day-25-p01-v1", ""), &input, |b, i| {
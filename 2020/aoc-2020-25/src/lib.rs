@@ -119,7 +119,7 @@ mod tests {
 
     #[test]
     fn test_part_one_v1() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let data = parse_line_delimited(&input);
 
         let solution = solve_v1(&data);
@@ -128,7 +128,7 @@ mod tests {
 
     #[test]
     fn test_part_one_v2() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let data = parse_line_delimited(&input);
 
         let solution = solve_v2(&data);
@@ -137,7 +137,7 @@ mod tests {
 
     #[test]
     fn test_part_one_v3() {
-        let input = load_input(default_test_input(DAY));
+        let input = load_input(default_test_input!(DAY));
         let data = parse_line_delimited(&input);
 
         let solution = solve_v3(&data);
@@ -1,3 +1,5 @@
+pub use aoc_core::{blocks, math};
+
 pub mod input;
 pub mod output;
 pub mod parsing;
@@ -1,20 +1,16 @@
 use std::fmt::Debug;
 use std::str::FromStr;
 
+use aoc_core::parsing::parse_trimmed;
+pub use aoc_core::parsing::{try_parse_csv, ParseError};
+
 pub fn parse_csv<I, R, E>(input: I) -> Vec<R>
 where
     I: AsRef<str>,
     E: Debug,
     R: FromStr<Err = E>,
 {
-    input
-        .as_ref()
-        .split(',')
-        .map(|v| v.trim())
-        .filter(|&v| !v.is_empty())
-        .map(|v| v.parse())
-        .collect::<Result<_, _>>()
-        .unwrap()
+    try_parse_csv(input).unwrap()
 }
 
 pub fn parse_line_delimited<I, R, E>(input: I) -> Vec<R>
@@ -22,13 +18,20 @@ where
     I: AsRef<str>,
     E: Debug,
     R: FromStr<Err = E>,
+{
+    try_parse_line_delimited(input).unwrap()
+}
+
+pub fn try_parse_line_delimited<I, R>(input: I) -> Result<Vec<R>, ParseError<R::Err>>
+where
+    I: AsRef<str>,
+    R: FromStr,
 {
     input
         .as_ref()
         .lines()
-        .map(|l| l.trim())
-        .filter(|&l| !l.is_empty())
-        .map(|l| l.parse())
-        .collect::<Result<_, _>>()
-        .unwrap()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(idx, l)| parse_trimmed(l, idx))
+        .collect()
 }
@@ -1,4 +1,4 @@
-use aoc_shared::input::{load_line_delimited_input_from_file, load_text_input_from_file};
+use aoc_shared::input_locator;
 use aoc_shared::parsing::parse_line_delimited;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use aoc_day_01::{part_one, part_two};
@@ -8,7 +8,7 @@ criterion_group!(benches, benchmark_parsing, benchmark_part_one, benchmark_part_
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let raw = load_text_input_from_file("inputs/input.txt");
+    let raw = input_locator!(2021, 1).load().unwrap();
 
     c.bench_function("parsing", |b| {
         b.iter(|| black_box(parse_line_delimited::<_, u32>(black_box(&raw))));
@@ -17,7 +17,7 @@ fn benchmark_parsing(c: &mut Criterion) {
 
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_line_delimited_input_from_file("inputs/input.txt");
+    let input = parse_line_delimited(input_locator!(2021, 1).load().unwrap());
 
     c.bench_function("part-1", |b| {
         b.iter(|| black_box(part_one(black_box(&input))));
@@ -25,7 +25,7 @@ fn benchmark_part_one(c: &mut Criterion) {
 }
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_line_delimited_input_from_file("inputs/input.txt");
+    let input = parse_line_delimited(input_locator!(2021, 1).load().unwrap());
 
     c.bench_function("part-2", |b| {
         b.iter(|| black_box(part_two(black_box(&input))));
@@ -1,5 +1,5 @@
 use aoc_day_02::{part_one, part_two, Direction};
-use aoc_shared::input::{load_line_delimited_input_from_file, load_text_input_from_file};
+use aoc_shared::input_locator;
 use aoc_shared::parsing::parse_line_delimited;
 use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
 
@@ -12,7 +12,7 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2021, 2).load().unwrap();
 
     c.bench_function("parsing", |b| {
         b.iter_batched(
@@ -24,7 +24,7 @@ fn benchmark_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_line_delimited_input_from_file("inputs/input.txt");
+    let input = parse_line_delimited(input_locator!(2021, 2).load().unwrap());
 
     c.bench_function("part-1", |b| {
         b.iter(|| black_box(part_one(black_box(&input))));
@@ -32,7 +32,7 @@ fn benchmark_part_one(c: &mut Criterion) {
 }
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_line_delimited_input_from_file("inputs/input.txt");
+    let input = parse_line_delimited(input_locator!(2021, 2).load().unwrap());
 
     c.bench_function("part-2", |b| {
         b.iter(|| black_box(part_two(black_box(&input))));
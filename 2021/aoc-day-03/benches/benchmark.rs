@@ -1,4 +1,4 @@
-use aoc_shared::input::{load_line_delimited_input_from_file, load_text_input_from_file};
+use aoc_shared::input_locator;
 use aoc_shared::parsing::parse_line_delimited;
 use criterion::{black_box, criterion_group, criterion_main, Criterion, BatchSize};
 use aoc_day_03::{part_one, part_two_v1, part_two_v2};
@@ -8,7 +8,7 @@ criterion_group!(benches, benchmark_parsing, benchmark_part_one, benchmark_part_
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2021, 3).load().unwrap();
 
     c.bench_function("parsing", |b| {
         b.iter(|| black_box(parse_line_delimited::<_, String>(&input)));
@@ -16,7 +16,7 @@ fn benchmark_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input: Vec<String> = load_line_delimited_input_from_file("inputs/input.txt");
+    let input: Vec<String> = parse_line_delimited(input_locator!(2021, 3).load().unwrap());
 
     c.bench_function("part-1", |b| {
         b.iter(|| black_box(part_one(black_box(&input))));
@@ -24,7 +24,7 @@ fn benchmark_part_one(c: &mut Criterion) {
 }
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input: Vec<String> = load_line_delimited_input_from_file("inputs/input.txt");
+    let input: Vec<String> = parse_line_delimited(input_locator!(2021, 3).load().unwrap());
 
     c.bench_function("part-2-with_two_pointers", |b| {
         b.iter_batched(
@@ -1,5 +1,5 @@
 use aoc_day_04::{parse_input, part_one, part_two};
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
 use std::fs::File;
 use std::io::BufReader;
@@ -13,7 +13,7 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2021, 4).load().unwrap();
 
     c.bench_function("parse-input", |b| {
         b.iter_batched(
@@ -25,7 +25,7 @@ fn benchmark_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_1(c: &mut Criterion) {
-    let file = File::open("inputs/input.txt").unwrap();
+    let file = File::open(input_locator!(2021, 4).locate().unwrap()).unwrap();
     let (numbers, boards) = parse_input(BufReader::new(file));
 
     c.bench_function("part-1", |b| {
@@ -34,7 +34,7 @@ fn benchmark_part_1(c: &mut Criterion) {
 }
 
 fn benchmark_part_2(c: &mut Criterion) {
-    let file = File::open("inputs/input.txt").unwrap();
+    let file = File::open(input_locator!(2021, 4).locate().unwrap()).unwrap();
     let (numbers, boards) = parse_input(BufReader::new(file));
 
     c.bench_function("part-2", |b| {
@@ -1,11 +1,16 @@
-use aoc_day_04::{parse_input, part_one, part_two};
-use aoc_shared::input::load_text_input_from_autodetect;
+use std::error::Error;
 use std::io::BufReader;
 
-fn main() {
-    let input_text = load_text_input_from_autodetect();
+use aoc_day_04::{parse_input, part_one, part_two};
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let input_text = try_load_text_input_from_autodetect(input_locator!(2021, 4))?;
     let (numbers, boards) = parse_input(BufReader::new(input_text.as_bytes()));
 
     println!("Part 1: {:?}", part_one(&numbers, &boards));
     println!("Part 2: {:?}", part_two(&numbers, &boards));
+
+    Ok(())
 }
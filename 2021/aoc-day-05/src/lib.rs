@@ -1,4 +1,3 @@
-mod fnvhash;
 mod input;
 mod part_one;
 mod part_two;
@@ -1,9 +1,14 @@
+use std::error::Error;
+
 use aoc_day_05::{part_one_v2, part_two_v2};
-use aoc_shared::input::load_line_delimited_input_from_autodetect;
+use aoc_shared::input::try_load_line_delimited_input_from_autodetect;
+use aoc_shared::input_locator;
 
-fn main() {
-    let input = load_line_delimited_input_from_autodetect();
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = try_load_line_delimited_input_from_autodetect(input_locator!(2021, 5))?;
 
     println!("Part 1: {}", part_one_v2(&input));
     println!("Part 2: {}", part_two_v2(&input));
+
+    Ok(())
 }
@@ -1,4 +1,4 @@
-use crate::fnvhash::{FnvHasher, HashBuilder};
+use aoc_shared::hashing::{FnvHasher, HashBuilder};
 use crate::Line;
 use std::collections::HashMap;
 
@@ -1,5 +1,5 @@
 use aoc_day_06::{part_one_v1, part_one_v2, part_one_v3, part_two_v1, part_two_v2, part_two_v3};
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use aoc_shared::parsing::parse_csv;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
@@ -12,14 +12,14 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2021, 6).load().unwrap();
     c.bench_function("parsing", |b| {
         b.iter(|| black_box(parse_csv::<_, u8>(black_box(&input))));
     });
 }
 
 fn benchmark_part_1(c: &mut Criterion) {
-    let input = parse_csv(load_text_input_from_file("inputs/input.txt"));
+    let input = parse_csv(input_locator!(2021, 6).load().unwrap());
 
     c.bench_function("part-1-v1", |b| {
         b.iter(|| black_box(part_one_v1(black_box(&input))));
@@ -35,7 +35,7 @@ fn benchmark_part_1(c: &mut Criterion) {
 }
 
 fn benchmark_part_2(c: &mut Criterion) {
-    let input = parse_csv(load_text_input_from_file("inputs/input.txt"));
+    let input = parse_csv(input_locator!(2021, 6).load().unwrap());
 
     c.bench_function("part-2-v1", |b| {
         b.iter(|| black_box(part_two_v1(black_box(&input))));
@@ -1,5 +1,5 @@
 use aoc_day_09::{part_one, part_two};
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use aoc_shared::parsing::parse_u8_numeric_grid;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
@@ -12,7 +12,7 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2021, 9).load().unwrap();
 
     c.bench_function("parsing", |b| {
         b.iter(|| black_box(parse_u8_numeric_grid(black_box(&input))));
@@ -20,7 +20,7 @@ fn benchmark_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_1(c: &mut Criterion) {
-    let input = parse_u8_numeric_grid(load_text_input_from_file("inputs/input.txt"));
+    let input = parse_u8_numeric_grid(input_locator!(2021, 9).load().unwrap());
 
     c.bench_function("part-1", |b| {
         b.iter(|| black_box(part_one(black_box(&input))));
@@ -28,7 +28,7 @@ fn benchmark_part_1(c: &mut Criterion) {
 }
 
 fn benchmark_part_2(c: &mut Criterion) {
-    let input = parse_u8_numeric_grid(load_text_input_from_file("inputs/input.txt"));
+    let input = parse_u8_numeric_grid(input_locator!(2021, 9).load().unwrap());
 
     c.bench_function("part-2", |b| {
         b.iter(|| black_box(part_two(black_box(&input))));
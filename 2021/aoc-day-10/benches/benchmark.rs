@@ -1,12 +1,12 @@
+use aoc_shared::input_locator;
 use aoc_day_10::{parse_input, part_one, part_two};
-use aoc_shared::input::load_text_input_from_file;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 criterion_group!(benches, benchmark_part_parsing, benchmark_part_1, benchmark_part_2);
 criterion_main!(benches);
 
 fn benchmark_part_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2021, 10).load().unwrap();
 
     c.bench_function("parsing", |b| {
         b.iter(|| black_box(parse_input(black_box(&input))));
@@ -14,7 +14,7 @@ fn benchmark_part_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_1(c: &mut Criterion) {
-    let input = parse_input(load_text_input_from_file("inputs/input.txt"));
+    let input = parse_input(input_locator!(2021, 10).load().unwrap());
 
     c.bench_function("part-1", |b| {
         b.iter(|| black_box(part_one(black_box(&input))));
@@ -22,7 +22,7 @@ fn benchmark_part_1(c: &mut Criterion) {
 }
 
 fn benchmark_part_2(c: &mut Criterion) {
-    let input = parse_input(load_text_input_from_file("inputs/input.txt"));
+    let input = parse_input(input_locator!(2021, 10).load().unwrap());
 
     c.bench_function("part-2", |b| {
         b.iter(|| black_box(part_two(black_box(&input))));
@@ -1,9 +1,16 @@
+use std::error::Error;
+
 use aoc_day_11::{part_one, part_two};
-use aoc_shared::input::load_text_input_from_autodetect;
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
 use aoc_shared::parsing::parse_i8_numeric_grid;
 
-fn main() {
-    let input = parse_i8_numeric_grid(load_text_input_from_autodetect());
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = parse_i8_numeric_grid(try_load_text_input_from_autodetect(input_locator!(
+        2021, 11
+    ))?);
     println!("Part 1: {}", part_one(&input));
     println!("Part 2: {}", part_two(&input));
+
+    Ok(())
 }
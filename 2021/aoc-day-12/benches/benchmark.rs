@@ -1,5 +1,5 @@
 use aoc_day_12::{parse_input, part_one_v1, part_one_v2, part_two_v1, part_two_v2, simplify_graph};
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 criterion_group!(
@@ -11,7 +11,7 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2021, 12).load().unwrap();
 
     c.bench_function("parsing", |b| {
         b.iter(|| black_box(parse_input(black_box(&input))));
@@ -24,7 +24,7 @@ fn benchmark_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_1(c: &mut Criterion) {
-    let (graph, limits) = parse_input(load_text_input_from_file("inputs/input.txt"));
+    let (graph, limits) = parse_input(input_locator!(2021, 12).load().unwrap());
 
     c.bench_function("part-1-v1", |b| {
         b.iter(|| black_box(part_one_v1(black_box(&graph), black_box(&limits))));
@@ -37,7 +37,7 @@ fn benchmark_part_1(c: &mut Criterion) {
 }
 
 fn benchmark_part_2(c: &mut Criterion) {
-    let (graph, limits) = parse_input(load_text_input_from_file("inputs/input.txt"));
+    let (graph, limits) = parse_input(input_locator!(2021, 12).load().unwrap());
 
     c.bench_function("part-2-v1", |b| {
         b.iter(|| black_box(part_two_v1(black_box(&graph), black_box(&limits))));
@@ -1,3 +1,5 @@
+use aoc_shared::ocr::{recognize_text, OcrError};
+
 mod input;
 mod parsing;
 
@@ -16,7 +18,18 @@ pub fn part_one(points: &[Point], instr: &[Fold]) -> usize {
     points.len()
 }
 
-pub fn part_two(points: &[Point], instr: &[Fold]) -> Vec<Vec<char>> {
+pub fn part_two(points: &[Point], instr: &[Fold]) -> Result<String, OcrError> {
+    let paper = render_paper(points, instr)
+        .iter()
+        .map(|row| row.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    recognize_text(&paper)
+}
+
+/// Draws the dots left on the paper after all folds
+pub fn render_paper(points: &[Point], instr: &[Fold]) -> Vec<Vec<char>> {
     let mut points = points.to_vec();
 
     for f_op in instr.iter().copied() {
@@ -50,6 +63,13 @@ mod tests {
 
     #[test]
     fn test_part_two() {
+        let (points, instr) = parse_input(load_text_input_from_file("inputs/input.txt"));
+        let answer = part_two(&points, &instr);
+        assert_eq!(Ok("HKUJGAJZ".to_owned()), answer);
+    }
+
+    #[test]
+    fn test_render_paper() {
         let expected = vec![
             "█  █ █  █ █  █   ██  ██   ██    ██ ████",
             "█  █ █ █  █  █    █ █  █ █  █    █    █",
@@ -60,7 +80,7 @@ mod tests {
         ];
 
         let (points, instr) = parse_input(load_text_input_from_file("inputs/input.txt"));
-        let answer = part_two(&points, &instr);
+        let answer = render_paper(&points, &instr);
 
         let answer = answer
             .iter()
@@ -1,13 +1,16 @@
-use aoc_day_12::{parse_input, part_one, part_two};
-use aoc_shared::input::load_text_input_from_autodetect;
+use std::error::Error;
 
-fn main() {
-    let (points, fold_instr) = parse_input(load_text_input_from_autodetect());
+use aoc_day_13::{parse_input, part_one, part_two};
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let (points, fold_instr) = parse_input(try_load_text_input_from_autodetect(input_locator!(
+        2021, 13
+    ))?);
 
     println!("Part 1: {}", part_one(&points, &fold_instr));
+    println!("Part 2: {}", part_two(&points, &fold_instr)?);
 
-    println!("Part 2:");
-    part_two(&points, &fold_instr)
-        .iter()
-        .for_each(|row| println!("{}", row.iter().collect::<String>()));
+    Ok(())
 }
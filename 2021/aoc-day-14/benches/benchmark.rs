@@ -1,5 +1,5 @@
 use aoc_day_14::{parse_input, part_one, part_one_naive, part_two};
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 criterion_group!(
@@ -11,7 +11,7 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2021, 14).load().unwrap();
 
     c.bench_function("parsing", |b| {
         b.iter(|| black_box(parse_input(black_box(&input))));
@@ -19,7 +19,7 @@ fn benchmark_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_1(c: &mut Criterion) {
-    let (polymer, rules) = parse_input(load_text_input_from_file("inputs/input.txt"));
+    let (polymer, rules) = parse_input(input_locator!(2021, 14).load().unwrap());
 
     c.bench_function("part-1-naive", |b| {
         b.iter(|| black_box(part_one_naive(black_box(&polymer), black_box(&rules))));
@@ -31,7 +31,7 @@ fn benchmark_part_1(c: &mut Criterion) {
 }
 
 fn benchmark_part_2(c: &mut Criterion) {
-    let (polymer, rules) = parse_input(load_text_input_from_file("inputs/input.txt"));
+    let (polymer, rules) = parse_input(input_locator!(2021, 14).load().unwrap());
 
     c.bench_function("part-2", |b| {
         b.iter(|| black_box(part_two(black_box(&polymer), black_box(&rules))));
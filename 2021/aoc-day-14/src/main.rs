@@ -1,9 +1,16 @@
+use std::error::Error;
+
 use aoc_day_14::{parse_input, part_one, part_two};
-use aoc_shared::input::load_text_input_from_autodetect;
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
 
-fn main() {
-    let (polymer, rules) = parse_input(load_text_input_from_autodetect());
+fn main() -> Result<(), Box<dyn Error>> {
+    let (polymer, rules) = parse_input(try_load_text_input_from_autodetect(input_locator!(
+        2021, 14
+    ))?);
 
     println!("Part 1: {}", part_one(&polymer, &rules));
     println!("Part 2: {}", part_two(&polymer, &rules));
+
+    Ok(())
 }
@@ -1,7 +1,7 @@
 use aoc_day_15::astar_pf::a_star_pf;
 use aoc_day_15::astar_v1::a_star_v1;
 use aoc_day_15::{expand_grid, Position};
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use aoc_shared::parsing::parse_numeric_grid;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
@@ -14,7 +14,7 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2021, 15).load().unwrap();
 
     c.bench_function("parsing", |b| {
         b.iter(|| black_box(parse_numeric_grid::<_, u8>(black_box(&input))));
@@ -22,7 +22,7 @@ fn benchmark_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_1(c: &mut Criterion) {
-    let grid = parse_numeric_grid(load_text_input_from_file("inputs/input.txt"));
+    let grid = parse_numeric_grid(input_locator!(2021, 15).load().unwrap());
     let start = Position::new(0, 0);
     let dst = Position::new(grid.len() - 1, grid[0].len() - 1);
 
@@ -44,7 +44,7 @@ fn benchmark_part_1(c: &mut Criterion) {
 }
 
 fn benchmark_part_2(c: &mut Criterion) {
-    let grid = parse_numeric_grid(load_text_input_from_file("inputs/input.txt"));
+    let grid = parse_numeric_grid(input_locator!(2021, 15).load().unwrap());
     let grid = expand_grid(&grid);
     let start = Position::new(0, 0);
     let dst = Position::new(grid.len() - 1, grid[0].len() - 1);
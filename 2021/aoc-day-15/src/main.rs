@@ -1,14 +1,21 @@
+use std::error::Error;
+
 use aoc_day_15::astar_pf::a_star_pf;
 use aoc_day_15::{expand_grid, part_one, part_two};
-use aoc_shared::input::load_text_input_from_autodetect;
-use aoc_shared::parsing::parse_numeric_grid;
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
+use aoc_shared::parsing::try_parse_numeric_grid;
 
-fn main() {
-    let input = parse_numeric_grid(load_text_input_from_autodetect());
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = try_parse_numeric_grid(try_load_text_input_from_autodetect(input_locator!(
+        2021, 15
+    ))?)?;
     println!("Part 1: {}", part_one(&input));
     println!("Part 1(pf): {}", a_star_pf(&input));
 
     let input = expand_grid(&input);
     println!("Part 2: {}", part_two(&input));
     println!("Part 3(pf): {}", a_star_pf(&input));
+
+    Ok(())
 }
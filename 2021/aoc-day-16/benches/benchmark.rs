@@ -1,4 +1,4 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use aoc_day_16::{decode_packets, parse_to_binary, part_one, part_two};
 
@@ -11,7 +11,7 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2021, 16).load().unwrap();
 
     c.bench_function("parse-to-binary", |b| {
         b.iter(|| black_box(parse_to_binary(black_box(&input))));
@@ -24,7 +24,7 @@ fn benchmark_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_1(c: &mut Criterion) {
-    let (binary, bits) = parse_to_binary(load_text_input_from_file("inputs/input.txt"));
+    let (binary, bits) = parse_to_binary(input_locator!(2021, 16).load().unwrap());
     let packet = decode_packets(&binary, bits);
 
     c.bench_function("part-1", |b| {
@@ -33,7 +33,7 @@ fn benchmark_part_1(c: &mut Criterion) {
 }
 
 fn benchmark_part_2(c: &mut Criterion) {
-    let (binary, bits) = parse_to_binary(load_text_input_from_file("inputs/input.txt"));
+    let (binary, bits) = parse_to_binary(input_locator!(2021, 16).load().unwrap());
     let packet = decode_packets(&binary, bits);
 
     c.bench_function("part-2", |b| {
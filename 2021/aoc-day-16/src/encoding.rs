@@ -0,0 +1,254 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::packets::{Content, Packet, LITERAL};
+
+/// How an operator packet stores the size of its sub-packets
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LengthType {
+    /// Type 0: the total number of bits in the sub-packets, as 15 bits
+    TotalBits,
+    /// Type 1: the number of sub-packets, as 11 bits
+    PacketCount,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum EncodeError {
+    /// The version does not fit in 3 bits
+    InvalidVersion(u32),
+    /// The type ID does not fit in 3 bits, or it's the ID of the literal packets
+    InvalidOperator(u8),
+    /// The sub-packets do not fit in either length type
+    TooLarge { packets: usize, bits: usize },
+}
+
+impl Display for EncodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EncodeError::InvalidVersion(version) => write!(f, "invalid version: {}", version),
+            EncodeError::InvalidOperator(id) => write!(f, "invalid operator type ID: {}", id),
+            EncodeError::TooLarge { packets, bits } => write!(
+                f,
+                "{} sub-packets with {} bits do not fit in any length type",
+                packets, bits
+            ),
+        }
+    }
+}
+
+impl Error for EncodeError {}
+
+#[derive(Debug, Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u64, bits: u8) {
+        debug_assert!(bits == 64 || value >> bits == 0);
+
+        for bit in (0..bits).rev() {
+            if self.len & 7 == 0 {
+                self.bytes.push(0);
+            }
+
+            let set = (value >> bit) & 1;
+            *self.bytes.last_mut().unwrap() |= (set as u8) << (7 - self.len % 8);
+            self.len += 1;
+        }
+    }
+
+    fn append(&mut self, other: &BitWriter) {
+        for idx in 0..other.len {
+            let bit = (other.bytes[idx / 8] >> (7 - idx % 8)) & 1;
+            self.write(bit as u64, 1);
+        }
+    }
+
+    // The transmission is padded with zeros to whole bytes
+    fn to_hex(&self) -> String {
+        (0..2 * self.bytes.len())
+            .map(|idx| {
+                let nibble = (self.bytes[idx / 2] >> (4 - 4 * (idx % 2))) & 0xF;
+                char::from_digit(nibble as u32, 16)
+                    .unwrap()
+                    .to_ascii_uppercase()
+            })
+            .collect()
+    }
+}
+
+/// Encodes the packet in the hexadecimal transmission format, using the preferred
+/// length type for every operator, unless the sub-packets do not fit in it
+pub fn encode_packet(packet: &Packet, preferred: LengthType) -> Result<String, EncodeError> {
+    let mut writer = BitWriter::default();
+    encode_internal(packet, preferred, &mut writer)?;
+    Ok(writer.to_hex())
+}
+
+fn encode_internal(
+    packet: &Packet,
+    preferred: LengthType,
+    dst: &mut BitWriter,
+) -> Result<(), EncodeError> {
+    if packet.version() >= 8 {
+        return Err(EncodeError::InvalidVersion(packet.version()));
+    }
+    dst.write(packet.version() as u64, 3);
+
+    match packet.content() {
+        Content::Literal(value) => {
+            dst.write(LITERAL as u64, 3);
+            encode_literal(*value, dst);
+        }
+        Content::Operator(id, packets) => {
+            if *id >= 8 || *id == LITERAL {
+                return Err(EncodeError::InvalidOperator(*id));
+            }
+            dst.write(*id as u64, 3);
+
+            let mut sub_packets = BitWriter::default();
+            for packet in packets {
+                encode_internal(packet, preferred, &mut sub_packets)?;
+            }
+
+            let fits_bits = sub_packets.len < 1 << 15;
+            let fits_count = packets.len() < 1 << 11;
+            let length_type = match (preferred, fits_bits, fits_count) {
+                (LengthType::TotalBits, true, _) | (_, true, false) => LengthType::TotalBits,
+                (_, _, true) => LengthType::PacketCount,
+                (_, false, false) => {
+                    return Err(EncodeError::TooLarge {
+                        packets: packets.len(),
+                        bits: sub_packets.len,
+                    })
+                }
+            };
+
+            match length_type {
+                LengthType::TotalBits => {
+                    dst.write(0, 1);
+                    dst.write(sub_packets.len as u64, 15);
+                }
+                LengthType::PacketCount => {
+                    dst.write(1, 1);
+                    dst.write(packets.len() as u64, 11);
+                }
+            }
+            dst.append(&sub_packets);
+        }
+    }
+
+    Ok(())
+}
+
+// Groups of 4 bits, from the most significant one, where all but the last group
+// are prefixed with a 1 bit
+fn encode_literal(value: u64, dst: &mut BitWriter) {
+    let groups = (64 - value.leading_zeros() as usize).div_ceil(4);
+    let groups = groups.max(1);
+
+    for group in (0..groups).rev() {
+        let more = if group > 0 { 1 << 4 } else { 0 };
+        dst.write(more | ((value >> (4 * group)) & 0xF), 5);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_shared::testing::XorShift;
+
+    use crate::encoding::{encode_packet, EncodeError, LengthType};
+    use crate::packets::Packet;
+    use crate::{decode_packets, parse_to_binary};
+
+    fn decode(hex: &str) -> Packet {
+        let (binary, bits) = parse_to_binary(hex);
+        decode_packets(&binary, bits)
+    }
+
+    fn random_packet(rng: &mut XorShift, depth: u32) -> Packet {
+        let version = rng.below(8) as u32;
+        if depth == 0 || rng.below(3) == 0 {
+            let value = match rng.below(3) {
+                0 => rng.below(16),
+                1 => rng.below(u64::MAX),
+                _ => u64::MAX,
+            };
+            return Packet::literal(version, value);
+        }
+
+        let id = [0, 1, 2, 3, 5, 6, 7][rng.below(7) as usize];
+        let count = 1 + rng.below(4);
+        let packets = (0..count).map(|_| random_packet(rng, depth - 1)).collect();
+        Packet::operator(version, id, packets)
+    }
+
+    #[test]
+    fn test_encode_examples() {
+        let packet = Packet::literal(6, 2021);
+        assert_eq!(
+            "D2FE28",
+            encode_packet(&packet, LengthType::TotalBits).unwrap()
+        );
+
+        let packet = Packet::operator(1, 6, vec![Packet::literal(6, 10), Packet::literal(2, 20)]);
+        assert_eq!(
+            "38006F45291200",
+            encode_packet(&packet, LengthType::TotalBits).unwrap()
+        );
+
+        let packet = Packet::operator(
+            7,
+            3,
+            vec![
+                Packet::literal(2, 1),
+                Packet::literal(4, 2),
+                Packet::literal(1, 3),
+            ],
+        );
+        assert_eq!(
+            "EE00D40C823060",
+            encode_packet(&packet, LengthType::PacketCount).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut rng = XorShift::new(0x2021_1216);
+        for _ in 0..500 {
+            let packet = random_packet(&mut rng, 5);
+            for length_type in [LengthType::TotalBits, LengthType::PacketCount] {
+                let hex = encode_packet(&packet, length_type).unwrap();
+                assert_eq!(packet, decode(&hex), "{}", hex);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fallback_and_errors() {
+        // too many sub-packets to count them in 11 bits
+        let packets = (0..2048).map(|_| Packet::literal(0, 0)).collect::<Vec<_>>();
+        let packet = Packet::operator(0, 0, packets);
+        let hex = encode_packet(&packet, LengthType::PacketCount).unwrap();
+        assert_eq!(packet, decode(&hex));
+
+        assert_eq!(
+            Err(EncodeError::InvalidOperator(4)),
+            encode_packet(&Packet::operator(0, 4, vec![]), LengthType::TotalBits)
+        );
+        assert_eq!(
+            Err(EncodeError::InvalidVersion(8)),
+            encode_packet(&Packet::literal(8, 1), LengthType::TotalBits)
+        );
+
+        // a deeply nested packet
+        let mut packet = Packet::literal(3, 7);
+        for _ in 0..100 {
+            packet = Packet::operator(5, 2, vec![packet]);
+        }
+        let hex = encode_packet(&packet, LengthType::TotalBits).unwrap();
+        assert_eq!(packet, decode(&hex));
+    }
+}
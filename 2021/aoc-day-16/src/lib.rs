@@ -1,9 +1,11 @@
+mod encoding;
 mod parsing;
 mod packets;
 mod part_one;
 mod part_two;
 
 pub use parsing::parse_to_binary;
-pub use packets::decode_packets;
+pub use encoding::{encode_packet, EncodeError, LengthType};
+pub use packets::{decode_packets, Content, Packet};
 pub use part_one::part_one;
 pub use part_two::part_two;
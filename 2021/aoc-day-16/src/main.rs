@@ -1,10 +1,17 @@
-use aoc_shared::input::load_text_input_from_autodetect;
+use std::error::Error;
+
 use aoc_day_16::{decode_packets, parse_to_binary, part_one, part_two};
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
 
-fn main() {
-    let (binary_input, bits) = parse_to_binary(load_text_input_from_autodetect());
+fn main() -> Result<(), Box<dyn Error>> {
+    let (binary_input, bits) = parse_to_binary(try_load_text_input_from_autodetect(
+        input_locator!(2021, 16),
+    )?);
     let decoded_input = decode_packets(&binary_input, bits);
 
     println!("Part 1: {}", part_one(&decoded_input));
     println!("Part 1: {}", part_two(&decoded_input));
+
+    Ok(())
 }
@@ -1,4 +1,8 @@
 use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+/// The type ID of the literal value packets
+pub const LITERAL: u8 = 4;
 
 #[derive(Debug)]
 struct BitStream<'a> {
@@ -58,13 +62,21 @@ impl<'a> BitStream<'a> {
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Packet {
     version: u32,
     content: Content,
 }
 
 impl Packet {
+    pub fn literal(version: u32, value: u64) -> Self {
+        Packet { version, content: Content::Literal(value) }
+    }
+
+    pub fn operator(version: u32, id: u8, packets: Vec<Packet>) -> Self {
+        Packet { version, content: Content::Operator(id, packets) }
+    }
+
     pub fn version(&self) -> u32 {
         self.version
     }
@@ -74,12 +86,42 @@ impl Packet {
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub enum Content {
     Literal(u64),
     Operator(u8, Vec<Packet>),
 }
 
+/// Shows the packet as an expression, e.g. `sum(3, min(7, 8))`
+impl Display for Packet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.content {
+            Content::Literal(value) => write!(f, "{}", value),
+            Content::Operator(id, packets) => {
+                match id {
+                    0 => write!(f, "sum(")?,
+                    1 => write!(f, "product(")?,
+                    2 => write!(f, "min(")?,
+                    3 => write!(f, "max(")?,
+                    5 => write!(f, "gt(")?,
+                    6 => write!(f, "lt(")?,
+                    7 => write!(f, "eq(")?,
+                    _ => write!(f, "op{}(", id)?,
+                }
+
+                for (idx, packet) in packets.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", packet)?;
+                }
+
+                write!(f, ")")
+            }
+        }
+    }
+}
+
 pub fn decode_packets(input: &[u8], bits: usize) -> Packet {
     let mut src = BitStream::new(input, bits);
     let mut packets = decode_internal(&mut src, 1);
@@ -96,7 +138,7 @@ fn decode_internal(src: &mut BitStream, max_packets: u16) -> Vec<Packet> {
         let kind = src.read(3);
 
         let content = match kind {
-            4 => decode_literal(src),
+            LITERAL => decode_literal(src),
             _ => decode_operator(src, kind)
         };
 
@@ -231,6 +273,19 @@ mod tests {
         assert_eq!(expected, packet, "{:#?}", packet);
     }
 
+    #[test]
+    fn test_display() {
+        let (data, len) = parse_to_binary("9C0141080250320F1802104A08");
+        let packet = decode_packets(&data, len);
+        assert_eq!("eq(sum(1, 3), product(2, 2))", packet.to_string());
+
+        let packet = Packet::operator(0, 0, vec![
+            Packet::literal(0, 3),
+            Packet::operator(0, 2, vec![Packet::literal(0, 7), Packet::literal(0, 8)]),
+        ]);
+        assert_eq!("sum(3, min(7, 8))", packet.to_string());
+    }
+
     #[test]
     fn test_decode_operator_packet_1() {
         let (data, len) = parse_to_binary("EE00D40C823060");
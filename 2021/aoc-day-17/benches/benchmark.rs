@@ -1,5 +1,5 @@
 use aoc_day_17::{parse_input, part_one, part_two};
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 criterion_group!(
@@ -11,7 +11,7 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2021, 17).load().unwrap();
 
     c.bench_function("parsing", |b| {
         b.iter(|| black_box(parse_input(black_box(&input))));
@@ -19,7 +19,7 @@ fn benchmark_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_1(c: &mut Criterion) {
-    let (x0, x1, y0, y1) = parse_input(load_text_input_from_file("inputs/input.txt"));
+    let (x0, x1, y0, y1) = parse_input(input_locator!(2021, 17).load().unwrap());
 
     c.bench_function("part-1", |b| {
         b.iter(|| {
@@ -34,7 +34,7 @@ fn benchmark_part_1(c: &mut Criterion) {
 }
 
 fn benchmark_part_2(c: &mut Criterion) {
-    let (x0, x1, y0, y1) = parse_input(load_text_input_from_file("inputs/input.txt"));
+    let (x0, x1, y0, y1) = parse_input(input_locator!(2021, 17).load().unwrap());
 
     c.bench_function("part-2", |b| {
         b.iter(|| {
@@ -1,8 +1,15 @@
+use std::error::Error;
+
 use aoc_day_17::{parse_input, part_one, part_two};
-use aoc_shared::input::load_text_input_from_autodetect;
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
 
-fn main() {
-    let (x0, x1, y0, y1) = parse_input(load_text_input_from_autodetect());
+fn main() -> Result<(), Box<dyn Error>> {
+    let (x0, x1, y0, y1) = parse_input(try_load_text_input_from_autodetect(input_locator!(
+        2021, 17
+    ))?);
     println!("Part 1: {}", part_one(x0, x1, y0, y1));
     println!("Part 2: {}", part_two(x0, x1, y0, y1));
+
+    Ok(())
 }
@@ -1,5 +1,5 @@
-use aoc_day_18::{part_one, part_two, Number};
-use aoc_shared::input::load_text_input_from_file;
+use aoc_day_18::{part_one, part_one_tree, part_two, part_two_tree, Number, Tree};
+use aoc_shared::input_locator;
 use aoc_shared::parsing::parse_line_delimited;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
@@ -12,25 +12,41 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2021, 18).load().unwrap();
 
-    c.bench_function("parsing", |b| {
+    c.bench_function("parsing/tree", |b| {
+        b.iter(|| black_box(parse_line_delimited::<_, Tree>(black_box(&input))));
+    });
+
+    c.bench_function("parsing/flat", |b| {
         b.iter(|| black_box(parse_line_delimited::<_, Number>(black_box(&input))));
     });
 }
 
 fn benchmark_part_1(c: &mut Criterion) {
-    let input = parse_line_delimited(load_text_input_from_file("inputs/input.txt"));
+    let input = input_locator!(2021, 18).load().unwrap();
+    let trees = parse_line_delimited(&input);
+    let numbers = parse_line_delimited(&input);
+
+    c.bench_function("part-1/tree", |b| {
+        b.iter(|| black_box(part_one_tree(black_box(&trees))))
+    });
 
-    c.bench_function("part-1", |b| {
-        b.iter(|| black_box(part_one(black_box(&input))))
+    c.bench_function("part-1/flat", |b| {
+        b.iter(|| black_box(part_one(black_box(&numbers))))
     });
 }
 
 fn benchmark_part_2(c: &mut Criterion) {
-    let input = parse_line_delimited(load_text_input_from_file("inputs/input.txt"));
+    let input = input_locator!(2021, 18).load().unwrap();
+    let trees = parse_line_delimited(&input);
+    let numbers = parse_line_delimited(&input);
+
+    c.bench_function("part-2/tree", |b| {
+        b.iter(|| black_box(part_two_tree(black_box(&trees))))
+    });
 
-    c.bench_function("part-2", |b| {
-        b.iter(|| black_box(part_two(black_box(&input))))
+    c.bench_function("part-2/flat", |b| {
+        b.iter(|| black_box(part_two(black_box(&numbers))))
     });
 }
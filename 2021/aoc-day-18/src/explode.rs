@@ -1,19 +1,16 @@
-use crate::{Number, Numeric};
+use crate::{Numeric, Tree};
 
-pub(crate) fn explode(
-    number: &mut Number,
-    depth: usize,
-) -> (bool, Option<Numeric>, Option<Numeric>) {
+pub(crate) fn explode(number: &mut Tree, depth: usize) -> (bool, Option<Numeric>, Option<Numeric>) {
     match number {
-        Number::Value(_) => return (false, None, None),
-        Number::Tuple(t) => {
+        Tree::Value(_) => return (false, None, None),
+        Tree::Tuple(t) => {
             if depth == 5 {
                 return match (&mut t.0, &mut t.1) {
-                    (Number::Value(l), Number::Value(r)) => {
+                    (Tree::Value(l), Tree::Value(r)) => {
                         let l = *l;
                         let r = *r;
 
-                        *number = Number::Value(0);
+                        *number = Tree::Value(0);
                         (true, Some(l), Some(r))
                     }
                     _ => panic!("Maximum depth exceeded!"),
@@ -43,17 +40,17 @@ pub(crate) fn explode(
     }
 }
 
-fn add_to_left(n: &mut Number, v: Numeric) {
+fn add_to_left(n: &mut Tree, v: Numeric) {
     match n {
-        Number::Value(x) => *x += v,
-        Number::Tuple(t) => add_to_left(&mut t.0, v),
+        Tree::Value(x) => *x += v,
+        Tree::Tuple(t) => add_to_left(&mut t.0, v),
     }
 }
 
-fn add_to_right(number: &mut Number, v: Numeric) {
+fn add_to_right(number: &mut Tree, v: Numeric) {
     match number {
-        Number::Value(x) => *x += v,
-        Number::Tuple(t) => add_to_right(&mut t.1, v),
+        Tree::Value(x) => *x += v,
+        Tree::Tuple(t) => add_to_right(&mut t.1, v),
     }
 }
 
@@ -64,8 +61,8 @@ mod tests {
 
     #[test]
     fn test_explode_no_left() {
-        let expected = Number::from_str("[[[[0,9],2],3],4]").unwrap();
-        let mut num = Number::from_str("[[[[[9,8],1],2],3],4]").unwrap();
+        let expected = Tree::from_str("[[[[0,9],2],3],4]").unwrap();
+        let mut num = Tree::from_str("[[[[[9,8],1],2],3],4]").unwrap();
 
         explode(&mut num, 1);
         assert_eq!(expected, num);
@@ -73,8 +70,8 @@ mod tests {
 
     #[test]
     fn test_explode_no_right_1() {
-        let expected = Number::from_str("[7,[6,[5,[7,0]]]]").unwrap();
-        let mut num = Number::from_str("[7,[6,[5,[4,[3,2]]]]]").unwrap();
+        let expected = Tree::from_str("[7,[6,[5,[7,0]]]]").unwrap();
+        let mut num = Tree::from_str("[7,[6,[5,[4,[3,2]]]]]").unwrap();
 
         explode(&mut num, 1);
         assert_eq!(expected, num);
@@ -82,8 +79,8 @@ mod tests {
 
     #[test]
     fn test_explode_no_right_2() {
-        let expected = Number::from_str("[[3,[2,[8,0]]],[9,[5,[7,0]]]]").unwrap();
-        let mut num = Number::from_str("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]").unwrap();
+        let expected = Tree::from_str("[[3,[2,[8,0]]],[9,[5,[7,0]]]]").unwrap();
+        let mut num = Tree::from_str("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]").unwrap();
 
         explode(&mut num, 1);
         assert_eq!(expected, num);
@@ -91,8 +88,8 @@ mod tests {
 
     #[test]
     fn test_explode_left_right_1() {
-        let expected = Number::from_str("[[6,[5,[7,0]]],3]").unwrap();
-        let mut num = Number::from_str("[[6,[5,[4,[3,2]]]],1]").unwrap();
+        let expected = Tree::from_str("[[6,[5,[7,0]]],3]").unwrap();
+        let mut num = Tree::from_str("[[6,[5,[4,[3,2]]]],1]").unwrap();
 
         explode(&mut num, 1);
         assert_eq!(expected, num);
@@ -100,8 +97,8 @@ mod tests {
 
     #[test]
     fn test_explode_left_right_2() {
-        let expected = Number::from_str("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]").unwrap();
-        let mut num = Number::from_str("[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]").unwrap();
+        let expected = Tree::from_str("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]").unwrap();
+        let mut num = Tree::from_str("[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]").unwrap();
 
         explode(&mut num, 1);
         assert_eq!(expected, num);
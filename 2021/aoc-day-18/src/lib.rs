@@ -1,28 +1,32 @@
 use std::fmt::{Display, Formatter};
 
 mod explode;
+mod number;
 mod parsing;
 mod part_one;
 mod part_two;
 mod reduce;
 mod split;
 
-pub use part_one::part_one;
-pub use part_two::part_two;
+pub use number::Number;
+pub use part_one::{part_one, part_one_tree};
+pub use part_two::{part_two, part_two_tree};
 
 type Numeric = u32;
 
+/// The original representation of a snailfish number, kept for comparison with
+/// the faster [`Number`]
 #[derive(Debug, Clone, Eq, PartialEq)]
-pub enum Number {
+pub enum Tree {
     Value(Numeric),
-    Tuple(Box<(Number, Number)>),
+    Tuple(Box<(Tree, Tree)>),
 }
 
-impl Number {
+impl Tree {
     pub fn magnitude(&self) -> Numeric {
         match self {
-            Number::Value(x) => *x,
-            Number::Tuple(tuple) => tuple.0.magnitude() * 3 + tuple.1.magnitude() * 2,
+            Tree::Value(x) => *x,
+            Tree::Tuple(tuple) => tuple.0.magnitude() * 3 + tuple.1.magnitude() * 2,
         }
     }
 
@@ -30,19 +34,19 @@ impl Number {
         reduce::reduce(self);
     }
 
-    pub fn add(&mut self, n: Number) {
-        let mut temp = Number::Value(0);
+    pub fn add(&mut self, n: Tree) {
+        let mut temp = Tree::Value(0);
         std::mem::swap(self, &mut temp);
 
-        *self = Number::Tuple(Box::new((temp, n)))
+        *self = Tree::Tuple(Box::new((temp, n)))
     }
 }
 
-impl Display for Number {
+impl Display for Tree {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            Number::Value(v) => write!(f, "{}", *v)?,
-            Number::Tuple(t) => write!(f, "[{},{}]", &t.0, &t.1)?,
+            Tree::Value(v) => write!(f, "{}", *v)?,
+            Tree::Tuple(t) => write!(f, "[{},{}]", &t.0, &t.1)?,
         }
 
         Ok(())
@@ -56,21 +60,21 @@ mod tests {
 
     #[test]
     fn test_magnitude_simple() {
-        let num = Number::from_str("[1,2]").unwrap();
+        let num = Tree::from_str("[1,2]").unwrap();
         assert_eq!(7, num.magnitude());
     }
 
     #[test]
     fn test_magnitude_complex() {
-        let num = Number::from_str("[[1,2],[3,4]]").unwrap();
+        let num = Tree::from_str("[[1,2],[3,4]]").unwrap();
         assert_eq!(55, num.magnitude());
     }
 
     #[test]
     fn test_add_1() {
-        let exp = Number::from_str("[[1,1],[2,2]]").unwrap();
-        let mut a = Number::from_str("[1,1]").unwrap();
-        let b = Number::from_str("[2,2]").unwrap();
+        let exp = Tree::from_str("[[1,1],[2,2]]").unwrap();
+        let mut a = Tree::from_str("[1,1]").unwrap();
+        let b = Tree::from_str("[2,2]").unwrap();
 
         a.add(b);
         assert_eq!(exp, a);
@@ -1,9 +1,15 @@
+use std::error::Error;
+
 use aoc_day_18::{part_one, part_two, Number};
-use aoc_shared::input::load_line_delimited_input_from_autodetect;
+use aoc_shared::input::try_load_line_delimited_input_from_autodetect;
+use aoc_shared::input_locator;
 
-fn main() {
-    let input: Vec<Number> = load_line_delimited_input_from_autodetect();
+fn main() -> Result<(), Box<dyn Error>> {
+    let input: Vec<Number> =
+        try_load_line_delimited_input_from_autodetect(input_locator!(2021, 18))?;
 
     println!("Part 1: {}", part_one(&input));
     println!("Part 2: {}", part_two(&input));
+
+    Ok(())
 }
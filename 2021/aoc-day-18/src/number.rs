@@ -0,0 +1,314 @@
+use std::fmt::{Display, Formatter};
+use std::iter::Sum;
+use std::ops::Add;
+use std::str::FromStr;
+
+use crate::Numeric;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Element {
+    // the number of pairs around the value
+    depth: u8,
+    value: Numeric,
+}
+
+/// A snailfish number, stored as its regular numbers from left to right together
+/// with their depth. The pairs are implied by the depths, so exploding and
+/// splitting only have to look at the neighbouring values.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Number {
+    elements: Vec<Element>,
+}
+
+impl Number {
+    pub fn magnitude(&self) -> Numeric {
+        // combine the two values on top of the stack while they are in the same pair
+        let mut stack: Vec<Element> = Vec::with_capacity(8);
+        for &element in self.elements.iter() {
+            let mut element = element;
+            while let Some(&top) = stack.last() {
+                if top.depth != element.depth {
+                    break;
+                }
+
+                stack.pop();
+                element = Element {
+                    depth: element.depth - 1,
+                    value: 3 * top.value + 2 * element.value,
+                };
+            }
+            stack.push(element);
+        }
+
+        debug_assert_eq!(1, stack.len());
+        stack[0].value
+    }
+
+    fn reduce(&mut self) {
+        loop {
+            self.explode_all();
+            if !self.split_first() {
+                break;
+            }
+        }
+    }
+
+    // Explodes the pairs nested in 4 pairs from left to right. Exploding never
+    // creates deeper pairs, so one pass is enough.
+    fn explode_all(&mut self) {
+        let mut idx = 0;
+        while idx < self.elements.len() {
+            if self.elements[idx].depth <= 4 {
+                idx += 1;
+                continue;
+            }
+
+            let left = self.elements[idx];
+            let right = self.elements[idx + 1];
+            debug_assert_eq!(left.depth, right.depth, "the pair is nested too deep");
+
+            if idx > 0 {
+                self.elements[idx - 1].value += left.value;
+            }
+            if let Some(next) = self.elements.get_mut(idx + 2) {
+                next.value += right.value;
+            }
+
+            self.elements[idx] = Element {
+                depth: left.depth - 1,
+                value: 0,
+            };
+            self.elements.remove(idx + 1);
+            idx += 1;
+        }
+    }
+
+    fn split_first(&mut self) -> bool {
+        let Some(idx) = self.elements.iter().position(|e| e.value >= 10) else {
+            return false;
+        };
+
+        let Element { depth, value } = self.elements[idx];
+        let left = Element {
+            depth: depth + 1,
+            value: value / 2,
+        };
+        let right = Element {
+            depth: depth + 1,
+            value: value.div_ceil(2),
+        };
+
+        self.elements[idx] = left;
+        self.elements.insert(idx + 1, right);
+        true
+    }
+}
+
+/// Adds and reduces the numbers
+impl Add for Number {
+    type Output = Number;
+
+    fn add(mut self, rhs: Number) -> Number {
+        self.elements.extend(rhs.elements);
+        for element in self.elements.iter_mut() {
+            element.depth += 1;
+        }
+
+        self.reduce();
+        self
+    }
+}
+
+impl Add for &Number {
+    type Output = Number;
+
+    fn add(self, rhs: &Number) -> Number {
+        self.clone() + rhs.clone()
+    }
+}
+
+/// Panics when there are no numbers, as snailfish numbers have no zero
+impl Sum for Number {
+    fn sum<I: Iterator<Item = Number>>(iter: I) -> Number {
+        iter.reduce(|sum, n| sum + n)
+            .expect("cannot sum zero snailfish numbers")
+    }
+}
+
+impl<'a> Sum<&'a Number> for Number {
+    fn sum<I: Iterator<Item = &'a Number>>(iter: I) -> Number {
+        iter.cloned().sum()
+    }
+}
+
+impl Display for Number {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let next = write_element(f, &self.elements, 0, 0)?;
+        debug_assert_eq!(self.elements.len(), next);
+        Ok(())
+    }
+}
+
+// Writes the element, or the pair of elements at `depth`, that starts at `idx`.
+// Returns the index after it.
+fn write_element(
+    f: &mut Formatter<'_>,
+    elements: &[Element],
+    idx: usize,
+    depth: u8,
+) -> Result<usize, std::fmt::Error> {
+    if elements[idx].depth == depth {
+        write!(f, "{}", elements[idx].value)?;
+        return Ok(idx + 1);
+    }
+
+    write!(f, "[")?;
+    let idx = write_element(f, elements, idx, depth + 1)?;
+    write!(f, ",")?;
+    let idx = write_element(f, elements, idx, depth + 1)?;
+    write!(f, "]")?;
+    Ok(idx)
+}
+
+impl FromStr for Number {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().as_bytes();
+        if s.first() != Some(&b'[') {
+            return Err(format!(
+                "Expecting '[' at 0: {:?}",
+                String::from_utf8_lossy(s)
+            ));
+        }
+
+        let mut elements = vec![];
+        let end = parse_element(s, 0, 0, &mut elements)?;
+        if end != s.len() {
+            return Err(format!("Unexpected input at {}", end));
+        }
+
+        Ok(Number { elements })
+    }
+}
+
+// Parses a regular number or a pair starting at `pos`. Returns the position after it.
+fn parse_element(
+    s: &[u8],
+    pos: usize,
+    depth: u8,
+    elements: &mut Vec<Element>,
+) -> Result<usize, String> {
+    match s.get(pos) {
+        Some(b'[') => {
+            let pos = parse_element(s, pos + 1, depth + 1, elements)?;
+            let pos = expect(s, pos, b',')?;
+            let pos = parse_element(s, pos, depth + 1, elements)?;
+            expect(s, pos, b']')
+        }
+        Some(ch) if ch.is_ascii_digit() => {
+            let len = s[pos..].iter().take_while(|ch| ch.is_ascii_digit()).count();
+            let value = std::str::from_utf8(&s[pos..pos + len])
+                .unwrap()
+                .parse()
+                .map_err(|e| format!("Failed to parse number at {}: {:?}", pos, e))?;
+
+            elements.push(Element { depth, value });
+            Ok(pos + len)
+        }
+        Some(ch) => Err(format!("Unexpected {:?} at {}", *ch as char, pos)),
+        None => Err(format!("Unexpected end at {}", pos)),
+    }
+}
+
+fn expect(s: &[u8], pos: usize, expected: u8) -> Result<usize, String> {
+    match s.get(pos) {
+        Some(&ch) if ch == expected => Ok(pos + 1),
+        _ => Err(format!("Expecting {:?} at {}", expected as char, pos)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::number::Number;
+    use crate::Tree;
+
+    const NUMBERS: [&str; 6] = [
+        "[1,2]",
+        "[[1,2],3]",
+        "[9,[8,7]]",
+        "[[1,9],[8,5]]",
+        "[[[[1,2],[3,4]],[[5,6],[7,8]]],9]",
+        "[[[9,[3,8]],[[0,9],6]],[[[3,7],[4,9]],3]]",
+    ];
+
+    #[test]
+    fn test_round_trip() {
+        for number in NUMBERS {
+            assert_eq!(number, Number::from_str(number).unwrap().to_string());
+        }
+
+        // values above 9 may appear before reducing
+        assert_eq!(
+            "[10,[3,15]]",
+            Number::from_str("[10,[3,15]]").unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        for number in [
+            "", "1", "[1]", "[1,2", "[1,2]]", "[1,[2,3]", "[1;2]", "[a,2]",
+        ] {
+            assert!(Number::from_str(number).is_err(), "{:?}", number);
+        }
+    }
+
+    #[test]
+    fn test_magnitude() {
+        let examples = [
+            ("[[1,2],[[3,4],5]]", 143),
+            ("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]", 1384),
+            ("[[[[1,1],[2,2]],[3,3]],[4,4]]", 445),
+            ("[[[[3,0],[5,3]],[4,4]],[5,5]]", 791),
+            ("[[[[5,0],[7,4]],[5,5]],[6,6]]", 1137),
+            (
+                "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]",
+                3488,
+            ),
+        ];
+        for (number, magnitude) in examples {
+            assert_eq!(magnitude, Number::from_str(number).unwrap().magnitude());
+        }
+
+        for number in NUMBERS {
+            assert_eq!(
+                Tree::from_str(number).unwrap().magnitude(),
+                Number::from_str(number).unwrap().magnitude()
+            );
+        }
+    }
+
+    #[test]
+    fn test_add() {
+        let a = Number::from_str("[[[[4,3],4],4],[7,[[8,4],9]]]").unwrap();
+        let b = Number::from_str("[1,1]").unwrap();
+
+        let sum = &a + &b;
+        assert_eq!("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]", sum.to_string());
+        assert_eq!(sum, a + b);
+    }
+
+    #[test]
+    fn test_sum() {
+        let numbers = ["[1,1]", "[2,2]", "[3,3]", "[4,4]", "[5,5]", "[6,6]"]
+            .iter()
+            .map(|n| Number::from_str(n).unwrap())
+            .collect::<Vec<_>>();
+
+        let sum: Number = numbers.iter().sum();
+        assert_eq!("[[[[5,0],[7,4]],[5,5]],[6,6]]", sum.to_string());
+    }
+}
@@ -1,7 +1,7 @@
-use crate::Number;
+use crate::Tree;
 use std::str::FromStr;
 
-impl FromStr for Number {
+impl FromStr for Tree {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -23,7 +23,7 @@ impl FromStr for Number {
                     .parse()
                     .map_err(|e| format!("Failed to parse right number: {:?} -> {:?}", e, s))?;
 
-                (Number::Value(ln), Number::Value(rn))
+                (Tree::Value(ln), Tree::Value(rn))
             } else if s.as_bytes()[0].is_ascii_digit() {
                 let (n, rest) = s
                     .split_once(',')
@@ -32,9 +32,9 @@ impl FromStr for Number {
                 let number = n
                     .parse()
                     .map_err(|e| format!("Failed to parse left number: {:?} -> {:?}", e, s))?;
-                let remaining = Number::from_str(rest)?;
+                let remaining = Tree::from_str(rest)?;
 
-                (Number::Value(number), remaining)
+                (Tree::Value(number), remaining)
             } else if s.as_bytes()[s.len() - 1].is_ascii_digit() {
                 let (rest, n) = s
                     .rsplit_once(',')
@@ -43,9 +43,9 @@ impl FromStr for Number {
                 let number = n
                     .parse()
                     .map_err(|e| format!("Failed to parse right number: {:?} -> {:?}", e, s))?;
-                let remaining = Number::from_str(rest)?;
+                let remaining = Tree::from_str(rest)?;
 
-                (remaining, Number::Value(number))
+                (remaining, Tree::Value(number))
             } else {
                 let mut brackets = 0i32;
                 let mut split_pos = 0;
@@ -71,13 +71,13 @@ impl FromStr for Number {
                 }
 
                 let (left, right) = s.split_at(split_pos);
-                let left = Number::from_str(left)?;
-                let right = Number::from_str(&right[1..])?;
+                let left = Tree::from_str(left)?;
+                let right = Tree::from_str(&right[1..])?;
 
                 (left, right)
             };
 
-        Ok(Number::Tuple(Box::new((left, right))))
+        Ok(Tree::Tuple(Box::new((left, right))))
     }
 }
 
@@ -87,18 +87,18 @@ mod tests {
 
     #[test]
     fn test_parsing_numeric_pair() {
-        let actual = Number::from_str("[1,2]").unwrap();
-        let expected = Number::Tuple(Box::new((Number::Value(1), Number::Value(2))));
+        let actual = Tree::from_str("[1,2]").unwrap();
+        let expected = Tree::Tuple(Box::new((Tree::Value(1), Tree::Value(2))));
 
         assert_eq!(expected, actual);
     }
 
     #[test]
     fn test_parsing_value_and_tuple() {
-        let actual = Number::from_str("[1,[2,3]]").unwrap();
-        let expected = Number::Tuple(Box::new((
-            Number::Value(1),
-            Number::Tuple(Box::new((Number::Value(2), Number::Value(3)))),
+        let actual = Tree::from_str("[1,[2,3]]").unwrap();
+        let expected = Tree::Tuple(Box::new((
+            Tree::Value(1),
+            Tree::Tuple(Box::new((Tree::Value(2), Tree::Value(3)))),
         )));
 
         assert_eq!(expected, actual);
@@ -106,10 +106,10 @@ mod tests {
 
     #[test]
     fn test_parsing_tuple_and_value() {
-        let actual = Number::from_str("[[1,2],3]").unwrap();
-        let expected = Number::Tuple(Box::new((
-            Number::Tuple(Box::new((Number::Value(1), Number::Value(2)))),
-            Number::Value(3),
+        let actual = Tree::from_str("[[1,2],3]").unwrap();
+        let expected = Tree::Tuple(Box::new((
+            Tree::Tuple(Box::new((Tree::Value(1), Tree::Value(2)))),
+            Tree::Value(3),
         )));
 
         assert_eq!(expected, actual);
@@ -117,10 +117,10 @@ mod tests {
 
     #[test]
     fn test_parsing_tuple_and_tuple() {
-        let actual = Number::from_str("[[1,2],[3,4]]").unwrap();
-        let expected = Number::Tuple(Box::new((
-            Number::Tuple(Box::new((Number::Value(1), Number::Value(2)))),
-            Number::Tuple(Box::new((Number::Value(3), Number::Value(4)))),
+        let actual = Tree::from_str("[[1,2],[3,4]]").unwrap();
+        let expected = Tree::Tuple(Box::new((
+            Tree::Tuple(Box::new((Tree::Value(1), Tree::Value(2)))),
+            Tree::Tuple(Box::new((Tree::Value(3), Tree::Value(4)))),
         )));
 
         assert_eq!(expected, actual);
@@ -1,11 +1,16 @@
-use crate::{Number, Numeric};
+use crate::{Number, Numeric, Tree};
 
 pub fn part_one(input: &[Number]) -> Numeric {
+    assert!(!input.is_empty());
+    input.iter().sum::<Number>().magnitude()
+}
+
+pub fn part_one_tree(input: &[Tree]) -> Numeric {
     assert!(!input.is_empty());
     sum_numbers(input).magnitude()
 }
 
-fn sum_numbers(numbers: &[Number]) -> Number {
+fn sum_numbers(numbers: &[Tree]) -> Tree {
     assert!(!numbers.is_empty());
 
     let mut sum = numbers[0].clone();
@@ -123,14 +128,18 @@ mod tests {
     fn verify_sum(nums: &str, sum: &str) {
         let input = parse_line_delimited(nums);
         let actual = sum_numbers(&input);
-        let expected = Number::from_str(sum).unwrap();
+        let expected = Tree::from_str(sum).unwrap();
         assert_eq!(expected, actual);
+
+        let input: Vec<Number> = parse_line_delimited(nums);
+        let actual: Number = input.iter().sum();
+        assert_eq!(sum, actual.to_string());
     }
 
     #[test]
     fn test_part_one() {
-        let input = parse_line_delimited(load_text_input_from_file("inputs/input.txt"));
-        let answer = part_one(&input);
-        assert_eq!(3486, answer);
+        let input = load_text_input_from_file("inputs/input.txt");
+        assert_eq!(3486, part_one(&parse_line_delimited(&input)));
+        assert_eq!(3486, part_one_tree(&parse_line_delimited(&input)));
     }
 }
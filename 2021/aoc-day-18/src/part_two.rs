@@ -1,10 +1,26 @@
-use crate::{Number, Numeric};
+use crate::{Number, Numeric, Tree};
 
 pub fn part_two(input: &[Number]) -> Numeric {
     assert!(!input.is_empty());
 
     let mut best = 0;
 
+    for (i, a) in input.iter().enumerate() {
+        for (j, b) in input.iter().enumerate() {
+            if i != j {
+                best = best.max((a + b).magnitude());
+            }
+        }
+    }
+
+    best
+}
+
+pub fn part_two_tree(input: &[Tree]) -> Numeric {
+    assert!(!input.is_empty());
+
+    let mut best = 0;
+
     for i in 0..input.len() {
         for j in 0..input.len() {
             if i == j {
@@ -20,3 +36,36 @@ pub fn part_two(input: &[Number]) -> Numeric {
 
     best
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_shared::input::load_text_input_from_file;
+    use aoc_shared::parsing::parse_line_delimited;
+
+    const SAMPLE: &str = r#"
+        [[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+        [[[5,[2,8]],4],[5,[[9,9],0]]]
+        [6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+        [[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+        [[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+        [[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+        [[[[5,4],[7,7]],8],[[8,3],8]]
+        [[9,3],[[9,9],[6,[4,9]]]]
+        [[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+        [[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]
+    "#;
+
+    #[test]
+    fn test_sample() {
+        assert_eq!(3993, part_two(&parse_line_delimited(SAMPLE)));
+        assert_eq!(3993, part_two_tree(&parse_line_delimited(SAMPLE)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = load_text_input_from_file("inputs/input.txt");
+        let answer = part_two(&parse_line_delimited(&input));
+        assert_eq!(part_two_tree(&parse_line_delimited(&input)), answer);
+    }
+}
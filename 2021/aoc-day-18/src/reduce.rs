@@ -1,8 +1,8 @@
 use crate::explode::explode;
 use crate::split::split;
-use crate::Number;
+use crate::Tree;
 
-pub(crate) fn reduce(number: &mut Number) {
+pub(crate) fn reduce(number: &mut Tree) {
     loop {
         let (exploded, _, _) = explode(number, 1);
         if exploded {
@@ -24,8 +24,8 @@ mod tests {
 
     #[test]
     fn test_reduce_1() {
-        let expected = Number::from_str("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]").unwrap();
-        let mut num = Number::from_str("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]").unwrap();
+        let expected = Tree::from_str("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]").unwrap();
+        let mut num = Tree::from_str("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]").unwrap();
 
         reduce(&mut num);
         assert_eq!(expected, num);
@@ -1,19 +1,19 @@
-use crate::Number;
+use crate::Tree;
 
-pub(crate) fn split(number: &mut Number) -> bool {
+pub(crate) fn split(number: &mut Tree) -> bool {
     match number {
-        Number::Value(v) => {
+        Tree::Value(v) => {
             if *v >= 10 {
                 let l = *v / 2;
                 let r = *v - l;
 
-                *number = Number::Tuple(Box::new((Number::Value(l), Number::Value(r))));
+                *number = Tree::Tuple(Box::new((Tree::Value(l), Tree::Value(r))));
                 return true;
             }
             false
         }
 
-        Number::Tuple(t) => {
+        Tree::Tuple(t) => {
             if split(&mut t.0) {
                 return true;
             }
@@ -30,8 +30,8 @@ mod tests {
 
     #[test]
     fn test_split_left_even() {
-        let mut num = Number::from_str("[10,1]").unwrap();
-        let expected = Number::from_str("[[5,5],1]").unwrap();
+        let mut num = Tree::from_str("[10,1]").unwrap();
+        let expected = Tree::from_str("[[5,5],1]").unwrap();
 
         let ok = split(&mut num);
         assert!(ok, "The number should have been split!");
@@ -40,8 +40,8 @@ mod tests {
 
     #[test]
     fn test_split_right_even() {
-        let mut num = Number::from_str("[1,10]").unwrap();
-        let expected = Number::from_str("[1,[5,5]]").unwrap();
+        let mut num = Tree::from_str("[1,10]").unwrap();
+        let expected = Tree::from_str("[1,[5,5]]").unwrap();
 
         let ok = split(&mut num);
         assert!(ok, "The number should have been split!");
@@ -50,8 +50,8 @@ mod tests {
 
     #[test]
     fn test_split_left_odd() {
-        let mut num = Number::from_str("[11,1]").unwrap();
-        let expected = Number::from_str("[[5,6],1]").unwrap();
+        let mut num = Tree::from_str("[11,1]").unwrap();
+        let expected = Tree::from_str("[[5,6],1]").unwrap();
 
         let ok = split(&mut num);
         assert!(ok, "The number should have been split!");
@@ -60,8 +60,8 @@ mod tests {
 
     #[test]
     fn test_split_right_odd() {
-        let mut num = Number::from_str("[1,11]").unwrap();
-        let expected = Number::from_str("[1,[5,6]]").unwrap();
+        let mut num = Tree::from_str("[1,11]").unwrap();
+        let expected = Tree::from_str("[1,[5,6]]").unwrap();
 
         let ok = split(&mut num);
         assert!(ok, "The number should have been split!");
@@ -1,8 +1,15 @@
+use std::error::Error;
+
 use aoc_day_21::{parse_input, part_one, part_two};
-use aoc_shared::input::load_text_input_from_autodetect;
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
 
-fn main() {
-    let (a, b) = parse_input(load_text_input_from_autodetect());
+fn main() -> Result<(), Box<dyn Error>> {
+    let (a, b) = parse_input(try_load_text_input_from_autodetect(input_locator!(
+        2021, 21
+    ))?);
     println!("Part 1: {}", part_one(a, b));
     println!("Part 2: {}", part_two(a, b));
+
+    Ok(())
 }
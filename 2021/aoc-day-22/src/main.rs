@@ -1,8 +1,13 @@
+use std::error::Error;
+
 use aoc_day_22::{part_one_v2, part_two};
-use aoc_shared::input::load_line_delimited_input_from_autodetect;
+use aoc_shared::input::try_load_line_delimited_input_from_autodetect;
+use aoc_shared::input_locator;
 
-fn main() {
-    let input = load_line_delimited_input_from_autodetect();
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = try_load_line_delimited_input_from_autodetect(input_locator!(2021, 22))?;
     println!("Part 1: {}", part_one_v2(&input));
     println!("Part 2: {}", part_two(&input));
+
+    Ok(())
 }
@@ -1,4 +1,4 @@
-use aoc_day_23::{parse_input, part_one, part_two};
+use aoc_day_23::{parse_input, part_one, part_two, INPUT_PART_ONE, INPUT_PART_TWO};
 use aoc_shared::input::load_text_input_from_file;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
@@ -11,29 +11,29 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input_one = load_text_input_from_file("inputs/input-1.txt");
+    let input_one = load_text_input_from_file(INPUT_PART_ONE);
     c.bench_function("parsing-a", |b| {
-        b.iter(|| black_box(parse_input::<_, 2>(black_box(&input_one))));
+        b.iter(|| black_box(parse_input(black_box(&input_one))));
     });
 
-    let input_two = load_text_input_from_file("inputs/input-2.txt");
+    let input_two = load_text_input_from_file(INPUT_PART_TWO);
     c.bench_function("parsing-b", |b| {
-        b.iter(|| black_box(parse_input::<_, 4>(black_box(&input_two))));
+        b.iter(|| black_box(parse_input(black_box(&input_two))));
     });
 }
 
 fn benchmark_part_1(c: &mut Criterion) {
-    let (rooms, hall) = parse_input(load_text_input_from_file("inputs/input-1.txt"));
+    let burrow = parse_input(load_text_input_from_file(INPUT_PART_ONE));
 
     c.bench_function("part-1", |b| {
-        b.iter(|| black_box(part_one(black_box(rooms), black_box(hall))))
+        b.iter(|| black_box(part_one(black_box(&burrow))))
     });
 }
 
 fn benchmark_part_2(c: &mut Criterion) {
-    let (rooms, hall) = parse_input(load_text_input_from_file("inputs/input-2.txt"));
+    let burrow = parse_input(load_text_input_from_file(INPUT_PART_TWO));
 
     c.bench_function("part-2", |b| {
-        b.iter(|| black_box(part_two(black_box(rooms), black_box(hall))))
+        b.iter(|| black_box(part_two(black_box(&burrow))))
     });
 }
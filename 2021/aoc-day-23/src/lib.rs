@@ -1,22 +1,148 @@
+use std::fmt::{Display, Formatter};
+
 mod parsing;
 mod solver;
 
 const FREE_SPOT: u8 = u8::MAX;
 
-pub const HALL_LEN: usize = 11;
-pub const ROOMS: usize = 4;
-
-pub type Hall = [u8; HALL_LEN];
-pub type Rooms<const N: usize> = [[u8; N]; ROOMS];
+/// The inputs of the two parts, which have different burrows
+pub const INPUT_PART_ONE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/input-1.txt");
+pub const INPUT_PART_TWO: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/input-2.txt");
 
 pub use parsing::parse_input;
+pub use solver::Move;
+
+/// A place where an amphipod can stop
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Spot {
+    /// A hallway spot, identified by its column in the diagram
+    Hall(usize),
+    /// A room spot, where depth 0 is the one next to the hallway
+    Room { room: usize, depth: usize },
+}
+
+/// The burrow as a graph of spots and the amphipods in them
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Burrow {
+    // the diagram without any amphipods
+    template: Vec<String>,
+    spots: Vec<Spot>,
+    // the (row, column) of each spot in the diagram
+    positions: Vec<(usize, usize)>,
+    // the spots of each room, from the door to the back
+    rooms: Vec<Vec<usize>>,
+    // the column of each room's door
+    doors: Vec<usize>,
+    // the amphipod in each spot
+    amphipods: Vec<u8>,
+}
+
+impl Burrow {
+    pub fn spots(&self) -> &[Spot] {
+        &self.spots
+    }
+
+    /// The amphipod in each spot, `None` for a free one. `Some(0)` is an `A`.
+    pub fn amphipods(&self) -> impl Iterator<Item = Option<u8>> + '_ {
+        self.amphipods
+            .iter()
+            .map(|&a| if a == FREE_SPOT { None } else { Some(a) })
+    }
+
+    fn with_amphipods(&self, amphipods: Vec<u8>) -> Burrow {
+        Burrow {
+            amphipods,
+            ..self.clone()
+        }
+    }
+}
+
+impl Display for Burrow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut lines = self
+            .template
+            .iter()
+            .map(|line| line.as_bytes().to_vec())
+            .collect::<Vec<_>>();
 
-pub fn part_one(input: Rooms<2>, hall: Hall) -> u64 {
-    solver::solve(input, hall)
+        for (&(row, col), &amphipod) in self.positions.iter().zip(self.amphipods.iter()) {
+            lines[row][col] = amphipod_char(amphipod) as u8;
+        }
+
+        for line in lines {
+            writeln!(f, "{}", String::from_utf8(line).unwrap())?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn part_one(burrow: &Burrow) -> u64 {
+    solver::solve(burrow).expect("no solution")
+}
+
+pub fn part_two(burrow: &Burrow) -> u64 {
+    solver::solve(burrow).expect("no solution")
 }
 
-pub fn part_two(input: Rooms<4>, hall: Hall) -> u64 {
-    solver::solve(input, hall)
+/// An optimal sequence of moves. It is displayed as the burrow after each move.
+#[derive(Clone, Debug)]
+pub struct Solution {
+    pub energy: u64,
+    pub start: Burrow,
+    pub steps: Vec<(Move, Burrow)>,
+}
+
+/// Like `part_one`/`part_two`, but also returns the moves of an optimal solution
+pub fn solve_with_path(burrow: &Burrow) -> Option<Solution> {
+    let (energy, steps) = solver::solve_with_path(burrow)?;
+    Some(Solution {
+        energy,
+        start: burrow.clone(),
+        steps: steps
+            .into_iter()
+            .map(|(step, amphipods)| (step, burrow.with_amphipods(amphipods)))
+            .collect(),
+    })
+}
+
+impl Display for Solution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.start)?;
+
+        let mut total = 0;
+        for (step, burrow) in self.steps.iter() {
+            total += step.energy;
+
+            writeln!(f)?;
+            writeln!(
+                f,
+                "{} moves from {} to {}: {} energy (total {})",
+                amphipod_char(step.amphipod),
+                spot_name(step.from),
+                spot_name(step.to),
+                step.energy,
+                total
+            )?;
+            write!(f, "{}", burrow)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn amphipod_char(amphipod: u8) -> char {
+    match amphipod {
+        FREE_SPOT => '.',
+        a => (b'A' + a) as char,
+    }
+}
+
+fn spot_name(spot: Spot) -> String {
+    match spot {
+        Spot::Hall(col) => format!("hall {}", col),
+        Spot::Room { room, depth } => format!("room {} depth {}", room + 1, depth),
+    }
 }
 
 #[cfg(test)]
@@ -24,17 +150,100 @@ mod tests {
     use super::*;
     use aoc_shared::input::load_text_input_from_file;
 
+    const EXAMPLE: &str = "\
+#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########
+";
+
+    const EXAMPLE_UNFOLDED: &str = "\
+#############
+#...........#
+###B#C#B#D###
+  #D#C#B#A#
+  #D#B#A#C#
+  #A#D#C#A#
+  #########
+";
+
     #[test]
     fn test_part_one() {
-        let (rooms, hall) = parse_input(load_text_input_from_file("inputs/input-1.txt"));
-        let answer = part_one(rooms, hall);
+        let burrow = parse_input(load_text_input_from_file(INPUT_PART_ONE));
+        let answer = part_one(&burrow);
         assert_eq!(13556, answer);
     }
 
     #[test]
     fn test_part_two() {
-        let (rooms, hall) = parse_input(load_text_input_from_file("inputs/input-2.txt"));
-        let answer = part_two(rooms, hall);
+        let burrow = parse_input(load_text_input_from_file(INPUT_PART_TWO));
+        let answer = part_two(&burrow);
         assert_eq!(54200, answer);
     }
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(12521, part_one(&parse_input(EXAMPLE)));
+        assert_eq!(44169, part_two(&parse_input(EXAMPLE_UNFOLDED)));
+    }
+
+    #[test]
+    fn test_parsing() {
+        let burrow = parse_input(EXAMPLE);
+        assert_eq!(7 + 8, burrow.spots().len());
+        assert_eq!(Spot::Hall(1), burrow.spots()[0]);
+        assert_eq!(Spot::Room { room: 0, depth: 1 }, burrow.spots()[3]);
+        assert_eq!(Some(Some(0)), burrow.amphipods().nth(3));
+        assert_eq!(EXAMPLE, burrow.to_string());
+    }
+
+    #[test]
+    fn test_custom_burrows() {
+        // three rooms and a longer hallway: the A steps aside, so that the B can
+        // go straight to its room
+        let burrow = parse_input("###########\n#.........#\n###B#A#C###\n  #######\n");
+        assert_eq!(2 + 40 + 4, part_one(&burrow));
+
+        // a deeper burrow with a short hallway
+        let burrow = parse_input("#######\n#.....#\n##B#A##\n #A#B#\n #A#B#\n #####\n");
+        let solution = solve_with_path(&burrow).unwrap();
+        assert_eq!(2 + 40 + 4, solution.energy);
+        assert_eq!(3, solution.steps.len());
+    }
+
+    #[test]
+    #[should_panic(expected = "too many rooms")]
+    fn test_too_many_rooms() {
+        let rooms = 20;
+        let input = format!(
+            "#{}#\n#{}#\n{}#\n",
+            "#".repeat(2 * rooms + 1),
+            ".".repeat(2 * rooms + 1),
+            "#.".repeat(rooms)
+        );
+        parse_input(input);
+    }
+
+    #[test]
+    fn test_solve_with_path() {
+        let input = load_text_input_from_file(INPUT_PART_ONE);
+        let burrow = parse_input(&input);
+
+        let solution = solve_with_path(&burrow).unwrap();
+        assert_eq!(13556, solution.energy);
+        assert_eq!(
+            solution.energy,
+            solution
+                .steps
+                .iter()
+                .map(|(step, _)| step.energy)
+                .sum::<u64>()
+        );
+
+        let replay = solution.to_string();
+        assert!(replay.starts_with(&input));
+        assert!(replay
+            .ends_with("#############\n#...........#\n###A#B#C#D###\n  #A#B#C#D#\n  #########\n"));
+    }
 }
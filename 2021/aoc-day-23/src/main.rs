@@ -1,10 +1,14 @@
-use aoc_day_23::{parse_input, part_one, part_two};
-use aoc_shared::input::load_text_input_from_file;
+use std::error::Error;
 
-fn main() {
-    let (rooms, hall) = parse_input(load_text_input_from_file("inputs/input-1.txt"));
-    println!("Part 1: {}", part_one(rooms, hall));
+use aoc_day_23::{parse_input, part_one, part_two, INPUT_PART_ONE, INPUT_PART_TWO};
+use aoc_shared::input::try_load_text_input_from_file;
 
-    let (rooms, hall) = parse_input(load_text_input_from_file("inputs/input-1.txt"));
-    println!("Part 2: {}", part_two(rooms, hall));
+fn main() -> Result<(), Box<dyn Error>> {
+    let burrow = parse_input(try_load_text_input_from_file(INPUT_PART_ONE)?);
+    println!("Part 1: {}", part_one(&burrow));
+
+    let burrow = parse_input(try_load_text_input_from_file(INPUT_PART_TWO)?);
+    println!("Part 2: {}", part_two(&burrow));
+
+    Ok(())
 }
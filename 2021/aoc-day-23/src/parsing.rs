@@ -1,39 +1,98 @@
-use crate::{Hall, Rooms, FREE_SPOT, HALL_LEN};
+use crate::{Burrow, Spot, FREE_SPOT};
 
-pub fn parse_input<S: AsRef<str>, const N: usize>(input: S) -> (Rooms<N>, Hall) {
+// An amphipod of room `n` takes `10^n` energy per step, which must fit in an `u64`
+const MAX_ROOMS: usize = 19;
+
+/// Parses a burrow diagram. The first row with open cells is the hallway, and
+/// every column of open cells below it is a room, whose door is the hallway cell
+/// right above it. The rooms are the targets of the amphipods `A`, `B`, ... from
+/// left to right.
+///
+/// Panics when there are more than 19 rooms, as the energy of the amphipods of the
+/// later rooms would not fit in an `u64`.
+pub fn parse_input<S: AsRef<str>>(input: S) -> Burrow {
     let input = input.as_ref();
-    let lines = input.lines().collect::<Vec<_>>();
-
-    let mut hall = [0u8; HALL_LEN];
-    lines[1]
-        .as_bytes()
-        .iter()
-        .copied()
-        .skip(1)
-        .take(HALL_LEN)
-        .enumerate()
-        .for_each(|(idx, v)| hall[idx] = if v == b'.' { FREE_SPOT } else { v - b'A' });
-
-    let mut rooms = [[0u8; N]; 4];
-
-    for pos_in_room in (0..N).rev() {
-        let line = lines[2 + N - pos_in_room - 1];
-        let mut iter = line
-            .as_bytes()
-            .iter()
-            .copied()
-            .filter(|&ch| ch.is_ascii_uppercase() || ch == b'.');
+    let mut template = input
+        .lines()
+        .map(|line| line.as_bytes().to_vec())
+        .collect::<Vec<_>>();
+
+    let is_open = |template: &[Vec<u8>], row: usize, col: usize| {
+        template
+            .get(row)
+            .and_then(|line| line.get(col))
+            .is_some_and(|&ch| ch == b'.' || ch.is_ascii_uppercase())
+    };
+
+    let hall_row = (0..template.len())
+        .find(|&row| (0..template[row].len()).any(|col| is_open(&template, row, col)))
+        .expect("the burrow has no hallway");
+
+    let mut rooms: Vec<Vec<usize>> = vec![];
+    let mut doors = vec![];
+    let mut positions = vec![];
+    let mut spots = vec![];
+    let mut amphipods = vec![];
+
+    let mut add_spot = |spot, row: usize, col: usize, template: &mut [Vec<u8>]| {
+        let ch = template[row][col];
+        amphipods.push(match ch {
+            b'.' => FREE_SPOT,
+            amphipod => amphipod - b'A',
+        });
+        template[row][col] = b'.';
+
+        spots.push(spot);
+        positions.push((row, col));
+        spots.len() - 1
+    };
 
-        for room_idx in 0..rooms.len() {
-            let value = iter.next().unwrap();
+    for col in 0..template[hall_row].len() {
+        if !is_open(&template, hall_row, col) {
+            continue;
+        }
+
+        if !is_open(&template, hall_row + 1, col) {
+            add_spot(Spot::Hall(col), hall_row, col, &mut template);
+            continue;
+        }
 
-            rooms[room_idx][pos_in_room] = if value == b'.' {
-                FREE_SPOT
-            } else {
-                value - b'A'
-            }
+        // a door: amphipods never stop here, so it's not a spot
+        let room = rooms.len();
+        let mut room_spots = vec![];
+        let mut row = hall_row + 1;
+        while is_open(&template, row, col) {
+            let depth = room_spots.len();
+            room_spots.push(add_spot(
+                Spot::Room { room, depth },
+                row,
+                col,
+                &mut template,
+            ));
+            row += 1;
         }
+
+        rooms.push(room_spots);
+        doors.push(col);
     }
 
-    (rooms, hall)
+    assert!(rooms.len() <= MAX_ROOMS, "too many rooms");
+    assert!(
+        amphipods
+            .iter()
+            .all(|&a| a == FREE_SPOT || (a as usize) < rooms.len()),
+        "an amphipod has no room"
+    );
+
+    Burrow {
+        template: template
+            .into_iter()
+            .map(|line| String::from_utf8(line).unwrap())
+            .collect(),
+        spots,
+        positions,
+        rooms,
+        doors,
+        amphipods,
+    }
 }
@@ -1,230 +1,257 @@
-use crate::{Hall, Rooms, FREE_SPOT, HALL_LEN, ROOMS};
-use ahash::AHashMap;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
 
-const COSTS: [u64; ROOMS] = [1, 10, 100, 1000];
+use ahash::AHashMap;
 
-// Distances to each free space in the hall from each room
-// Each row contains the distances from this room. A distance
-// of 0 means that it's forbidden to stand at that place
-const HALL_DIST: [[u8; HALL_LEN]; ROOMS] = [
-    [2, 1, 0, 1, 0, 3, 0, 5, 0, 7, 8], // distance from room 1
-    [4, 3, 0, 1, 0, 1, 0, 3, 0, 5, 6], // distance from room 2
-    [6, 5, 0, 3, 0, 1, 0, 1, 0, 3, 4], // distance from room 3
-    [8, 7, 0, 5, 0, 3, 0, 1, 0, 1, 2], // distance from room 4
-];
+use crate::{Burrow, Spot, FREE_SPOT};
 
 type Map<K, V> = AHashMap<K, V>;
 
-pub(crate) fn solve<const N: usize>(input: Rooms<N>, hall: Hall) -> u64 {
-    let mut cache = Map::default();
-    {
-        let end_game_hall = [FREE_SPOT; HALL_LEN];
-        let mut end_game_rooms = [[0u8; N]; ROOMS];
-        for (room_idx, room) in end_game_rooms.iter_mut().enumerate() {
-            room.iter_mut().for_each(|v| *v = room_idx as u8);
-        }
-        cache.insert((end_game_rooms, end_game_hall), Some(0));
-    }
+// The moves and the amphipods in each spot after them
+type Path = Vec<(Move, Vec<u8>)>;
+
+/// An amphipod moving straight to another spot and the energy it takes
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Move {
+    pub amphipod: u8,
+    pub from: Spot,
+    pub to: Spot,
+    pub energy: u64,
+}
 
-    round(input, hall, &mut cache).unwrap()
+pub(crate) fn solve(burrow: &Burrow) -> Option<u64> {
+    search(burrow, |_, _, _| {}).map(|(energy, _)| energy)
 }
 
-fn round<const N: usize>(
-    rooms: Rooms<N>,
-    hall: Hall,
-    cache: &mut Map<(Rooms<N>, Hall), Option<u64>>,
-) -> Option<u64> {
-    if let Some(&cost) = cache.get(&(rooms, hall)) {
-        return cost;
+/// Returns the total energy and an optimal sequence of moves, each with the
+/// amphipods after it
+pub(crate) fn solve_with_path(burrow: &Burrow) -> Option<(u64, Path)> {
+    let mut parents = Map::default();
+    let (energy, end) = search(burrow, |from, step, to| {
+        parents.insert(to.to_vec(), (from.to_vec(), step));
+    })?;
+
+    let mut steps = vec![];
+    let mut state = end;
+    while state != burrow.amphipods {
+        let (previous, step) = parents.remove(&state).unwrap();
+        steps.push((step, state));
+        state = previous;
     }
+    steps.reverse();
+
+    Some((energy, steps))
+}
 
-    let mut min_cost: Option<u64> = None;
+// A* over the amphipod positions. Returns the energy and the final positions.
+// `on_improve` is called with the previous state and the move whenever a cheaper
+// way to reach a state is found.
+fn search<F>(burrow: &Burrow, mut on_improve: F) -> Option<(u64, Vec<u8>)>
+where
+    F: FnMut(&[u8], Move, &[u8]),
+{
+    let start = burrow.amphipods.clone();
 
-    // move from room to hall
-    for (room_idx, room) in rooms.iter().copied().enumerate() {
-        for occ_idx in (0..room.len()).rev() {
-            if room[occ_idx] == FREE_SPOT {
-                continue;
-            }
+    let mut best = Map::default();
+    best.insert(start.clone(), 0);
 
-            if should_move_to_hall(room, occ_idx, room_idx as u8) {
-                // to the left of room
-                for hall_idx in (0..room_to_hall_idx(room_idx)).rev() {
-                    if HALL_DIST[room_idx][hall_idx] == 0 {
-                        continue;
-                    }
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse((heuristic(burrow, &start), 0, start)));
 
-                    if hall[hall_idx] == FREE_SPOT {
-                        min_cost =
-                            move_to_hall(rooms, hall, cache, min_cost, room_idx, occ_idx, hall_idx);
-                        continue;
-                    }
+    while let Some(Reverse((_, energy, state))) = queue.pop() {
+        if is_organized(burrow, &state) {
+            return Some((energy, state));
+        }
 
-                    break;
-                }
+        if best.get(&state).is_some_and(|&e| e < energy) {
+            continue;
+        }
 
-                // to the right of room
-                for hall_idx in room_to_hall_idx(room_idx) + 1..hall.len() {
-                    if HALL_DIST[room_idx][hall_idx] == 0 {
-                        continue;
-                    }
+        for (from, to, cost) in moves(burrow, &state) {
+            let mut next = state.clone();
+            next[to] = state[from];
+            next[from] = FREE_SPOT;
 
-                    if hall[hall_idx] == FREE_SPOT {
-                        min_cost =
-                            move_to_hall(rooms, hall, cache, min_cost, room_idx, occ_idx, hall_idx);
-                        continue;
-                    }
+            let step = Move {
+                amphipod: state[from],
+                from: burrow.spots[from],
+                to: burrow.spots[to],
+                energy: cost,
+            };
 
-                    break;
-                }
+            let next_energy = energy + step.energy;
+            if best.get(&next).is_some_and(|&e| e <= next_energy) {
+                continue;
             }
 
-            break;
+            on_improve(&state, step, &next);
+            best.insert(next.clone(), next_energy);
+            queue.push(Reverse((
+                next_energy + heuristic(burrow, &next),
+                next_energy,
+                next,
+            )));
         }
     }
 
-    // move from hall to room
-    for room_idx in 0..rooms.len() {
-        // from left of the room
-        let mut left_side = None;
-        for hall_idx in (0..room_to_hall_idx(room_idx)).rev() {
-            if hall[hall_idx] == FREE_SPOT {
-                continue;
-            }
+    None
+}
 
-            if hall[hall_idx] == room_idx as u8 {
-                left_side = Some(hall_idx);
-            }
+fn energy_per_step(amphipod: u8) -> u64 {
+    10u64.pow(amphipod as u32)
+}
 
-            break;
-        }
-        if let Some(hall_idx) = left_side {
-            min_cost = move_to_room(rooms, hall, cache, min_cost, room_idx, hall_idx);
-        }
+fn column(burrow: &Burrow, spot: usize) -> usize {
+    burrow.positions[spot].1
+}
 
-        // from right of the room
-        let mut right_side = None;
-        for hall_idx in room_to_hall_idx(room_idx) + 1..hall.len() {
-            if hall[hall_idx] == FREE_SPOT {
-                continue;
-            }
+// The number of steps from a room spot to its door, 0 for the hallway
+fn steps_to_hall(burrow: &Burrow, spot: usize) -> usize {
+    match burrow.spots[spot] {
+        Spot::Hall(_) => 0,
+        Spot::Room { depth, .. } => depth + 1,
+    }
+}
 
-            if hall[hall_idx] == room_idx as u8 {
-                right_side = Some(hall_idx);
-            }
+// Whether the amphipod in a room spot is in its room and only has amphipods of
+// the same kind behind it, so it never has to move again
+fn is_settled(burrow: &Burrow, state: &[u8], spot: usize) -> bool {
+    let Spot::Room { room, depth } = burrow.spots[spot] else {
+        return false;
+    };
 
-            break;
-        }
-        if let Some(hall_idx) = right_side {
-            min_cost = move_to_room(rooms, hall, cache, min_cost, room_idx, hall_idx);
-        }
+    burrow.rooms[room][depth..]
+        .iter()
+        .all(|&s| state[s] as usize == room)
+}
+
+fn is_organized(burrow: &Burrow, state: &[u8]) -> bool {
+    (0..state.len()).all(|spot| state[spot] == FREE_SPOT || is_settled(burrow, state, spot))
+}
+
+// The deepest free spot of a room, if the room contains only its own amphipods
+fn free_target(burrow: &Burrow, state: &[u8], room: usize) -> Option<usize> {
+    let spots = &burrow.rooms[room];
+    if spots
+        .iter()
+        .any(|&s| state[s] != FREE_SPOT && state[s] as usize != room)
+    {
+        return None;
     }
 
-    cache.insert((rooms, hall), min_cost);
-    min_cost
+    spots.iter().rev().copied().find(|&s| state[s] == FREE_SPOT)
+}
+
+// Whether the hallway strictly between two columns is free. The spots at `from`
+// and `to` are not checked, as the first one holds the moving amphipod.
+fn is_hall_free(burrow: &Burrow, state: &[u8], from: usize, to: usize) -> bool {
+    let (low, high) = (from.min(to), from.max(to));
+    burrow
+        .spots
+        .iter()
+        .enumerate()
+        .all(|(spot, kind)| match kind {
+            Spot::Hall(col) => state[spot] == FREE_SPOT || *col <= low || *col >= high,
+            Spot::Room { .. } => true,
+        })
 }
 
-fn move_to_hall<const N: usize>(
-    rooms: Rooms<N>,
-    hall: Hall,
-    cache: &mut Map<(Rooms<N>, Hall), Option<u64>>,
-    min_cost: Option<u64>,
-    room_idx: usize,
-    occ_idx: usize,
-    hall_idx: usize,
-) -> Option<u64> {
-    let occupant = rooms[room_idx][occ_idx];
-
-    let steps_from_front_to_pos = HALL_DIST[room_idx][hall_idx] as u64;
-    let steps_from_room_to_front = (N - occ_idx) as u64;
-    let cost = COSTS[occupant as usize] * (steps_from_room_to_front + steps_from_front_to_pos);
-
-    let mut rooms_next = rooms.clone();
-    rooms_next[room_idx][occ_idx] = FREE_SPOT;
-
-    let mut hall_next = hall.clone();
-    hall_next[hall_idx] = occupant;
-
-    match round(rooms_next, hall_next, cache) {
-        None => min_cost,
-        Some(cst) => {
-            let total = cst + cost;
-            match min_cost {
-                None => Some(total),
-                Some(best) => Some(best.min(total)),
+// All moves as (from, to, energy)
+fn moves(burrow: &Burrow, state: &[u8]) -> Vec<(usize, usize, u64)> {
+    let mut moves = vec![];
+
+    for from in 0..state.len() {
+        let amphipod = state[from];
+        if amphipod == FREE_SPOT || is_settled(burrow, state, from) {
+            continue;
+        }
+
+        // the spots between a room spot and the door must be free
+        if let Spot::Room { room, depth } = burrow.spots[from] {
+            if burrow.rooms[room][..depth]
+                .iter()
+                .any(|&s| state[s] != FREE_SPOT)
+            {
+                continue;
             }
         }
-    }
-}
 
-fn move_to_room<const N: usize>(
-    rooms: Rooms<N>,
-    hall: Hall,
-    cache: &mut Map<(Rooms<N>, Hall), Option<u64>>,
-    min_cost: Option<u64>,
-    room_idx: usize,
-    hall_idx: usize,
-) -> Option<u64> {
-    if let Some(idx) = find_room_idx(rooms, room_idx) {
-        let occupant = hall[hall_idx];
-
-        let steps_from_hall_to_front = HALL_DIST[room_idx][hall_idx] as u64;
-        let steps_from_front_to_room = (N - idx) as u64;
-        let cost = COSTS[occupant as usize] * (steps_from_hall_to_front + steps_from_front_to_room);
-
-        let mut rooms_next = rooms.clone();
-        rooms_next[room_idx][idx] = occupant;
-
-        let mut hall_next = hall.clone();
-        hall_next[hall_idx] = FREE_SPOT;
-
-        return match round(rooms_next, hall_next, cache) {
-            None => min_cost,
-            Some(cst) => {
-                let total = cst + cost;
-                match min_cost {
-                    None => Some(total),
-                    Some(best) => Some(best.min(total)),
+        let from_col = column(burrow, from);
+        let per_step = energy_per_step(amphipod);
+
+        // straight into its room, which is always the best move
+        if let Some(to) = free_target(burrow, state, amphipod as usize) {
+            let door = burrow.doors[amphipod as usize];
+            let in_own_room =
+                matches!(burrow.spots[from], Spot::Room { room, .. } if room == amphipod as usize);
+
+            if !in_own_room && is_hall_free(burrow, state, from_col, door) {
+                let steps = steps_to_hall(burrow, from)
+                    + from_col.abs_diff(door)
+                    + steps_to_hall(burrow, to);
+                moves.push((from, to, per_step * steps as u64));
+                continue;
+            }
+        }
+
+        // out of a room to any reachable hallway spot
+        if matches!(burrow.spots[from], Spot::Room { .. }) {
+            for (to, spot) in burrow.spots.iter().enumerate() {
+                let Spot::Hall(col) = *spot else {
+                    continue;
+                };
+
+                if state[to] == FREE_SPOT && is_hall_free(burrow, state, from_col, col) {
+                    let steps = steps_to_hall(burrow, from) + from_col.abs_diff(col);
+                    moves.push((from, to, per_step * steps as u64));
                 }
             }
-        };
+        }
     }
 
-    min_cost
+    moves
 }
 
-fn room_to_hall_idx(room_idx: usize) -> usize {
-    (room_idx + 1) * 2
-}
+// A lower bound of the remaining energy: every amphipod that is not settled has
+// to walk to the door of its room and then into the room, where the ones that
+// enter later stop closer to the door
+fn heuristic(burrow: &Burrow, state: &[u8]) -> u64 {
+    let mut entering = vec![0; burrow.rooms.len()];
+    let mut energy = 0;
 
-fn should_move_to_hall<const N: usize>(room: [u8; N], idx: usize, expected: u8) -> bool {
-    for pos in (0..=idx).rev() {
-        if room[pos] != expected {
-            return true;
+    for (spot, &amphipod) in state.iter().enumerate() {
+        if amphipod == FREE_SPOT || is_settled(burrow, state, spot) {
+            continue;
         }
-    }
 
-    false
-}
+        let target = amphipod as usize;
+        let door = burrow.doors[target];
+        let col = column(burrow, spot);
 
-fn find_room_idx<const N: usize>(rooms: Rooms<N>, room_idx: usize) -> Option<usize> {
-    let mut position = rooms[room_idx].len();
-    for idx in (0..rooms[room_idx].len()).rev() {
-        if rooms[room_idx][idx] != FREE_SPOT {
-            break;
-        }
-        position = idx;
-    }
+        let steps = match burrow.spots[spot] {
+            Spot::Hall(_) => col.abs_diff(door),
+            // it has to step aside to let the others out
+            Spot::Room { room, depth } if room == target => depth + 1 + 2,
+            Spot::Room { depth, .. } => depth + 1 + col.abs_diff(door),
+        };
 
-    for idx in (0..position).rev() {
-        if rooms[room_idx][idx] != room_idx as u8 {
-            return None;
-        }
+        energy += energy_per_step(amphipod) * (steps as u64 + 1);
+        entering[target] += 1;
     }
 
-    if position < rooms[room_idx].len() {
-        return Some(position);
+    for (room, count) in entering.into_iter().enumerate() {
+        // the free spots that will be filled, from the back
+        let spots = &burrow.rooms[room];
+        let settled = spots
+            .iter()
+            .rev()
+            .take_while(|&&s| state[s] as usize == room)
+            .count();
+
+        let deepest = spots.len() - settled;
+        let extra: usize = (0..count).map(|idx| deepest.saturating_sub(idx + 1)).sum();
+        energy += energy_per_step(room as u8) * extra as u64;
     }
 
-    None
+    energy
 }
@@ -1,8 +1,15 @@
+use std::error::Error;
+
 use aoc_day_25::part_one;
-use aoc_shared::input::load_text_input_from_autodetect;
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
 use aoc_shared::parsing::parse_u8_grid;
 
-fn main() {
-    let input = parse_u8_grid(load_text_input_from_autodetect());
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = parse_u8_grid(try_load_text_input_from_autodetect(input_locator!(
+        2021, 25
+    ))?);
     println!("Part 1: {}", part_one(input));
+
+    Ok(())
 }
@@ -1,4 +1,4 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use aoc_day_01::{parse_input, part_one, part_two};
 
@@ -8,7 +8,7 @@ criterion_main!(benches);
 
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2022, 1).load().unwrap();
 
     c.bench_function("parsing", |b| {
         b.iter(|| black_box(parse_input(black_box(&input))));
@@ -17,7 +17,7 @@ fn benchmark_parsing(c: &mut Criterion) {
 
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2022, 1).load().unwrap();
     let parsed = parse_input(&input).unwrap();
 
     c.bench_function("part-1", |b| {
@@ -27,7 +27,7 @@ fn benchmark_part_one(c: &mut Criterion) {
 
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2022, 1).load().unwrap();
     let parsed = parse_input(&input).unwrap();
 
     c.bench_function("part-2", |b| {
@@ -1,25 +1,11 @@
 use std::error::Error;
 
-pub fn parse_input(input: impl AsRef<str>) -> Result<Vec<Vec<u64>>, Box<dyn Error>> {
-    let input = input.as_ref().trim_end();
-    let mut parsed = vec![];
-    let mut last = 0;
-
-    for line in input.lines() {
-        let line = line.trim_end();
-        if line.is_empty() || parsed.is_empty() {
-            last = parsed.len();
-            parsed.push(vec![]);
-
-            // Check again for empty line, because
-            //we do not want to skip the very first row
-            if line.is_empty() {
-                continue;
-            }
-        }
+use aoc_shared::blocks::blocks;
 
-        parsed[last].push(line.parse()?);
-    }
+pub fn parse_input(input: impl AsRef<str>) -> Result<Vec<Vec<u64>>, Box<dyn Error>> {
+    let parsed = blocks(input.as_ref())
+        .map(|elf| elf.try_parse_lines())
+        .collect::<Result<_, _>>()?;
 
     Ok(parsed)
 }
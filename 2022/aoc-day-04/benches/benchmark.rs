@@ -1,5 +1,5 @@
 use aoc_day_04::{part_one, part_two, Assignment};
-use aoc_shared::input::{load_line_delimited_input_from_file, load_text_input_from_file};
+use aoc_shared::input_locator;
 use aoc_shared::parsing::parse_line_delimited;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
@@ -12,7 +12,7 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2022, 4).load().unwrap();
 
     c.bench_function("parsing", |b| {
         b.iter(|| parse_line_delimited::<_, Assignment>(black_box(&input)));
@@ -20,7 +20,7 @@ fn benchmark_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_line_delimited_input_from_file("inputs/input.txt");
+    let input = parse_line_delimited(input_locator!(2022, 4).load().unwrap());
 
     c.bench_function("part-1", |b| {
         b.iter(|| part_one(black_box(&input)));
@@ -28,7 +28,7 @@ fn benchmark_part_one(c: &mut Criterion) {
 }
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_line_delimited_input_from_file("inputs/input.txt");
+    let input = parse_line_delimited(input_locator!(2022, 4).load().unwrap());
 
     c.bench_function("part-2", |b| {
         b.iter(|| part_two(black_box(&input)));
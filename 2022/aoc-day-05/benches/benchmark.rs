@@ -1,4 +1,4 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{black_box, Criterion, criterion_group, criterion_main};
 
 use aoc_day_05::{parse_input, part_one_v1, part_one_v2, part_two};
@@ -8,7 +8,7 @@ criterion_main!(benches);
 
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2022, 5).load().unwrap();
 
     c.bench_function("parsing", |b| {
         b.iter(|| parse_input(black_box(&input)));
@@ -17,7 +17,8 @@ fn benchmark_parsing(c: &mut Criterion) {
 
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let (stacks, instructions) = parse_input(load_text_input_from_file("inputs/input.txt"));
+    let (stacks, instructions) =
+        parse_input(input_locator!(2022, 5).load().unwrap()).unwrap();
 
     c.bench_function("part-1 (v1)", |b| {
         b.iter(|| part_one_v1(black_box(&stacks), black_box(&instructions)));
@@ -30,7 +31,8 @@ fn benchmark_part_one(c: &mut Criterion) {
 
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let (stacks, instructions) = parse_input(load_text_input_from_file("inputs/input.txt"));
+    let (stacks, instructions) =
+        parse_input(input_locator!(2022, 5).load().unwrap()).unwrap();
 
     c.bench_function("part-2", |b| {
         b.iter(|| part_two(black_box(&stacks), black_box(&instructions)));
@@ -1,71 +1,63 @@
+use std::error::Error;
+
+use aoc_shared::blocks::try_split_blocks;
+
 const IDX_VALUE: usize = 1;
 const VALUE_EMPTY: u8 = b' ';
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
-enum ParserState {
-    Stacks,
-    StacksEnd,
-    Instructions,
-}
+/// The stacks of crates and the (count, source, destination) instructions
+pub type Input = (Vec<Vec<char>>, Vec<(usize, usize, usize)>);
 
-pub fn parse_input(input: impl AsRef<str>) -> (Vec<Vec<char>>, Vec<(usize, usize, usize)>) {
-    let input = input.as_ref();
+pub fn parse_input(input: impl AsRef<str>) -> Result<Input, Box<dyn Error>> {
+    let [drawing, moves] = try_split_blocks(input.as_ref())?;
 
     let mut stacks = vec![vec![]];
-    let mut instructions = vec![];
-    let mut state = ParserState::Stacks;
-
-    for line in input.lines() {
-        match state {
-            ParserState::Stacks => {
-                if line.starts_with(" 1") {
-                    state = ParserState::StacksEnd;
-                    continue;
-                }
-
-                for (idx, val) in line.as_bytes().chunks(4).enumerate() {
-                    if stacks.len() <= idx {
-                        stacks.push(vec![]);
-                    }
-
-                    if val[IDX_VALUE] != VALUE_EMPTY {
-                        stacks[idx].push(val[IDX_VALUE] as char);
-                    }
-                }
-            }
-
-            ParserState::StacksEnd => {
-                // After parsing, the stacks are in reversed order, thus we have to reverse them
-                for stack in stacks.iter_mut() {
-                    stack.reverse();
-                }
+    for line in drawing.lines() {
+        // The last line of the drawing contains the numbers of the stacks
+        if line.starts_with(" 1") {
+            break;
+        }
 
-                state = ParserState::Instructions;
+        for (idx, val) in line.as_bytes().chunks(4).enumerate() {
+            if stacks.len() <= idx {
+                stacks.push(vec![]);
             }
 
-            ParserState::Instructions => {
-                let mut count = 0;
-                let mut src = 0;
-                let mut dst = 0;
-
-                for (idx, part) in line.split(' ').enumerate() {
-                    match idx {
-                        0 | 2 | 4 => continue,
-                        1 => count = part.parse().unwrap(),
-                        3 => src = part.parse().unwrap(),
-                        5 => dst = part.parse().unwrap(),
-                        _ => panic!("unexpected input: {:?}", line)
-                    }
-                }
-
-                // -1 because we move from 1 based indexing to 0-based one
-                instructions.push((count, src - 1, dst - 1));
+            if val[IDX_VALUE] != VALUE_EMPTY {
+                stacks[idx].push(val[IDX_VALUE] as char);
             }
         }
     }
 
+    // After parsing, the stacks are in reversed order, thus we have to reverse them
+    for stack in stacks.iter_mut() {
+        stack.reverse();
+    }
+
+    let mut instructions = vec![];
+    for (line_number, line) in moves.numbered_lines() {
+        let parts: Vec<&str> = line.split(' ').collect();
+        let ["move", count, "from", src, "to", dst] = parts[..] else {
+            return Err(format!("line {}: unexpected input: {:?}", line_number, line).into());
+        };
+
+        // The stacks are numbered from 1, but indexed from 0
+        let to_index = |number: &str| -> Result<usize, Box<dyn Error>> {
+            match number.parse::<usize>()?.checked_sub(1) {
+                Some(idx) if idx < stacks.len() => Ok(idx),
+                _ => Err(format!("line {}: there is no stack {}", line_number, number).into()),
+            }
+        };
+
+        let (src, dst) = (to_index(src)?, to_index(dst)?);
+        if src == dst {
+            return Err(format!("line {}: moving crates onto the same stack", line_number).into());
+        }
+
+        instructions.push((count.parse()?, src, dst));
+    }
 
-    (stacks, instructions)
+    Ok((stacks, instructions))
 }
 
 pub fn part_one_v1(stacks: &[Vec<char>], instructions: &[(usize, usize, usize)]) -> String {
@@ -145,22 +137,36 @@ mod tests {
 
     #[test]
     fn test_part_one_v1() {
-        let (stacks, instructions) = parse_input(load_text_input_from_file("inputs/input.txt"));
+        let (stacks, instructions) =
+            parse_input(load_text_input_from_file("inputs/input.txt")).unwrap();
         let answer = part_one_v1(&stacks, &instructions);
         assert_eq!("JCMHLVGMG", answer);
     }
 
     #[test]
     fn test_part_one_v2() {
-        let (stacks, instructions) = parse_input(load_text_input_from_file("inputs/input.txt"));
+        let (stacks, instructions) =
+            parse_input(load_text_input_from_file("inputs/input.txt")).unwrap();
         let answer = part_one_v2(&stacks, &instructions);
         assert_eq!("JCMHLVGMG", answer);
     }
 
     #[test]
     fn test_part_two() {
-        let (stacks, instructions) = parse_input(load_text_input_from_file("inputs/input.txt"));
+        let (stacks, instructions) =
+            parse_input(load_text_input_from_file("inputs/input.txt")).unwrap();
         let answer = part_two(&stacks, &instructions);
         assert_eq!("LVMRWSSPZ", answer);
     }
+
+    #[test]
+    fn test_invalid_stacks() {
+        let drawing = "[A] [B]\n 1   2 \n\n";
+        assert!(parse_input(format!("{}move 1 from 1 to 2\n", drawing)).is_ok());
+
+        for moves in ["move 1 from 0 to 2", "move 1 from 1 to 3", "move 1 from 2 to 2"] {
+            let err = parse_input(format!("{}{}\n", drawing, moves)).unwrap_err();
+            assert!(err.to_string().starts_with("line 4: "), "{}", err);
+        }
+    }
 }
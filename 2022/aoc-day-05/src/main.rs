@@ -1,9 +1,16 @@
-use aoc_shared::input::load_text_input_from_autodetect;
+use std::error::Error;
+
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
 
 use aoc_day_05::{parse_input, part_one_v2, part_two};
 
-fn main() {
-    let (stacks, instructions) = parse_input(load_text_input_from_autodetect());
+fn main() -> Result<(), Box<dyn Error>> {
+    let (stacks, instructions) = parse_input(try_load_text_input_from_autodetect(
+        input_locator!(2022, 5),
+    )?)?;
     println!("Part 1: {}", part_one_v2(&stacks, &instructions));
     println!("Part 2: {}", part_two(&stacks, &instructions));
+
+    Ok(())
 }
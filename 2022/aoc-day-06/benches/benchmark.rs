@@ -1,12 +1,12 @@
 use aoc_day_06::{part_one, part_two};
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 criterion_group!(benches, benchmark_part_one, benchmark_part_two);
 criterion_main!(benches);
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2022, 6).load().unwrap();
 
     c.bench_function("part-1", |b| {
         b.iter(|| part_one(black_box(&input)));
@@ -14,7 +14,7 @@ fn benchmark_part_one(c: &mut Criterion) {
 }
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2022, 6).load().unwrap();
 
     c.bench_function("part-2", |b| {
         b.iter(|| part_two(black_box(&input)));
@@ -1,9 +1,14 @@
-use aoc_shared::input::load_text_input_from_autodetect;
+use std::error::Error;
+
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
 
 use aoc_day_06::{part_one, part_two};
 
-fn main() {
-    let input = load_text_input_from_autodetect();
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = try_load_text_input_from_autodetect(input_locator!(2022, 6))?;
     println!("Part 1: {}", part_one(&input));
     println!("Part 2: {}", part_two(&input));
+
+    Ok(())
 }
@@ -1,5 +1,5 @@
 use aoc_day_07::{parse_input, v1, v2};
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 criterion_group!(
@@ -11,7 +11,7 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2022, 7).load().unwrap();
 
     c.bench_function("parsing", |b| {
         b.iter(|| parse_input(black_box(&input)));
@@ -19,7 +19,7 @@ fn benchmark_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2022, 7).load().unwrap();
     let entries = parse_input(&input);
 
     c.bench_function("part-1 (flat)", |b| {
@@ -32,7 +32,7 @@ fn benchmark_part_one(c: &mut Criterion) {
 }
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2022, 7).load().unwrap();
     let entries = parse_input(&input);
 
     c.bench_function("part-2 (flat)", |b| {
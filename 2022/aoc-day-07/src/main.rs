@@ -1,11 +1,16 @@
-use aoc_shared::input::load_text_input_from_autodetect;
+use std::error::Error;
+
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
 
 use aoc_day_07::{parse_input, v1};
 
-fn main() {
-    let input = load_text_input_from_autodetect();
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = try_load_text_input_from_autodetect(input_locator!(2022, 7))?;
     let entries = parse_input(&input);
 
     println!("Part 1: {}", v1::part_one(&entries));
     println!("Part 2: {}", v1::part_two(&entries));
+
+    Ok(())
 }
@@ -1,12 +1,17 @@
-use aoc_shared::input::load_text_input_from_autodetect;
-use aoc_shared::parsing::parse_u8_numeric_grid;
+use std::error::Error;
+
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
+use aoc_shared::parsing::try_parse_numeric_grid;
 
 use aoc_day_08::{part_one, part_two};
 
-fn main() {
-    let input = load_text_input_from_autodetect();
-    let grid = parse_u8_numeric_grid(&input);
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = try_load_text_input_from_autodetect(input_locator!(2022, 8))?;
+    let grid = try_parse_numeric_grid(&input)?;
 
     println!("Part 1: {}", part_one(&grid));
     println!("Part 2: {}", part_two(&grid));
+
+    Ok(())
 }
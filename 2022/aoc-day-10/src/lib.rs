@@ -1,3 +1,5 @@
+use aoc_shared::ocr::{recognize, OcrError};
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum Instruction {
     Add(i32),
@@ -62,7 +64,13 @@ pub fn part_one(instructions: &[Instruction]) -> i32 {
     signal_strength
 }
 
-pub fn part_two(instructions: &[Instruction]) -> [[char; 40]; 6] {
+pub fn part_two(instructions: &[Instruction]) -> Result<String, OcrError> {
+    let crt = render_crt(instructions);
+    recognize(&crt.map(|row| row.map(|x| x == '█')))
+}
+
+/// Draws the pixels lit by the sprite on the CRT
+pub fn render_crt(instructions: &[Instruction]) -> [[char; 40]; 6] {
     let mut crt = [[' '; 40]; 6];
     let mut register = 1;
     let mut cycles = 0;
@@ -92,7 +100,7 @@ pub fn part_two(instructions: &[Instruction]) -> [[char; 40]; 6] {
 mod tests {
     use aoc_shared::input::load_text_input_from_file;
 
-    use crate::{parse_input, part_one, part_two};
+    use crate::{parse_input, part_one, part_two, render_crt};
 
     #[test]
     fn test_part_one() {
@@ -102,11 +110,18 @@ mod tests {
     }
 
     #[test]
-    #[rustfmt::skip]
     fn test_part_two() {
         let input = load_text_input_from_file("inputs/input.txt");
         let instructions = parse_input(&input);
-        let answer = part_two(&instructions);
+        assert_eq!(Ok("ECZUZALR".to_owned()), part_two(&instructions))
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_render_crt() {
+        let input = load_text_input_from_file("inputs/input.txt");
+        let instructions = parse_input(&input);
+        let answer = render_crt(&instructions);
         
         assert_eq!("████  ██  ████ █  █ ████  ██  █    ███  ", answer[0].iter().collect::<String>());
         assert_eq!("█    █  █    █ █  █    █ █  █ █    █  █ ", answer[1].iter().collect::<String>());
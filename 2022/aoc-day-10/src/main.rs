@@ -1,14 +1,16 @@
-use aoc_shared::input::load_text_input_from_autodetect;
+use std::error::Error;
+
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
 
 use aoc_day_10::{parse_input, part_one, part_two};
 
-fn main() {
-    let input = load_text_input_from_autodetect();
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = try_load_text_input_from_autodetect(input_locator!(2022, 10))?;
     let instructions = parse_input(&input);
 
     println!("Part 1: {}", part_one(&instructions));
-    let answer = part_two(&instructions);
-    for row in answer {
-        println!("{}", row.iter().collect::<String>());
-    }
+    println!("Part 2: {}", part_two(&instructions)?);
+
+    Ok(())
 }
@@ -1,10 +1,15 @@
+use std::error::Error;
+
 use aoc_day_11::{parse_input, part_one, part_two};
-use aoc_shared::input::load_text_input_from_autodetect;
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
 
-fn main() {
-    let input = load_text_input_from_autodetect();
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = try_load_text_input_from_autodetect(input_locator!(2022, 11))?;
     let monkeys = parse_input(&input);
 
     println!("Part 1: {}", part_one(&monkeys));
     println!("Part 2: {}", part_two(&monkeys));
+
+    Ok(())
 }
@@ -1,4 +1,4 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use aoc_shared::parsing::parse_u8_grid;
 use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
 
@@ -8,7 +8,7 @@ criterion_group!(benches, benchmark_part_one, benchmark_part_two);
 criterion_main!(benches);
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2022, 12).load().unwrap();
     let grid = parse_u8_grid(input);
 
     c.bench_function("part-1 (a*, s->e)", |b| {
@@ -30,7 +30,7 @@ fn benchmark_part_one(c: &mut Criterion) {
 
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2022, 12).load().unwrap();
     let grid = parse_u8_grid(input);
 
     c.bench_function("part-2 (a*, s->e)", |b| {
@@ -1,4 +1,4 @@
-use aoc_shared::input::{load_line_delimited_input_from_file, load_text_input_from_file};
+use aoc_shared::input_locator;
 use aoc_shared::parsing::parse_line_delimited;
 use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
 
@@ -13,7 +13,7 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2022, 13).load().unwrap();
 
     c.bench_function("parsing", |b| {
         b.iter(|| parse_line_delimited::<_, Packet>(black_box(&input)));
@@ -21,7 +21,7 @@ fn benchmark_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let parsed = load_line_delimited_input_from_file("inputs/input.txt");
+    let parsed = parse_line_delimited(input_locator!(2022, 13).load().unwrap());
 
     c.bench_function("part-1", |b| {
         b.iter(|| part_one(black_box(&parsed)));
@@ -29,7 +29,7 @@ fn benchmark_part_one(c: &mut Criterion) {
 }
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let parsed = load_line_delimited_input_from_file("inputs/input.txt");
+    let parsed = parse_line_delimited(input_locator!(2022, 13).load().unwrap());
 
     c.bench_function("part-2", |b| {
         b.iter_batched(
@@ -1,10 +1,15 @@
-use aoc_shared::input::load_line_delimited_input_from_autodetect;
+use std::error::Error;
+
+use aoc_shared::input::try_load_line_delimited_input_from_autodetect;
+use aoc_shared::input_locator;
 
 use aoc_day_13::{part_one, part_two};
 
-fn main() {
-    let packets = load_line_delimited_input_from_autodetect();
+fn main() -> Result<(), Box<dyn Error>> {
+    let packets = try_load_line_delimited_input_from_autodetect(input_locator!(2022, 13))?;
 
     println!("Part 1: {}", part_one(&packets));
     println!("Part 2: {}", part_two(packets));
+
+    Ok(())
 }
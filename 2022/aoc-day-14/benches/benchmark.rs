@@ -1,4 +1,4 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{BatchSize, black_box, Criterion, criterion_group, criterion_main};
 
 use aoc_day_14::{parse_input, part_one, part_two_v1, part_two_v2};
@@ -8,7 +8,7 @@ criterion_main!(benches);
 
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2022, 14).load().unwrap();
 
     c.bench_function("parsing", |b| {
         b.iter(|| parse_input(black_box(&input)));
@@ -17,7 +17,7 @@ fn benchmark_parsing(c: &mut Criterion) {
 
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2022, 14).load().unwrap();
     let (grid, last_row, initial_column) = parse_input(input);
 
     c.bench_function("part-1", |b| {
@@ -31,7 +31,7 @@ fn benchmark_part_one(c: &mut Criterion) {
 
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2022, 14).load().unwrap();
     let (grid, _, initial_column) = parse_input(input);
 
     c.bench_function("part-2 (simulation)", |b| {
@@ -1,12 +1,20 @@
-use aoc_shared::input::load_text_input_from_autodetect;
+use std::error::Error;
+
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
 
-use aoc_day_14::{part_one, part_two_v1};
 use aoc_day_14::parse_input;
+use aoc_day_14::{part_one, part_two_v1};
 
-fn main() {
-    let input = load_text_input_from_autodetect();
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = try_load_text_input_from_autodetect(input_locator!(2022, 14))?;
     let (grid, last_row, initial_column) = parse_input(input);
 
-    println!("Part 1: {}", part_one(grid.clone(), last_row, initial_column));
+    println!(
+        "Part 1: {}",
+        part_one(grid.clone(), last_row, initial_column)
+    );
     println!("Part 2: {}", part_two_v1(grid, initial_column));
+
+    Ok(())
 }
@@ -1,8 +1,13 @@
+use std::error::Error;
+
 use aoc_day_15::{part_one, part_two_v1};
-use aoc_shared::input::load_line_delimited_input_from_autodetect;
+use aoc_shared::input::try_load_line_delimited_input_from_autodetect;
+use aoc_shared::input_locator;
 
-fn main() {
-    let input = load_line_delimited_input_from_autodetect();
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = try_load_line_delimited_input_from_autodetect(input_locator!(2022, 15))?;
     println!("Part 1: {}", part_one(&input));
     println!("Part 2: {}", part_two_v1(&input));
+
+    Ok(())
 }
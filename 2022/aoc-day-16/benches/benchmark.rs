@@ -1,5 +1,5 @@
 use aoc_day_16::{p1v1, p1v2, p2v2, parse_input};
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 criterion_group!(
@@ -11,7 +11,7 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2022, 16).load().unwrap();
 
     c.bench_function("parsing", |b| {
         b.iter(|| parse_input(black_box(&input)));
@@ -19,7 +19,7 @@ fn benchmark_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2022, 16).load().unwrap();
     let graph = parse_input(input);
 
     c.bench_function("part-1 (greedy)", |b| {
@@ -37,7 +37,7 @@ fn benchmark_part_one(c: &mut Criterion) {
 
 // The second part of the problem takes around 25seconds and is too slow to benchmark!
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2022, 16).load().unwrap();
     let graph = parse_input(input);
 
     // The second part of the problem (p2v1) takes around
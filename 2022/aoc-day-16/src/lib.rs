@@ -6,9 +6,14 @@ pub mod p2v1;
 pub mod p1v2;
 pub mod p2v2;
 
+pub mod schedule;
+
 pub fn parse_input(input: impl AsRef<str>) -> Vec<(u16, Vec<usize>)> {
-    let input = input.as_ref();
+    parse_input_with_names(input.as_ref()).0
+}
 
+/// Like `parse_input`, but also returns the name of each valve
+pub fn parse_input_with_names(input: &str) -> (Vec<(u16, Vec<usize>)>, Vec<String>) {
     let mut graph = vec![(0, vec![]); 1];
     let mut valves = HashMap::default();
     valves.insert("AA", 0);
@@ -38,7 +43,12 @@ pub fn parse_input(input: impl AsRef<str>) -> Vec<(u16, Vec<usize>)> {
         }
     }
 
-    graph
+    let mut names = vec![String::new(); graph.len()];
+    for (name, idx) in valves {
+        names[idx] = name.to_owned();
+    }
+
+    (graph, names)
 }
 
 // Note: This seems similar to the "Okabe and city" problem. Maybe we can
@@ -1,11 +1,16 @@
-use aoc_shared::input::load_text_input_from_autodetect;
+use std::error::Error;
+
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
 
 use aoc_day_16::{p1v2, p2v2, parse_input};
 
-fn main() {
-    let input = load_text_input_from_autodetect();
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = try_load_text_input_from_autodetect(input_locator!(2022, 16))?;
     let graph = parse_input(input);
 
     println!("Part 1: {}", p1v2::part_one(&graph));
     println!("Part 2: {}", p2v2::part_two(&graph));
+
+    Ok(())
 }
@@ -0,0 +1,306 @@
+use std::collections::VecDeque;
+use std::fmt::Write;
+
+use aoc_shared::hashing::HashMap;
+
+/// A valve opened by one of the actors, after walking `route` to it
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Opening {
+    /// The minute during which the valve is opened. It releases pressure from
+    /// the next minute onwards.
+    pub minute: u32,
+    pub valve: usize,
+    pub rate: u16,
+    /// The valves walked through, ending with the opened one
+    pub route: Vec<usize>,
+    pub by_elephant: bool,
+}
+
+/// An optimal valve opening schedule, found with an exact search instead of the
+/// beam search used by `p1v2` and `p2v2`
+#[derive(Debug, Clone)]
+pub struct Schedule {
+    pub minutes: u32,
+    pub released: u16,
+    pub openings: Vec<Opening>,
+}
+
+pub fn part_one(graph: &[(u16, Vec<usize>)]) -> Schedule {
+    let routes = Routes::new(graph);
+    let best = routes.best_per_valve_set(30);
+
+    let (_, (released, openings)) = best
+        .into_iter()
+        .max_by_key(|(_, (released, _))| *released)
+        .unwrap();
+
+    Schedule {
+        minutes: 30,
+        released,
+        openings,
+    }
+}
+
+pub fn part_two(graph: &[(u16, Vec<usize>)]) -> Schedule {
+    let routes = Routes::new(graph);
+    let best = routes.best_per_valve_set(26);
+
+    let mut sets = best.into_iter().collect::<Vec<_>>();
+    sets.sort_unstable_by_key(|(_, (released, _))| std::cmp::Reverse(*released));
+
+    // The best pair of disjoint valve sets, one for me and one for the elephant.
+    // The sets are sorted, so we can stop once no pair can be better.
+    let mut answer = (0, 0, 0);
+    for (me, (my_set, (my_released, _))) in sets.iter().enumerate() {
+        if my_released * 2 < answer.0 {
+            break;
+        }
+
+        for (el, (el_set, (el_released, _))) in sets.iter().enumerate().skip(me) {
+            if my_released + el_released <= answer.0 {
+                break;
+            }
+
+            if my_set & el_set == 0 {
+                answer = (my_released + el_released, me, el);
+            }
+        }
+    }
+
+    let (released, me, el) = answer;
+    let mut openings = sets[me].1 .1.clone();
+    openings.extend(sets[el].1 .1.iter().cloned().map(|mut opening| {
+        opening.by_elephant = true;
+        opening
+    }));
+    openings.sort_by_key(|opening| (opening.minute, opening.by_elephant));
+
+    Schedule {
+        minutes: 26,
+        released,
+        openings,
+    }
+}
+
+impl Schedule {
+    /// Describes every minute like the puzzle example, using the valve names
+    /// returned by `parse_input_with_names`
+    pub fn replay(&self, names: &[String]) -> String {
+        // What each actor does during each minute
+        let mut actions = vec![vec![]; self.minutes as usize];
+        for opening in self.openings.iter() {
+            let (actor, moves, opens) = match opening.by_elephant {
+                true => ("The elephant", "moves", "opens"),
+                false => ("You", "move", "open"),
+            };
+
+            let start = opening.minute as usize - opening.route.len();
+            for (offset, valve) in opening.route.iter().enumerate() {
+                actions[start + offset - 1]
+                    .push(format!("{} {} to valve {}.", actor, moves, names[*valve]));
+            }
+            actions[opening.minute as usize - 1].push(format!(
+                "{} {} valve {}.",
+                actor, opens, names[opening.valve]
+            ));
+        }
+
+        let mut replay = String::new();
+        let mut released = 0;
+        for minute in 1..=self.minutes {
+            let open = self
+                .openings
+                .iter()
+                .filter(|opening| opening.minute < minute)
+                .collect::<Vec<_>>();
+            let rate = open.iter().map(|opening| opening.rate).sum::<u16>();
+            released += rate;
+
+            writeln!(replay, "== Minute {} ==", minute).unwrap();
+            if open.is_empty() {
+                writeln!(replay, "No valves are open.").unwrap();
+            } else {
+                let open = open
+                    .iter()
+                    .map(|opening| names[opening.valve].as_str())
+                    .collect::<Vec<_>>();
+                // "Valve AA is", "Valves AA and BB are", "Valves AA, BB, and CC are"
+                let valves = match open[..] {
+                    [valve] => format!("Valve {} is", valve),
+                    [first, second] => format!("Valves {} and {} are", first, second),
+                    [ref rest @ .., last] => {
+                        format!("Valves {}, and {} are", rest.join(", "), last)
+                    }
+                    [] => unreachable!(),
+                };
+                writeln!(replay, "{} open, releasing {} pressure.", valves, rate).unwrap();
+            }
+
+            for action in actions[minute as usize - 1].iter() {
+                writeln!(replay, "{}", action).unwrap();
+            }
+            writeln!(replay).unwrap();
+        }
+
+        debug_assert_eq!(self.released, released);
+        writeln!(replay, "Released {} pressure in total.", released).unwrap();
+        replay
+    }
+}
+
+// The shortest routes between the start and the valves with a positive flow rate
+struct Routes<'a> {
+    graph: &'a [(u16, Vec<usize>)],
+    valves: Vec<usize>,
+    // `parents[source][valve]` is the previous valve on the shortest route from source
+    parents: Vec<Vec<usize>>,
+    distances: Vec<Vec<u32>>,
+}
+
+impl<'a> Routes<'a> {
+    fn new(graph: &'a [(u16, Vec<usize>)]) -> Self {
+        let valves = (0..graph.len())
+            .filter(|&valve| graph[valve].0 > 0)
+            .collect::<Vec<_>>();
+        assert!(valves.len() <= 64);
+
+        let mut parents = vec![];
+        let mut distances = vec![];
+        for source in 0..graph.len() {
+            let mut parent = vec![usize::MAX; graph.len()];
+            let mut distance = vec![u32::MAX; graph.len()];
+            distance[source] = 0;
+
+            let mut queue = VecDeque::new();
+            queue.push_back(source);
+            while let Some(valve) = queue.pop_front() {
+                for next in graph[valve].1.iter().copied() {
+                    if distance[next] == u32::MAX {
+                        distance[next] = distance[valve] + 1;
+                        parent[next] = valve;
+                        queue.push_back(next);
+                    }
+                }
+            }
+
+            parents.push(parent);
+            distances.push(distance);
+        }
+
+        Routes {
+            graph,
+            valves,
+            parents,
+            distances,
+        }
+    }
+
+    fn route(&self, from: usize, to: usize) -> Vec<usize> {
+        let mut route = vec![];
+        let mut valve = to;
+        while valve != from {
+            route.push(valve);
+            valve = self.parents[from][valve];
+        }
+
+        route.reverse();
+        route
+    }
+
+    // The most pressure that can be released by opening exactly each set of valves
+    // (as a bit mask over `valves`) and the openings that achieve it
+    fn best_per_valve_set(&self, minutes: u32) -> HashMap<u64, (u16, Vec<Opening>)> {
+        let mut best = HashMap::default();
+        let mut openings = vec![];
+        self.dfs(minutes, 0, minutes, 0, 0, &mut openings, &mut best);
+        best
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn dfs(
+        &self,
+        minutes: u32,
+        position: usize,
+        time_left: u32,
+        opened: u64,
+        released: u16,
+        openings: &mut Vec<Opening>,
+        best: &mut HashMap<u64, (u16, Vec<Opening>)>,
+    ) {
+        let entry = best.entry(opened).or_insert((0, vec![]));
+        if released > entry.0 {
+            *entry = (released, openings.clone());
+        }
+
+        for (idx, valve) in self.valves.iter().copied().enumerate() {
+            if opened & (1 << idx) != 0 {
+                continue;
+            }
+
+            // walk to the valve and spend a minute opening it
+            let cost = self.distances[position][valve].saturating_add(1);
+            if cost >= time_left {
+                continue;
+            }
+
+            let remaining = time_left - cost;
+            openings.push(Opening {
+                minute: minutes - remaining,
+                valve,
+                rate: self.graph[valve].0,
+                route: self.route(position, valve),
+                by_elephant: false,
+            });
+
+            let released = released + self.graph[valve].0 * remaining as u16;
+            self.dfs(
+                minutes,
+                valve,
+                remaining,
+                opened | (1 << idx),
+                released,
+                openings,
+                best,
+            );
+
+            openings.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_shared::input::load_text_input_from_file;
+
+    use crate::parse_input_with_names;
+    use crate::schedule::{part_one, part_two};
+
+    #[test]
+    fn test_part_one() {
+        let input = load_text_input_from_file("inputs/input.txt");
+        let (graph, names) = parse_input_with_names(&input);
+
+        let schedule = part_one(&graph);
+        assert_eq!(1584, schedule.released);
+
+        let replay = schedule.replay(&names);
+        assert!(replay.starts_with("== Minute 1 ==\nNo valves are open.\nYou move to valve "));
+        assert!(replay.contains("\nValve "));
+        assert!(replay.contains(" is open, releasing "));
+        assert!(replay.ends_with("Released 1584 pressure in total.\n"));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = load_text_input_from_file("inputs/input.txt");
+        let (graph, names) = parse_input_with_names(&input);
+
+        let schedule = part_two(&graph);
+        assert_eq!(2052, schedule.released);
+        assert!(schedule.openings.iter().any(|opening| opening.by_elephant));
+
+        let replay = schedule.replay(&names);
+        assert!(replay.contains("The elephant opens valve "));
+        assert!(replay.ends_with("Released 2052 pressure in total.\n"));
+    }
+}
@@ -1,4 +1,4 @@
-use aoc_shared::hashing::HashMap;
+use aoc_shared::algo::cycle::find_by_key;
 
 const LEFT: u8 = b'<';
 const RIGHT: u8 = b'>';
@@ -147,25 +147,61 @@ pub fn part_one(input: &[u8]) -> usize {
 }
 
 pub fn part_two(wind: &[u8]) -> u64 {
-    const TARGET_ROCKS: u64 = 1_000_000_000_000;
-    const CACHE_ROWS: usize = 4;
+    const TARGET_ROCKS: usize = 1_000_000_000_000;
+    const CACHE_ROWS: usize = 32;
+
+    // The tower height after each fallen rock
+    let mut heights = vec![0];
+
+    // The state repeats when the next rock, the next wind and the top of the
+    // tower are the same as in some previous state. Only the top rows are
+    // compared, which is a heuristic: a rock could slip through a gap and fall
+    // deeper than them, so the states could still differ. It's enough for the
+    // example and the inputs, where no rock falls that deep.
+    let mut tower = Tower::default();
+    let cycle = find_by_key(
+        &mut tower,
+        |t| {
+            t.drop_rock(wind);
+            heights.push(t.stack.len());
+        },
+        |t| {
+            let top = &t.stack[t.stack.len().saturating_sub(CACHE_ROWS)..];
+            (t.next_rock, t.next_wind, top.to_vec())
+        },
+    );
+
+    let current = cycle.mu + cycle.lambda;
+    if TARGET_ROCKS < current {
+        return heights[TARGET_ROCKS] as u64;
+    }
+
+    let cycle_height = (heights[current] - heights[cycle.mu]) as u64;
+    let cycles = cycle.repetitions(current, TARGET_ROCKS) as u64;
+    let remaining = cycle.remaining(current, TARGET_ROCKS);
+    let remaining_height = (heights[cycle.mu + remaining] - heights[cycle.mu]) as u64;
 
-    let mut next_rock = 0;
-    let mut next_wind = 0;
-    let mut cache = HashMap::default();
+    heights[current] as u64 + cycles * cycle_height + remaining_height
+}
+
+#[derive(Default)]
+struct Tower {
+    stack: Vec<u8>,
+    next_rock: usize,
+    next_wind: usize,
+}
 
-    let mut stack: Vec<u8> = vec![];
-    let mut fallen = 0u64;
-    let mut height = 0u64;
+impl Tower {
+    fn drop_rock(&mut self, wind: &[u8]) {
+        let stack = &mut self.stack;
 
-    'next: while fallen < TARGET_ROCKS {
-        let shape = SHAPES[next_rock];
-        next_rock = (next_rock + 1) % SHAPES.len();
+        let shape = SHAPES[self.next_rock];
+        self.next_rock = (self.next_rock + 1) % SHAPES.len();
 
         let mut shape = shape.to_be_bytes();
 
         for _ in 0..4 {
-            match wind[next_wind] {
+            match wind[self.next_wind] {
                 LEFT => {
                     if (shape[0] | shape[1] | shape[2] | shape[3]) & BIT7 == 0 {
                         shape.iter_mut().for_each(|b| *b <<= 1);
@@ -181,7 +217,7 @@ pub fn part_two(wind: &[u8]) -> u64 {
                 _ => unreachable!(),
             }
 
-            next_wind = (next_wind + 1) % wind.len();
+            self.next_wind = (self.next_wind + 1) % wind.len();
         }
 
         for low in (0..stack.len()).rev() {
@@ -193,7 +229,6 @@ pub fn part_two(wind: &[u8]) -> u64 {
             }
 
             if overlap {
-                let old_height = stack.len();
                 let intersect = (stack.len() - low - 1).min(shape.len());
                 for pos in 0..intersect {
                     stack[low + pos + 1] |= shape[shape.len() - 1 - pos];
@@ -205,31 +240,10 @@ pub fn part_two(wind: &[u8]) -> u64 {
                     }
                 }
 
-                let height_diff = (stack.len() - old_height) as u64;
-                height += height_diff;
-
-                fallen += 1;
-
-                if height >= CACHE_ROWS as u64 && fallen % SHAPES.len() as u64 == 0 {
-                    // CHECK for cycles by memorizing the last N rows adn wind index
-                    if let Some((prev_fallen, prev_height)) = cache.insert(
-                        (next_wind, stack[stack.len() - CACHE_ROWS..].to_vec()),
-                        (fallen, height),
-                    ) {
-                        let cycle_len = fallen - prev_fallen;
-                        let cycles = (TARGET_ROCKS - fallen) / cycle_len;
-                        fallen += cycles * cycle_len;
-
-                        let height_diff = height - prev_height;
-                        height += cycles * height_diff;
-                        cache.clear();
-                    }
-                }
-
-                continue 'next;
+                return;
             }
 
-            match wind[next_wind] {
+            match wind[self.next_wind] {
                 LEFT => {
                     let mut cannot_shift = (shape[0] | shape[1] | shape[2] | shape[3]) & BIT7 != 0;
                     for pos in 0..intersect {
@@ -256,10 +270,9 @@ pub fn part_two(wind: &[u8]) -> u64 {
 
                 _ => unreachable!(),
             }
-            next_wind = (next_wind + 1) % wind.len();
+            self.next_wind = (self.next_wind + 1) % wind.len();
         }
 
-        let old_height = stack.len();
         let intersect = stack.len().min(shape.len());
         for pos in 0..intersect {
             stack[pos] |= shape[shape.len() - 1 - pos];
@@ -270,14 +283,7 @@ pub fn part_two(wind: &[u8]) -> u64 {
                 stack.push(shape[shape.len() - 1 - pos]);
             }
         }
-
-        let height_diff = (stack.len() - old_height) as u64;
-        height += height_diff;
-
-        fallen += 1;
     }
-
-    height
 }
 
 #[cfg(test)]
@@ -298,4 +304,11 @@ mod tests {
         let answer = part_two(input.trim_end().as_bytes());
         assert_eq!(1540804597682, answer)
     }
+
+    #[test]
+    fn test_example() {
+        let wind = b">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+        assert_eq!(3068, part_one(wind));
+        assert_eq!(1514285714288, part_two(wind));
+    }
 }
@@ -3,6 +3,7 @@ use std::hash::Hash;
 use std::str::FromStr;
 
 use aoc_shared::hashing::HashSet;
+use aoc_shared::parallel;
 use once_cell::sync::Lazy;
 use regex::Regex;
 
@@ -177,101 +178,97 @@ impl FromStr for BluePrint {
 }
 
 pub fn part_one(blue_prints: &[BluePrint]) -> u16 {
-    bfs(blue_prints, 24, blue_prints.len(), 0, |acc, id, geodes| {
-        acc + id * geodes
-    })
+    parallel::map_reduce(
+        blue_prints,
+        |blue_print| blue_print.id * max_geodes(blue_print, 24),
+        |a, b| a + b,
+    )
+    .unwrap_or(0)
 }
 
 pub fn part_two(blue_prints: &[BluePrint]) -> u16 {
-    bfs(blue_prints, 32, 3, 1, |acc, _, geodes| acc * geodes)
+    let blue_prints = &blue_prints[..blue_prints.len().min(3)];
+    parallel::map_reduce(
+        blue_prints,
+        |blue_print| max_geodes(blue_print, 32),
+        |a, b| a * b,
+    )
+    .unwrap_or(1)
 }
 
-fn bfs<F: Fn(u16, u16, u16) -> u16>(
-    blue_prints: &[BluePrint],
-    minutes: u32,
-    take: usize,
-    initial: u16,
-    fold_fn: F,
-) -> u16 {
+// The blueprints are independent of each other, so they can be solved in parallel
+fn max_geodes(blue_print: &BluePrint, minutes: u32) -> u16 {
     let mut visited = HashSet::default();
-    let mut answer = initial;
-
     let mut queue = VecDeque::new();
-    for blue_print in blue_prints.iter().copied().take(take) {
-        visited.clear();
-        queue.clear();
-
-        queue.push_back(([1, 0, 0, 0], [0, 0, 0], 0));
-        visited.insert(([1, 0, 0, 0], [0, 0, 0]));
-
-        let mut best = 0;
-        for _ in 0..minutes {
-            for _ in 0..queue.len() {
-                let (robots, resources, geodes) = queue.pop_front().unwrap();
-                let geodes = geodes + robots[RBT_GEO];
-                best = best.max(geodes);
-
-                // HEURISTIC: prune branches that have lower number of geodes
-                // What's the correct value?
-                if best - geodes > 0 {
-                    continue;
+
+    queue.push_back(([1, 0, 0, 0], [0, 0, 0], 0));
+    visited.insert(([1, 0, 0, 0], [0, 0, 0]));
+
+    let mut best = 0;
+    for _ in 0..minutes {
+        for _ in 0..queue.len() {
+            let (robots, resources, geodes) = queue.pop_front().unwrap();
+            let geodes = geodes + robots[RBT_GEO];
+            best = best.max(geodes);
+
+            // HEURISTIC: prune branches that have lower number of geodes
+            // What's the correct value?
+            if best - geodes > 0 {
+                continue;
+            }
+
+            // We always build a geode robot if we can and avoid branching
+            if blue_print.geode_robot.can_build(resources) {
+                let (mut res, rob) = blue_print.geode_robot.build(resources, robots);
+                res = update_resources(res, robots);
+
+                if visited.insert((rob, res)) {
+                    queue.push_back((rob, res, geodes));
                 }
+                continue;
+            }
 
-                // We always build a geode robot if we can and avoid branching
-                if blue_print.geode_robot.can_build(resources) {
-                    let (mut res, rob) = blue_print.geode_robot.build(resources, robots);
+            if blue_print.max_res[RES_OBS] > robots[RBT_OBS] {
+                if blue_print.obsidian_robot.can_build(resources) {
+                    let (mut res, rob) = blue_print.obsidian_robot.build(resources, robots);
                     res = update_resources(res, robots);
 
                     if visited.insert((rob, res)) {
                         queue.push_back((rob, res, geodes));
                     }
-                    continue;
-                }
-
-                if blue_print.max_res[RES_OBS] > robots[RBT_OBS] {
-                    if blue_print.obsidian_robot.can_build(resources) {
-                        let (mut res, rob) = blue_print.obsidian_robot.build(resources, robots);
-                        res = update_resources(res, robots);
-
-                        if visited.insert((rob, res)) {
-                            queue.push_back((rob, res, geodes));
-                        }
-                    }
                 }
+            }
 
-                if blue_print.max_res[RES_CLY] > robots[RBT_CLY] {
-                    if blue_print.clay_robot.can_build(resources) {
-                        let (mut res, rob) = blue_print.clay_robot.build(resources, robots);
-                        res = update_resources(res, robots);
+            if blue_print.max_res[RES_CLY] > robots[RBT_CLY] {
+                if blue_print.clay_robot.can_build(resources) {
+                    let (mut res, rob) = blue_print.clay_robot.build(resources, robots);
+                    res = update_resources(res, robots);
 
-                        if visited.insert((rob, res)) {
-                            queue.push_back((rob, res, geodes));
-                        }
+                    if visited.insert((rob, res)) {
+                        queue.push_back((rob, res, geodes));
                     }
                 }
+            }
 
-                if blue_print.max_res[RES_ORE] > robots[RBT_ORE] {
-                    if blue_print.ore_robot.can_build(resources) {
-                        let (mut res, rob) = blue_print.ore_robot.build(resources, robots);
-                        res = update_resources(res, robots);
+            if blue_print.max_res[RES_ORE] > robots[RBT_ORE] {
+                if blue_print.ore_robot.can_build(resources) {
+                    let (mut res, rob) = blue_print.ore_robot.build(resources, robots);
+                    res = update_resources(res, robots);
 
-                        if visited.insert((rob, res)) {
-                            queue.push_back((rob, res, geodes));
-                        }
+                    if visited.insert((rob, res)) {
+                        queue.push_back((rob, res, geodes));
                     }
                 }
+            }
 
-                let resources = update_resources(resources, robots);
-                if visited.insert((robots, resources)) {
-                    queue.push_back((robots, resources, geodes));
-                }
+            let resources = update_resources(resources, robots);
+            if visited.insert((robots, resources)) {
+                queue.push_back((robots, resources, geodes));
             }
         }
-
-        answer = fold_fn(answer, blue_print.id, best);
     }
 
-    answer
+    best
 }
 
 fn update_resources(mut resources: [u16; 3], robots: [u16; 4]) -> [u16; 3] {
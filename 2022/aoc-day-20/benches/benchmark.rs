@@ -1,5 +1,6 @@
 use aoc_day_20::{v1, v2};
-use aoc_shared::input::load_line_delimited_input_from_file;
+use aoc_shared::input_locator;
+use aoc_shared::parsing::parse_line_delimited;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 criterion_group!(benches, benchmark_part_one, benchmark_part_two);
@@ -9,7 +10,7 @@ fn benchmark_part_one(c: &mut Criterion) {
     let mut g = c.benchmark_group("part-1");
     g.sample_size(30);
 
-    let input = load_line_delimited_input_from_file("inputs/input.txt");
+    let input = parse_line_delimited(input_locator!(2022, 20).load().unwrap());
 
     g.bench_function("Rc<RefCell<>>", |b| {
         b.iter(|| v1::part_one(black_box(&input)));
@@ -24,7 +25,7 @@ fn benchmark_part_two(c: &mut Criterion) {
     let mut g = c.benchmark_group("part-2");
     g.sample_size(10);
 
-    let input = load_line_delimited_input_from_file("inputs/input.txt");
+    let input = parse_line_delimited(input_locator!(2022, 20).load().unwrap());
 
     g.bench_function("Rc<RefCell<>>", |b| {
         b.iter(|| v1::part_two(black_box(&input)));
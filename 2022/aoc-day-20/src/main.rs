@@ -1,9 +1,14 @@
-use aoc_shared::input::load_line_delimited_input_from_autodetect;
+use std::error::Error;
+
+use aoc_shared::input::try_load_line_delimited_input_from_autodetect;
+use aoc_shared::input_locator;
 
 use aoc_day_20::v2;
 
-fn main() {
-    let input = load_line_delimited_input_from_autodetect();
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = try_load_line_delimited_input_from_autodetect(input_locator!(2022, 20))?;
     println!("Part 1: {}", v2::part_one(&input));
     println!("Part 2: {}", v2::part_two(&input));
+
+    Ok(())
 }
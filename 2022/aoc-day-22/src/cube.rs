@@ -0,0 +1,381 @@
+use std::collections::VecDeque;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Facing {
+    Right = 0,
+    Down = 1,
+    Left = 2,
+    Up = 3,
+}
+
+const FACINGS: [Facing; 4] = [Facing::Right, Facing::Down, Facing::Left, Facing::Up];
+
+impl Facing {
+    pub fn rotate_right(self) -> Self {
+        match self {
+            Facing::Right => Facing::Down,
+            Facing::Down => Facing::Left,
+            Facing::Left => Facing::Up,
+            Facing::Up => Facing::Right,
+        }
+    }
+
+    pub fn rotate_left(self) -> Self {
+        match self {
+            Facing::Right => Facing::Up,
+            Facing::Down => Facing::Right,
+            Facing::Left => Facing::Down,
+            Facing::Up => Facing::Left,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate_right().rotate_right()
+    }
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+type Vector = [i32; 3];
+
+fn neg(v: Vector) -> Vector {
+    [-v[0], -v[1], -v[2]]
+}
+
+// How a face of the net lies on the cube: the directions of its columns and rows,
+// and its outward normal
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Orientation {
+    right: Vector,
+    down: Vector,
+    normal: Vector,
+}
+
+impl Orientation {
+    // The orientation of the neighbour in the net, after folding it along the
+    // shared edge
+    fn roll(self, facing: Facing) -> Self {
+        let Orientation {
+            right,
+            down,
+            normal,
+        } = self;
+
+        match facing {
+            Facing::Right => Orientation {
+                right: neg(normal),
+                down,
+                normal: right,
+            },
+            Facing::Left => Orientation {
+                right: normal,
+                down,
+                normal: neg(right),
+            },
+            Facing::Down => Orientation {
+                right,
+                down: neg(normal),
+                normal: down,
+            },
+            Facing::Up => Orientation {
+                right,
+                down: normal,
+                normal: neg(down),
+            },
+        }
+    }
+
+    // The direction from the centre of the face to its edge in `facing`
+    fn edge(self, facing: Facing) -> Vector {
+        match facing {
+            Facing::Right => self.right,
+            Facing::Left => neg(self.right),
+            Facing::Down => self.down,
+            Facing::Up => neg(self.down),
+        }
+    }
+
+    // The direction in which the position along an edge increases
+    fn tangent(self, facing: Facing) -> Vector {
+        match facing {
+            Facing::Right | Facing::Left => self.down,
+            Facing::Down | Facing::Up => self.right,
+        }
+    }
+}
+
+// Where walking off a face edge leads
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Edge {
+    face: usize,
+    facing: Facing,
+    // whether the position along the edge is reversed
+    flip: bool,
+}
+
+/// A map folded into a cube. Works with any of the 11 cube nets and face size.
+#[derive(Debug, Clone)]
+pub struct Cube {
+    size: usize,
+    // the top-left corner of each face in the map
+    corners: Vec<(usize, usize)>,
+    // `edges[face][facing]` is where walking off that edge of the face leads
+    edges: Vec<[Edge; 4]>,
+}
+
+impl Cube {
+    /// Detects the faces of the net and how they are glued together. Returns
+    /// `None` if the map is not a cube net.
+    pub fn fold(map: &[Vec<u8>]) -> Option<Cube> {
+        let is_tile = |r: usize, c: usize| {
+            map.get(r)
+                .and_then(|row| row.get(c))
+                .is_some_and(|&ch| ch == b'.' || ch == b'#')
+        };
+
+        let tiles = map
+            .iter()
+            .map(|row| row.iter().filter(|&&ch| ch != b' ').count())
+            .sum::<usize>();
+        let size = (1..).find(|n| 6 * n * n >= tiles)?;
+        if 6 * size * size != tiles {
+            return None;
+        }
+
+        // the faces in reading order, and the face in each block of the map
+        let width = map.iter().map(|row| row.len()).max()?;
+        let (rows, cols) = (map.len().div_ceil(size), width.div_ceil(size));
+        let mut blocks = vec![vec![None; cols]; rows];
+        let mut corners = vec![];
+        for (br, block_row) in blocks.iter_mut().enumerate() {
+            for (bc, block) in block_row.iter_mut().enumerate() {
+                if is_tile(br * size, bc * size) {
+                    *block = Some(corners.len());
+                    corners.push((br * size, bc * size));
+                }
+            }
+        }
+
+        if corners.len() != 6 {
+            return None;
+        }
+
+        // fold the net starting from the first face
+        let mut orientations = [None; 6];
+        orientations[0] = Some(Orientation {
+            right: [1, 0, 0],
+            down: [0, 1, 0],
+            normal: [0, 0, 1],
+        });
+
+        let mut queue = VecDeque::new();
+        queue.push_back(0);
+        while let Some(face) = queue.pop_front() {
+            let (br, bc) = (corners[face].0 / size, corners[face].1 / size);
+            let orientation = orientations[face].unwrap();
+
+            for facing in FACINGS {
+                let (nr, nc) = match facing {
+                    Facing::Right => (br, bc + 1),
+                    Facing::Down => (br + 1, bc),
+                    Facing::Left if bc > 0 => (br, bc - 1),
+                    Facing::Up if br > 0 => (br - 1, bc),
+                    _ => continue,
+                };
+
+                let Some(next) = blocks
+                    .get(nr)
+                    .and_then(|row| row.get(nc))
+                    .copied()
+                    .flatten()
+                else {
+                    continue;
+                };
+
+                if orientations[next].is_none() {
+                    orientations[next] = Some(orientation.roll(facing));
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        let orientations = orientations.into_iter().collect::<Option<Vec<_>>>()?;
+
+        // every face must end up on a different side of the cube
+        for (idx, orientation) in orientations.iter().enumerate() {
+            if orientations[..idx]
+                .iter()
+                .any(|other| other.normal == orientation.normal)
+            {
+                return None;
+            }
+        }
+
+        let face_with_normal = |normal: Vector| {
+            orientations
+                .iter()
+                .position(|orientation| orientation.normal == normal)
+        };
+
+        let mut edges = vec![];
+        for orientation in orientations.iter().copied() {
+            let mut face_edges = [Edge {
+                face: 0,
+                facing: Facing::Right,
+                flip: false,
+            }; 4];
+
+            for facing in FACINGS {
+                let next = face_with_normal(orientation.edge(facing))?;
+                let entry = FACINGS
+                    .into_iter()
+                    .find(|&e| orientations[next].edge(e) == orientation.normal)?;
+
+                face_edges[facing.index()] = Edge {
+                    face: next,
+                    facing: entry.opposite(),
+                    flip: orientations[next].tangent(entry) != orientation.tangent(facing),
+                };
+            }
+
+            edges.push(face_edges);
+        }
+
+        Some(Cube {
+            size,
+            corners,
+            edges,
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The position and facing after one step, which changes the facing only
+    /// when crossing to another face
+    pub fn step(&self, (r, c): (usize, usize), facing: Facing) -> ((usize, usize), Facing) {
+        let n = self.size;
+        let (lr, lc) = (r % n, c % n);
+
+        match facing {
+            Facing::Right if lc + 1 < n => return ((r, c + 1), facing),
+            Facing::Down if lr + 1 < n => return ((r + 1, c), facing),
+            Facing::Left if lc > 0 => return ((r, c - 1), facing),
+            Facing::Up if lr > 0 => return ((r - 1, c), facing),
+            _ => {}
+        }
+
+        let face = self
+            .corners
+            .iter()
+            .position(|&corner| corner == (r - lr, c - lc))
+            .expect("the position is not on the cube");
+
+        let edge = self.edges[face][facing.index()];
+        let offset = match facing {
+            Facing::Right | Facing::Left => lr,
+            Facing::Down | Facing::Up => lc,
+        };
+        let offset = if edge.flip { n - 1 - offset } else { offset };
+
+        // we enter through the edge opposite to the new facing
+        let (lr, lc) = match edge.facing {
+            Facing::Down => (0, offset),
+            Facing::Up => (n - 1, offset),
+            Facing::Right => (offset, 0),
+            Facing::Left => (offset, n - 1),
+        };
+
+        let (r0, c0) = self.corners[edge.face];
+        ((r0 + lr, c0 + lc), edge.facing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cube::{Cube, Facing, FACINGS};
+
+    // The 11 cube nets, as blocks of faces
+    const NETS: [&str; 11] = [
+        "#...\n####\n#...",
+        "#...\n####\n.#..",
+        "#...\n####\n..#.",
+        "#...\n####\n...#",
+        ".#..\n####\n.#..",
+        ".#..\n####\n..#.",
+        "##..\n.###\n.#..",
+        "##..\n.###\n..#.",
+        "##..\n.###\n...#",
+        "##..\n.##.\n..##",
+        "###..\n..###",
+    ];
+
+    fn net_map(net: &str, size: usize) -> Vec<Vec<u8>> {
+        let mut map = vec![];
+        for line in net.lines() {
+            let row = line
+                .bytes()
+                .flat_map(|block| {
+                    let tile = if block == b'#' { b'.' } else { b' ' };
+                    std::iter::repeat_n(tile, size)
+                })
+                .collect::<Vec<_>>();
+
+            for _ in 0..size {
+                map.push(row.clone());
+            }
+        }
+
+        map
+    }
+
+    #[test]
+    fn test_all_nets() {
+        for size in [1, 2, 3] {
+            for net in NETS {
+                let map = net_map(net, size);
+                let cube = Cube::fold(&map).unwrap_or_else(|| panic!("cannot fold:\n{}", net));
+                assert_eq!(size, cube.size());
+
+                // walking straight around the cube leads back to the start
+                for (r, row) in map.iter().enumerate() {
+                    for c in (0..row.len()).filter(|&c| row[c] == b'.') {
+                        for facing in FACINGS {
+                            let mut state = ((r, c), facing);
+                            for _ in 0..4 * size {
+                                state = cube.step(state.0, state.1);
+                            }
+                            assert_eq!(((r, c), facing), state, "net:\n{}", net);
+
+                            // and turning around retraces the steps
+                            let next = cube.step((r, c), facing);
+                            let back = cube.step(next.0, next.1.opposite());
+                            assert_eq!(((r, c), facing.opposite()), back, "net:\n{}", net);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_not_a_cube() {
+        assert!(Cube::fold(&net_map("######", 2)).is_none());
+        assert!(Cube::fold(&net_map("##..\n####", 2)).is_none());
+        assert!(Cube::fold(&net_map("#...\n####", 2)).is_none());
+    }
+
+    #[test]
+    fn test_example_edges() {
+        let map = net_map("..#.\n###.\n..##", 4);
+        let cube = Cube::fold(&map).unwrap();
+
+        // A to B in the puzzle description
+        assert_eq!(((8, 14), Facing::Down), cube.step((5, 11), Facing::Right));
+        // C to D
+        assert_eq!(((7, 1), Facing::Up), cube.step((11, 10), Facing::Down));
+    }
+}
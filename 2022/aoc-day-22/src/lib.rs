@@ -1,5 +1,6 @@
 use std::num::NonZeroU32;
 
+pub mod cube;
 pub mod part_one;
 pub mod part_two;
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -1,13 +1,18 @@
-use aoc_shared::input::load_text_input_from_autodetect;
+use std::error::Error;
+
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
 
 use aoc_day_22::parse_input;
 use aoc_day_22::part_one::part_one;
 use aoc_day_22::part_two::part_two;
 
-fn main() {
-    let input = load_text_input_from_autodetect();
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = try_load_text_input_from_autodetect(input_locator!(2022, 22))?;
     let (map, instr) = parse_input(input);
 
     println!("Part 1: {}", part_one(&map, &instr));
     println!("Part 2: {}", part_two(&map, &instr));
+
+    Ok(())
 }
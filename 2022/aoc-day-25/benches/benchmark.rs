@@ -1,4 +1,4 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 use aoc_day_25::part_one;
@@ -7,7 +7,7 @@ criterion_group!(benches, benchmark_part_one);
 criterion_main!(benches);
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2022, 25).load().unwrap();
 
     c.bench_function("part-1", |b| {
         b.iter(|| part_one(black_box(&input)));
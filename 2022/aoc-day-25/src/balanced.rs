@@ -0,0 +1,243 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// A balanced numeral system with an odd base, where the digits go from `-(base - 1) / 2`
+/// to `(base - 1) / 2`. Numbers are stored as their digits, least significant first,
+/// without leading zeros, so zero has no digits at all.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct BalancedBase {
+    // the symbols of the digits, from the smallest to the largest
+    symbols: &'static [u8],
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ParseDigitsError {
+    Empty,
+    InvalidDigit { position: usize, found: char },
+}
+
+impl Display for ParseDigitsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseDigitsError::Empty => write!(f, "cannot parse a number without digits"),
+            ParseDigitsError::InvalidDigit { position, found } => {
+                write!(f, "invalid digit {:?} at {}", found, position)
+            }
+        }
+    }
+}
+
+impl Error for ParseDigitsError {}
+
+impl BalancedBase {
+    pub const fn new(symbols: &'static [u8]) -> BalancedBase {
+        assert!(symbols.len() >= 3 && symbols.len() & 1 == 1 && symbols.len() <= 127);
+        BalancedBase { symbols }
+    }
+
+    pub fn base(&self) -> i8 {
+        self.symbols.len() as i8
+    }
+
+    pub fn max_digit(&self) -> i8 {
+        self.base() / 2
+    }
+
+    pub fn parse(&self, s: &str) -> Result<Vec<i8>, ParseDigitsError> {
+        if s.is_empty() {
+            return Err(ParseDigitsError::Empty);
+        }
+
+        let mut digits = s
+            .char_indices()
+            .rev()
+            .map(|(position, found)| {
+                self.symbols
+                    .iter()
+                    .position(|&symbol| symbol as char == found)
+                    .map(|idx| idx as i8 - self.max_digit())
+                    .ok_or(ParseDigitsError::InvalidDigit { position, found })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        trim(&mut digits);
+        Ok(digits)
+    }
+
+    pub fn format(&self, digits: &[i8]) -> String {
+        if digits.is_empty() {
+            return (self.symbols[self.max_digit() as usize] as char).to_string();
+        }
+
+        digits
+            .iter()
+            .rev()
+            .map(|&d| self.symbols[(d + self.max_digit()) as usize] as char)
+            .collect()
+    }
+
+    pub fn add(&self, a: &[i8], b: &[i8]) -> Vec<i8> {
+        let columns = (0..a.len().max(b.len()))
+            .map(|idx| {
+                let a = a.get(idx).copied().unwrap_or(0);
+                let b = b.get(idx).copied().unwrap_or(0);
+                a as i64 + b as i64
+            })
+            .collect();
+
+        self.normalize(columns)
+    }
+
+    pub fn neg(&self, a: &[i8]) -> Vec<i8> {
+        a.iter().map(|&d| -d).collect()
+    }
+
+    pub fn sub(&self, a: &[i8], b: &[i8]) -> Vec<i8> {
+        self.add(a, &self.neg(b))
+    }
+
+    pub fn mul(&self, a: &[i8], b: &[i8]) -> Vec<i8> {
+        if a.is_empty() || b.is_empty() {
+            return vec![];
+        }
+
+        let mut columns = vec![0i64; a.len() + b.len()];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                columns[i + j] += x as i64 * y as i64;
+            }
+        }
+
+        self.normalize(columns)
+    }
+
+    pub fn from_i128(&self, mut value: i128) -> Vec<i8> {
+        let base = self.base() as i128;
+        let max_digit = self.max_digit() as i128;
+
+        let mut digits = vec![];
+        while value != 0 {
+            let mut digit = value.rem_euclid(base);
+            value = value.div_euclid(base);
+            if digit > max_digit {
+                digit -= base;
+                value += 1;
+            }
+            digits.push(digit as i8);
+        }
+
+        digits
+    }
+
+    /// Returns `None` when the number does not fit in an `i128`
+    pub fn to_i128(&self, digits: &[i8]) -> Option<i128> {
+        let base = self.base() as i128;
+
+        // `acc * base + d` is computed as `(acc - sign) * base + (d + sign * base)`,
+        // so that the product cannot overflow when the sum fits
+        digits.iter().rev().try_fold(0i128, |acc, &d| {
+            let sign = acc.signum();
+            (acc - sign)
+                .checked_mul(base)?
+                .checked_add(d as i128 + sign * base)
+        })
+    }
+
+    // Turns the sums of the digits in every column back into digits, by carrying
+    // the excess to the next column
+    fn normalize(&self, columns: Vec<i64>) -> Vec<i8> {
+        let base = self.base() as i64;
+        let max_digit = self.max_digit() as i64;
+
+        let mut digits = Vec::with_capacity(columns.len() + 1);
+        let mut carry = 0;
+        let mut idx = 0;
+        while idx < columns.len() || carry != 0 {
+            let total = columns.get(idx).copied().unwrap_or(0) + carry;
+            carry = (total + max_digit).div_euclid(base);
+            digits.push((total - carry * base) as i8);
+            idx += 1;
+        }
+
+        trim(&mut digits);
+        digits
+    }
+}
+
+fn trim(digits: &mut Vec<i8>) {
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::balanced::{BalancedBase, ParseDigitsError};
+
+    const TERNARY: BalancedBase = BalancedBase::new(b"T01");
+
+    #[test]
+    fn test_balanced_ternary() {
+        let numbers = [
+            (0, "0"),
+            (1, "1"),
+            (-1, "T"),
+            (2, "1T"),
+            (8, "10T"),
+            (-8, "T01"),
+            (9, "100"),
+        ];
+
+        for (value, text) in numbers {
+            let digits = TERNARY.parse(text).unwrap();
+            assert_eq!(Some(value), TERNARY.to_i128(&digits));
+            assert_eq!(digits, TERNARY.from_i128(value));
+            assert_eq!(text, TERNARY.format(&digits));
+        }
+
+        assert_eq!(
+            TERNARY.parse("1T").unwrap(),
+            TERNARY.parse("0001T").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = TERNARY.from_i128(-47);
+        let b = TERNARY.from_i128(12);
+
+        assert_eq!(Some(-35), TERNARY.to_i128(&TERNARY.add(&a, &b)));
+        assert_eq!(Some(-59), TERNARY.to_i128(&TERNARY.sub(&a, &b)));
+        assert_eq!(Some(-564), TERNARY.to_i128(&TERNARY.mul(&a, &b)));
+        assert!(TERNARY.sub(&a, &a).is_empty());
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(Err(ParseDigitsError::Empty), TERNARY.parse(""));
+        assert_eq!(
+            Err(ParseDigitsError::InvalidDigit {
+                position: 2,
+                found: '2'
+            }),
+            TERNARY.parse("1T2")
+        );
+        assert_eq!(
+            Err(ParseDigitsError::InvalidDigit {
+                position: 1,
+                found: 'é'
+            }),
+            TERNARY.parse("1é")
+        );
+    }
+
+    #[test]
+    fn test_limits() {
+        for value in [i128::MAX, i128::MIN, i128::MIN + 1] {
+            assert_eq!(Some(value), TERNARY.to_i128(&TERNARY.from_i128(value)));
+        }
+
+        let max = TERNARY.from_i128(i128::MAX);
+        assert_eq!(None, TERNARY.to_i128(&TERNARY.add(&max, &[1])));
+    }
+}
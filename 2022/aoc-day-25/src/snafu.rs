@@ -0,0 +1,244 @@
+use std::fmt::{Display, Formatter};
+use std::iter::Sum;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+
+use crate::balanced::{BalancedBase, ParseDigitsError};
+
+pub const SNAFU_DIGITS: BalancedBase = BalancedBase::new(b"=-012");
+
+/// A number in SNAFU, the balanced base 5 of the elves. The arithmetic works on the
+/// digits, so the numbers are not limited to the range of any integer type.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Snafu {
+    // least significant first
+    digits: Vec<i8>,
+}
+
+impl Snafu {
+    pub fn zero() -> Snafu {
+        Snafu::default()
+    }
+
+    /// Returns `None` when the number does not fit in an `i128`
+    pub fn to_i128(&self) -> Option<i128> {
+        SNAFU_DIGITS.to_i128(&self.digits)
+    }
+}
+
+impl From<i128> for Snafu {
+    fn from(value: i128) -> Snafu {
+        Snafu {
+            digits: SNAFU_DIGITS.from_i128(value),
+        }
+    }
+}
+
+impl From<i64> for Snafu {
+    fn from(value: i64) -> Snafu {
+        Snafu::from(value as i128)
+    }
+}
+
+impl FromStr for Snafu {
+    type Err = ParseDigitsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Snafu {
+            digits: SNAFU_DIGITS.parse(s)?,
+        })
+    }
+}
+
+impl Display for Snafu {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", SNAFU_DIGITS.format(&self.digits))
+    }
+}
+
+impl Add for &Snafu {
+    type Output = Snafu;
+
+    fn add(self, rhs: &Snafu) -> Snafu {
+        Snafu {
+            digits: SNAFU_DIGITS.add(&self.digits, &rhs.digits),
+        }
+    }
+}
+
+impl Sub for &Snafu {
+    type Output = Snafu;
+
+    fn sub(self, rhs: &Snafu) -> Snafu {
+        Snafu {
+            digits: SNAFU_DIGITS.sub(&self.digits, &rhs.digits),
+        }
+    }
+}
+
+impl Mul for &Snafu {
+    type Output = Snafu;
+
+    fn mul(self, rhs: &Snafu) -> Snafu {
+        Snafu {
+            digits: SNAFU_DIGITS.mul(&self.digits, &rhs.digits),
+        }
+    }
+}
+
+impl Neg for &Snafu {
+    type Output = Snafu;
+
+    fn neg(self) -> Snafu {
+        Snafu {
+            digits: SNAFU_DIGITS.neg(&self.digits),
+        }
+    }
+}
+
+impl Add for Snafu {
+    type Output = Snafu;
+
+    fn add(self, rhs: Snafu) -> Snafu {
+        &self + &rhs
+    }
+}
+
+impl Sub for Snafu {
+    type Output = Snafu;
+
+    fn sub(self, rhs: Snafu) -> Snafu {
+        &self - &rhs
+    }
+}
+
+impl Mul for Snafu {
+    type Output = Snafu;
+
+    fn mul(self, rhs: Snafu) -> Snafu {
+        &self * &rhs
+    }
+}
+
+impl Neg for Snafu {
+    type Output = Snafu;
+
+    fn neg(self) -> Snafu {
+        -&self
+    }
+}
+
+impl Sum for Snafu {
+    fn sum<I: Iterator<Item = Snafu>>(iter: I) -> Snafu {
+        iter.fold(Snafu::zero(), |sum, n| &sum + &n)
+    }
+}
+
+impl<'a> Sum<&'a Snafu> for Snafu {
+    fn sum<I: Iterator<Item = &'a Snafu>>(iter: I) -> Snafu {
+        iter.fold(Snafu::zero(), |sum, n| &sum + n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use aoc_shared::testing::XorShift;
+
+    use crate::snafu::Snafu;
+
+    const EXAMPLES: [(i128, &str); 18] = [
+        (1, "1"),
+        (2, "2"),
+        (3, "1="),
+        (4, "1-"),
+        (5, "10"),
+        (6, "11"),
+        (7, "12"),
+        (8, "2="),
+        (9, "2-"),
+        (10, "20"),
+        (15, "1=0"),
+        (20, "1-0"),
+        (906, "12111"),
+        (1257, "20012"),
+        (1747, "1=-0-2"),
+        (2022, "1=11-2"),
+        (12345, "1-0---0"),
+        (314159265, "1121-1110-1=0"),
+    ];
+
+    // A value with a random number of bits, so that small values are common too
+    fn random_value(rng: &mut XorShift, max_bits: u32) -> i128 {
+        let bits = rng.below(max_bits as u64) as u32;
+        let value =
+            (((rng.next_u64() as u128) << 64 | rng.next_u64() as u128) >> (127 - bits)) as i128;
+        if rng.below(2) == 0 {
+            value
+        } else {
+            -value
+        }
+    }
+
+    #[test]
+    fn test_snafu_to_decimal() {
+        for (value, snafu) in EXAMPLES {
+            assert_eq!(Some(value), Snafu::from_str(snafu).unwrap().to_i128());
+        }
+    }
+
+    #[test]
+    fn test_decimal_to_snafu() {
+        for (value, snafu) in EXAMPLES {
+            assert_eq!(snafu, Snafu::from(value).to_string());
+        }
+
+        assert_eq!("0", Snafu::zero().to_string());
+        assert_eq!("-", Snafu::from(-1i64).to_string());
+        assert_eq!("1=", Snafu::from_str("001=").unwrap().to_string());
+    }
+
+    #[test]
+    fn test_larger_than_i64() {
+        let max = Snafu::from(i64::MAX);
+        let sum: Snafu = [&max, &max, &max].into_iter().sum();
+        assert_eq!(Some(3 * i64::MAX as i128), sum.to_i128());
+
+        let max = Snafu::from(i128::MAX);
+        let square = &max * &max;
+        assert_eq!(None, square.to_i128());
+
+        let one = Snafu::from(1i64);
+        let rest = &square - &(&max * &(&max - &one));
+        assert_eq!(max, rest);
+    }
+
+    #[test]
+    fn test_arithmetic_against_i128() {
+        let mut rng = XorShift::new(0x2022_1225);
+        for _ in 0..2000 {
+            let a = random_value(&mut rng, 62);
+            let b = random_value(&mut rng, 62);
+            let (x, y) = (Snafu::from(a), Snafu::from(b));
+
+            assert_eq!(Some(a), x.to_i128());
+            assert_eq!(x, Snafu::from_str(&x.to_string()).unwrap());
+            assert_eq!(Some(a + b), (&x + &y).to_i128(), "{} + {}", a, b);
+            assert_eq!(Some(a - b), (&x - &y).to_i128(), "{} - {}", a, b);
+            assert_eq!(Some(a * b), (&x * &y).to_i128(), "{} * {}", a, b);
+            assert_eq!(Some(-a), (-&x).to_i128());
+        }
+    }
+
+    #[test]
+    fn test_conversion_against_i128() {
+        let mut rng = XorShift::new(0x2512_2022);
+        for _ in 0..2000 {
+            let value = random_value(&mut rng, 127);
+            let snafu = Snafu::from(value);
+            assert_eq!(Some(value), snafu.to_i128());
+            assert_eq!(snafu, Snafu::from_str(&snafu.to_string()).unwrap());
+        }
+    }
+}
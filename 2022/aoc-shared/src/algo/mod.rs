@@ -0,0 +1 @@
+pub use aoc_core::cycle;
@@ -1,3 +1,6 @@
+pub use aoc_core::{bitset, blocks, grid, input_locator, ocr, parallel, testing, union_find};
+
+pub mod algo;
 pub mod hashing;
 pub mod input;
 pub mod parsing;
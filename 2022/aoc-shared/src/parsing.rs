@@ -1,20 +1,30 @@
 use std::fmt::Debug;
 use std::str::FromStr;
 
+use aoc_core::parsing::parse_trimmed;
+pub use aoc_core::parsing::{try_parse_csv, NotADigit, ParseError};
+
 pub fn parse_line_delimited<I, R>(input: I) -> Vec<R>
     where
         I: AsRef<str>,
         R: FromStr,
         <R as FromStr>::Err: Debug,
+{
+    try_parse_line_delimited(input).unwrap()
+}
+
+pub fn try_parse_line_delimited<I, R>(input: I) -> Result<Vec<R>, ParseError<R::Err>>
+    where
+        I: AsRef<str>,
+        R: FromStr,
 {
     input
         .as_ref()
         .lines()
-        .map(|l| l.trim())
-        .filter(|&l| !l.is_empty())
-        .map(|l| l.parse())
-        .collect::<Result<_, _>>()
-        .unwrap()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(idx, l)| parse_trimmed(l, idx))
+        .collect()
 }
 
 pub fn parse_csv<I, R>(input: I) -> Vec<R>
@@ -23,25 +33,33 @@ pub fn parse_csv<I, R>(input: I) -> Vec<R>
         R: FromStr,
         <R as FromStr>::Err: Debug,
 {
-    input
-        .as_ref()
-        .split(',')
-        .map(|v| v.trim())
-        .filter(|&v| !v.is_empty())
-        .map(|v| v.parse())
-        .collect::<Result<_, _>>()
-        .unwrap()
+    try_parse_csv(input).unwrap()
 }
 
 pub fn parse_numeric_grid<I: AsRef<str>, T: From<u8> + Copy>(input: I) -> Vec<Vec<T>> {
+    try_parse_numeric_grid(input).unwrap()
+}
+
+pub fn try_parse_numeric_grid<I: AsRef<str>, T: From<u8> + Copy>(
+    input: I,
+) -> Result<Vec<Vec<T>>, ParseError<NotADigit>> {
     input
         .as_ref()
         .lines()
-        .filter(|l| !l.is_empty())
-        .map(|l| {
-            l.as_bytes()
-                .iter()
-                .map(|&b| (b - b'0').try_into().unwrap())
+        .enumerate()
+        .filter(|(_, l)| !l.is_empty())
+        .map(|(row, l)| {
+            l.bytes()
+                .enumerate()
+                .map(|(col, b)| match b {
+                    b'0'..=b'9' => Ok(T::from(b - b'0')),
+                    _ => Err(ParseError {
+                        line: row + 1,
+                        column: col + 1,
+                        snippet: l.to_owned(),
+                        source: NotADigit(b as char),
+                    }),
+                })
                 .collect()
         })
         .collect()
@@ -98,4 +116,29 @@ mod tests {
         let parsed = parse_line_delimited(lines);
         assert_eq!(&[1u32, 2, 3], parsed.as_slice());
     }
+
+    #[test]
+    fn test_try_parse_line_delimited_error() {
+        let lines = "1\n2\n\n  x3 \n4";
+        let err = try_parse_line_delimited::<_, u32>(lines).unwrap_err();
+        assert_eq!(4, err.line);
+        assert_eq!(3, err.column);
+        assert_eq!("x3", err.snippet);
+    }
+
+    #[test]
+    fn test_try_parse_csv_error() {
+        let err = try_parse_csv::<_, i32>("1, 2,\n3,a").unwrap_err();
+        assert_eq!(2, err.line);
+        assert_eq!(3, err.column);
+        assert_eq!("a", err.snippet);
+        assert_eq!(Ok(vec![1, 2, 3]), try_parse_csv::<_, i32>("1, 2,\n3"));
+    }
+
+    #[test]
+    fn test_try_parse_numeric_grid_error() {
+        let err = try_parse_numeric_grid::<_, u8>("123\n4.6").unwrap_err();
+        assert_eq!((2, 2), (err.line, err.column));
+        assert_eq!(NotADigit('.'), err.source);
+    }
 }
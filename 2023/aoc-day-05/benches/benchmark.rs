@@ -1,4 +1,4 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{criterion_group, criterion_main, Criterion};
 
 use aoc_day_05::{parse_input, part_one, part_two_v1, part_two_v2};
@@ -12,7 +12,7 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2023, 5).load().unwrap();
 
     c.bench_function("parsing", |b| {
         b.iter(|| parse_input(&input));
@@ -20,8 +20,8 @@ fn benchmark_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
-    let input = parse_input(input);
+    let input = input_locator!(2023, 5).load().unwrap();
+    let input = parse_input(input).unwrap();
 
     c.bench_function("part-1", |b| {
         b.iter(|| part_one(&input));
@@ -29,8 +29,8 @@ fn benchmark_part_one(c: &mut Criterion) {
 }
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
-    let input = parse_input(input);
+    let input = input_locator!(2023, 5).load().unwrap();
+    let input = parse_input(input).unwrap();
 
     c.bench_function("part-2/v1 - alloc", |b| {
         b.iter(|| part_two_v1(&input));
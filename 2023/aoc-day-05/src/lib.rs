@@ -1,5 +1,8 @@
 use std::collections::BTreeMap;
-use std::str::Lines;
+use std::error::Error;
+
+use aoc_shared::blocks::{sections, Block};
+use aoc_shared::parsing::ParseError;
 
 #[derive(Default)]
 pub struct Input {
@@ -13,96 +16,50 @@ pub struct Input {
     humidity_to_location: BTreeMap<u64, (u64, u64)>,
 }
 
-pub fn parse_input(input: impl AsRef<str>) -> Input {
-    let input = input.as_ref();
-    let mut lines = input.lines();
-
-    return Input {
-        seeds: read_seeds(&mut lines),
-        seed_to_soil: read_seed_to_soil(&mut lines),
-        soil_to_fertilizer: read_soil_to_fertilizer(&mut lines),
-        fertilizer_to_water: read_fertilizer_to_water(&mut lines),
-        water_to_light: read_water_to_light(&mut lines),
-        light_to_temp: read_light_to_temp(&mut lines),
-        temp_to_humidity: read_temp_to_humidity(&mut lines),
-        humidity_to_location: read_humidity_to_location(&mut lines),
-    };
-}
-
-fn read_seeds(lines: &mut Lines) -> Vec<u64> {
-    let seeds_line = lines.next().unwrap();
-    assert!(seeds_line.starts_with("seeds: "));
-
-    seeds_line[7..]
-        .split_ascii_whitespace()
-        .map(|x| x.trim().parse().unwrap())
-        .collect()
-}
-
-fn read_seed_to_soil(lines: &mut Lines) -> BTreeMap<u64, (u64, u64)> {
-    read_label(lines, "seed-to-soil map:");
-    read_map(lines)
-}
-
-fn read_soil_to_fertilizer(lines: &mut Lines) -> BTreeMap<u64, (u64, u64)> {
-    read_label(lines, "soil-to-fertilizer map:");
-    read_map(lines)
-}
-fn read_fertilizer_to_water(lines: &mut Lines) -> BTreeMap<u64, (u64, u64)> {
-    read_label(lines, "fertilizer-to-water map:");
-    read_map(lines)
-}
+pub fn parse_input(input: impl AsRef<str>) -> Result<Input, Box<dyn Error>> {
+    let mut parsed = Input::default();
 
-fn read_water_to_light(lines: &mut Lines) -> BTreeMap<u64, (u64, u64)> {
-    read_label(lines, "water-to-light map:");
-    read_map(lines)
-}
-
-fn read_light_to_temp(lines: &mut Lines) -> BTreeMap<u64, (u64, u64)> {
-    read_label(lines, "light-to-temperature map:");
-    read_map(lines)
-}
-
-fn read_temp_to_humidity(lines: &mut Lines) -> BTreeMap<u64, (u64, u64)> {
-    read_label(lines, "temperature-to-humidity map:");
-    read_map(lines)
-}
-fn read_humidity_to_location(lines: &mut Lines) -> BTreeMap<u64, (u64, u64)> {
-    read_label(lines, "humidity-to-location map:");
-    read_map(lines)
-}
-
-fn read_label(lines: &mut Lines, label: &str) {
-    while let Some(line) = lines.next() {
-        if line.is_empty() {
+    for (header, body) in sections(input.as_ref()) {
+        if let Some(seeds) = header.strip_prefix("seeds: ") {
+            parsed.seeds = seeds
+                .split_ascii_whitespace()
+                .map(|x| x.parse())
+                .collect::<Result<_, _>>()?;
             continue;
         }
 
-        assert_eq!(label, line.trim());
-        return;
+        let map = match header {
+            "seed-to-soil map:" => &mut parsed.seed_to_soil,
+            "soil-to-fertilizer map:" => &mut parsed.soil_to_fertilizer,
+            "fertilizer-to-water map:" => &mut parsed.fertilizer_to_water,
+            "water-to-light map:" => &mut parsed.water_to_light,
+            "light-to-temperature map:" => &mut parsed.light_to_temp,
+            "temperature-to-humidity map:" => &mut parsed.temp_to_humidity,
+            "humidity-to-location map:" => &mut parsed.humidity_to_location,
+            _ => return Err(format!("unknown section: {:?}", header).into()),
+        };
+
+        *map = body
+            .try_parse_with(read_map)
+            .map_err(ParseError::into_boxed)?;
     }
 
-    panic!("end of input")
+    Ok(parsed)
 }
 
-fn read_map(lines: &mut Lines) -> BTreeMap<u64, (u64, u64)> {
+fn read_map(block: Block) -> Result<BTreeMap<u64, (u64, u64)>, Box<dyn Error>> {
     let mut map = BTreeMap::new();
 
-    while let Some(line) = lines.next() {
-        if line.is_empty() {
-            return map;
-        }
-
-        let (dest, rest) = line.split_once(' ').unwrap();
-        let (src, len) = rest.split_once(' ').unwrap();
+    for line in block.lines() {
+        let values: Vec<&str> = line.split_ascii_whitespace().collect();
+        let [dest, src, len] = values[..] else {
+            return Err(format!("invalid range: {:?}", line).into());
+        };
 
-        map.insert(
-            src.trim().parse().unwrap(),
-            (dest.trim().parse().unwrap(), len.trim().parse().unwrap()),
-        );
+        map.insert(src.parse()?, (dest.parse()?, len.parse()?));
     }
 
-    map
+    Ok(map)
 }
 
 pub fn part_one(input: &Input) -> u64 {
@@ -328,7 +285,7 @@ mod tests {
     #[test]
     fn test_part_one() {
         let input = load_text_input_from_file("inputs/input.txt");
-        let input = parse_input(input);
+        let input = parse_input(input).unwrap();
 
         let answer = part_one(&input);
         assert_eq!(551_761_867, answer);
@@ -337,7 +294,7 @@ mod tests {
     #[test]
     fn test_part_two_v1() {
         let input = load_text_input_from_file("inputs/input.txt");
-        let input = parse_input(input);
+        let input = parse_input(input).unwrap();
 
         let answer = part_two_v1(&input);
         assert_eq!(57_451_709, answer);
@@ -346,7 +303,7 @@ mod tests {
     #[test]
     fn test_part_two_v2() {
         let input = load_text_input_from_file("inputs/input.txt");
-        let input = parse_input(input);
+        let input = parse_input(input).unwrap();
 
         let answer = part_two_v2(&input);
         assert_eq!(57_451_709, answer);
@@ -355,7 +312,7 @@ mod tests {
     #[test]
     fn test_part_two_v3() {
         let input = load_text_input_from_file("inputs/input.txt");
-        let input = parse_input(input);
+        let input = parse_input(input).unwrap();
 
         let answer = part_two_v3(&input);
         assert_eq!(57_451_709, answer);
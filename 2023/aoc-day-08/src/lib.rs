@@ -2,7 +2,7 @@ use std::error::Error;
 use std::str::FromStr;
 
 use aoc_shared::hashing::FxHashMap;
-use num::Integer;
+use aoc_shared::math::lcm_all;
 
 type HashMap<K, V> = FxHashMap<K, V>;
 
@@ -69,13 +69,14 @@ pub fn part_two(input: &Input) -> u64 {
     // without passing through any other nodes ending with `Z`. The length
     // of that cycle appears to be the same length as the path from an A node
     // to the corresponding Z node
-    input
+    let steps = input
         .map
         .keys()
         .filter(|k| k.ends_with('A'))
         .copied()
-        .map(|key| solve(input, key, |node| node.ends_with('Z')))
-        .fold(1, |acc, val| acc.lcm(&val))
+        .map(|key| solve(input, key, |node| node.ends_with('Z')));
+
+    lcm_all(steps).unwrap()
 }
 
 fn solve(input: &Input, start: &str, finish: impl Fn(&str) -> bool) -> u64 {
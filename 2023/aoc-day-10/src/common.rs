@@ -25,10 +25,6 @@ impl Direction {
         }
     }
 
-    pub fn normal(self) -> Self {
-        self.rotr()
-    }
-
     pub fn rotr(self) -> Self {
         match self {
             Direction::Up => Direction::Right,
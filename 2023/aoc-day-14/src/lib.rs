@@ -50,4 +50,14 @@ mod tests {
         let answer = part_two_v2(input.as_bytes());
         assert_eq!(118_747, answer);
     }
+
+    #[test]
+    fn test_example() {
+        let input = "O....#....\nO.OO#....#\n.....##...\nOO.#O....O\n.O.....O#.\n\
+                     O.#..O.#.#\n..O..#O..O\n.......O..\n#....###..\n#OO..#....\n";
+
+        assert_eq!(136, part_one(&parse_u8_grid(input)));
+        assert_eq!(64, part_two_v1(&parse_u8_grid(input)));
+        assert_eq!(64, part_two_v2(input.as_bytes()));
+    }
 }
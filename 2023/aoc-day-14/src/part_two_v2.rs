@@ -1,10 +1,8 @@
-use aoc_shared::hashing::FxHashMap;
+use aoc_shared::algo::cycle::find_by_key;
 
 use crate::{EMPTY, FIXED, OVAL};
 
-type HashMap<K, V> = FxHashMap<K, V>;
-
-const TOTAL_CYCLES: u32 = 1_000_000_000;
+const TOTAL_CYCLES: usize = 1_000_000_000;
 
 pub fn part_two(input: &[u8]) -> usize {
     let mut grid = input.to_vec();
@@ -15,22 +13,10 @@ pub fn part_two(input: &[u8]) -> usize {
     let cols = grid.iter().position(|&x| x == b'\n').unwrap() + 1;
     let rows = grid.len() / cols;
 
-    let mut seen = HashMap::default();
-    seen.insert(identity(&grid), 0);
-
-    let mut cycle_start = 0;
-    let mut cycle_len = 0;
-
-    for c in 0..TOTAL_CYCLES {
-        cycle(&mut grid, rows, cols);
-        if let Some(prev) = seen.insert(identity(&grid), c + 1) {
-            cycle_start = prev;
-            cycle_len = c - prev + 1;
-            break;
-        }
-    }
+    let period = find_by_key(&mut grid, |g| cycle(g, rows, cols), |g| identity(g));
 
-    for _ in 0..(TOTAL_CYCLES - cycle_start) % cycle_len {
+    let current = period.mu + period.lambda;
+    for _ in 0..period.remaining(current, TOTAL_CYCLES) {
         cycle(&mut grid, rows, cols);
     }
 
@@ -1,4 +1,4 @@
-use rayon::prelude::*;
+use aoc_shared::parallel;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum Direction {
@@ -52,29 +52,27 @@ pub fn part_two(input: &[u8]) -> usize {
     answer
 }
 
-pub fn part_two_rayon(input: &[u8]) -> usize {
+pub fn part_two_parallel(input: &[u8]) -> usize {
     let cols = input.iter().position(|&x| x == b'\n').unwrap() + 1;
     let rows = input.len() / cols;
 
-    (0..rows)
-        .par_bridge()
-        .map(|r| energize(input, rows, cols, r, 0, Direction::Right))
-        .chain(
-            (0..rows)
-                .par_bridge()
-                .map(|r| energize(input, rows, cols, r, cols - 2, Direction::Left)),
-        )
-        .chain(
-            (0..cols - 1)
-                .par_bridge()
-                .map(|c| energize(input, rows, cols, 0, c, Direction::Down)),
-        )
-        .chain(
-            (0..cols - 1)
-                .par_bridge()
-                .map(|c| energize(input, rows, cols, rows - 1, c, Direction::Up)),
-        )
-        .reduce(|| 0, |a, b| a.max(b))
+    let mut starts = Vec::with_capacity(2 * (rows + cols));
+    for r in 0..rows {
+        starts.push((r, 0, Direction::Right));
+        starts.push((r, cols - 2, Direction::Left));
+    }
+
+    for c in 0..cols - 1 {
+        starts.push((0, c, Direction::Down));
+        starts.push((rows - 1, c, Direction::Up));
+    }
+
+    parallel::map_reduce(
+        &starts,
+        |&(r, c, d)| energize(input, rows, cols, r, c, d),
+        usize::max,
+    )
+    .unwrap_or(0)
 }
 
 fn energize(input: &[u8], rows: usize, cols: usize, r: usize, c: usize, d: Direction) -> usize {
@@ -174,10 +172,10 @@ mod tests {
     }
 
     #[test]
-    fn test_part_two_rayon() {
+    fn test_part_two_parallel() {
         let input = load_text_input_from_file("inputs/input.txt");
 
-        let answer = part_two_rayon(input.as_bytes());
+        let answer = part_two_parallel(input.as_bytes());
         assert_eq!(8_221, answer);
     }
 }
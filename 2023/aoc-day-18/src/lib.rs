@@ -1,29 +1,27 @@
-use aoc_shared::grid::Point;
+use aoc_shared::grid::{Direction, Point, Polygon};
 
 pub fn part_one(input: &str) -> i64 {
-    let mut p = Point::ZERO;
-    let mut area = 0;
-
-    // Shoelace formula + Pick's theorem
-    for line in input.lines() {
+    let steps = input.lines().map(|line| {
         let amount = &line[2..line.len() - 10];
         let amount = amount.parse::<i64>().unwrap();
-        let direction = Point::from_direction(&line[..1]).unwrap();
 
-        let q = p + direction * amount;
-        area += (p.x * q.y - p.y * q.x) + amount;
-        p = q;
-    }
+        let direction = match &line[..1] {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            _ => panic!("invalid direction: {:?}", &line[..1]),
+        };
+
+        (direction, amount)
+    });
 
-    area.abs() / 2 + 1
+    // Shoelace formula + Pick's theorem
+    Polygon::from_walk(Point::ZERO, steps).lattice_points() as i64
 }
 
 pub fn part_two(input: &str) -> i64 {
-    let mut p = Point::ZERO;
-    let mut area = 0;
-
-    // Shoelace formula + Pick's theorem
-    for line in input.lines() {
+    let steps = input.lines().map(|line| {
         let config = &line[line.len() - 7..line.len() - 1];
         let config = config.as_bytes();
 
@@ -34,19 +32,18 @@ pub fn part_two(input: &str) -> i64 {
             | hex(config[4]) << 0;
 
         let direction = match config[5] - b'0' {
-            0 => Point::RIGHT,
-            1 => Point::DOWN,
-            2 => Point::LEFT,
-            3 => Point::UP,
+            0 => Direction::Right,
+            1 => Direction::Down,
+            2 => Direction::Left,
+            3 => Direction::Up,
             _ => panic!("invalid direction: {:?}", config[5]),
         };
 
-        let q = p + direction * amount;
-        area += (p.x * q.y - p.y * q.x) + amount;
-        p = q;
-    }
+        (direction, amount)
+    });
 
-    area.abs() / 2 + 1
+    // Shoelace formula + Pick's theorem
+    Polygon::from_walk(Point::ZERO, steps).lattice_points() as i64
 }
 
 fn hex(x: u8) -> i64 {
@@ -1,6 +1,7 @@
 use std::error::Error;
 use std::fmt::Debug;
 
+use aoc_shared::blocks::try_split_blocks;
 use aoc_shared::hashing::{FxHashBuilder, FxHashMap};
 
 type HashMap<K, V> = FxHashMap<K, V>;
@@ -220,14 +221,10 @@ pub fn parse_input(input: &str, parse_data: bool) -> Result<(RuleSet, Vec<Xmas>)
     rule_names.insert(START_NODE, 0);
     rules.rules.push(Rule::default());
 
-    let mut lines = input.lines();
+    let [rule_lines, data_lines] = try_split_blocks(input)?;
 
     // parse the rules
-    while let Some(line) = lines.next() {
-        if line.is_empty() {
-            break;
-        }
-
+    for line in rule_lines.lines() {
         let Some((name, text)) = line.split_once('{') else {
             return Err(format!("Invalid rule: {:?}", line).into());
         };
@@ -282,7 +279,7 @@ pub fn parse_input(input: &str, parse_data: bool) -> Result<(RuleSet, Vec<Xmas>)
     if parse_data {
         data.reserve(DATA_CAPACITY);
 
-        while let Some(line) = lines.next() {
+        for line in data_lines.lines() {
             let mut xmas = Xmas::default();
 
             for part in line[1..line.len() - 1].split(',') {
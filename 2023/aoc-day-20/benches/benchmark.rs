@@ -1,4 +1,4 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{criterion_group, criterion_main, Criterion};
 
 use aoc_day_20::{v1, v2};
@@ -14,7 +14,7 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2023, 20).load().unwrap();
 
     c.bench_function("parsing/v1", |b| {
         b.iter(|| v1::load_graph(&input));
@@ -26,7 +26,7 @@ fn benchmark_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_one_v1(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2023, 20).load().unwrap();
     let graph = v1::load_graph(&input);
 
     c.bench_function("part-1/v1", |b| {
@@ -35,7 +35,7 @@ fn benchmark_part_one_v1(c: &mut Criterion) {
 }
 
 fn benchmark_part_one_v2(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2023, 20).load().unwrap();
     let graph = v2::load_graph(&input);
 
     c.bench_function("part-1/v2", |b| {
@@ -44,7 +44,7 @@ fn benchmark_part_one_v2(c: &mut Criterion) {
 }
 
 fn benchmark_part_two_v1(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2023, 20).load().unwrap();
     let graph = v1::load_graph(&input);
 
     c.bench_function("part-2/v1", |b| {
@@ -53,7 +53,7 @@ fn benchmark_part_two_v1(c: &mut Criterion) {
 }
 
 fn benchmark_part_two_v2(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2023, 20).load().unwrap();
     let graph = v2::load_graph(&input);
 
     c.bench_function("part-2/v2", |b| {
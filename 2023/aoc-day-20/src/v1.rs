@@ -1,7 +1,7 @@
 use std::collections::VecDeque;
 
 use aoc_shared::hashing::FxHashMap;
-use num::Integer;
+use aoc_shared::math::lcm;
 
 type HashMap<K, V> = FxHashMap<K, V>;
 
@@ -133,7 +133,7 @@ pub fn part_two(graph: &HashMap<&str, (Kind, Vec<&str>)>) -> u64 {
             while let Some((src, dst, pulse)) = queue.pop_front() {
                 if final_node == dst {
                     if pulse == Pulse::High {
-                        answer = answer.lcm(&button_presses);
+                        answer = lcm(answer, button_presses).unwrap();
                         queue.clear();
                         continue 'next_node;
                     }
@@ -1,7 +1,7 @@
 use std::collections::VecDeque;
 
 use aoc_shared::hashing::FxHashMap;
-use num::Integer;
+use aoc_shared::math::lcm;
 
 type HashMap<K, V> = FxHashMap<K, V>;
 
@@ -223,7 +223,7 @@ pub fn part_two(graph: &[(Kind, Vec<usize>)]) -> u64 {
         }
 
         queue.clear();
-        answer = answer.lcm(&presses);
+        answer = lcm(answer, presses).unwrap();
     }
 
     answer
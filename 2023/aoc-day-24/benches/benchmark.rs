@@ -1,13 +1,18 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{criterion_group, criterion_main, Criterion};
 
-use aoc_day_24::{parse_input, part_one};
+use aoc_day_24::{parse_input, part_one, part_two};
 
-criterion_group!(benches, benchmark_parsing, benchmark_part_one,);
+criterion_group!(
+    benches,
+    benchmark_parsing,
+    benchmark_part_one,
+    benchmark_part_two
+);
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2023, 24).load().unwrap();
 
     c.bench_function("parsing", |b| {
         b.iter(|| parse_input(&input));
@@ -15,10 +20,19 @@ fn benchmark_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2023, 24).load().unwrap();
     let parsed = parse_input(&input);
 
     c.bench_function("part-1", |b| {
         b.iter(|| part_one(&parsed));
     });
 }
+
+fn benchmark_part_two(c: &mut Criterion) {
+    let input = input_locator!(2023, 24).load().unwrap();
+    let parsed = parse_input(&input);
+
+    c.bench_function("part-2", |b| {
+        b.iter(|| part_two(&parsed));
+    });
+}
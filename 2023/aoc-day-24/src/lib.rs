@@ -1,5 +1,4 @@
-use z3::ast::{Ast, Int};
-use z3::{Config, Context, Solver};
+use aoc_shared::linalg::{self, Rational, Solution};
 
 #[derive(Debug, Clone)]
 pub struct X3D {
@@ -38,8 +37,8 @@ fn parse_x3d(line: &str) -> X3D {
 }
 
 pub fn part_one(input: impl AsRef<[HailStone]>) -> usize {
-    const MIN: f64 = 200_000_000_000_000.0;
-    const MAX: f64 = 400_000_000_000_000.0;
+    const MIN: Rational = Rational::integer(200_000_000_000_000);
+    const MAX: Rational = Rational::integer(400_000_000_000_000);
 
     let input = input.as_ref();
     let mut intersections = 0;
@@ -47,7 +46,11 @@ pub fn part_one(input: impl AsRef<[HailStone]>) -> usize {
     for i in 0..input.len() {
         for j in i + 1..input.len() {
             if let Some(((x, y), (t0, t1))) = intersects_xy(&input[i], &input[j]) {
-                if (MIN..=MAX).contains(&x) && (MIN..=MAX).contains(&y) && t0 >= 0.0 && t1 >= 0.0 {
+                if (MIN..=MAX).contains(&x)
+                    && (MIN..=MAX).contains(&y)
+                    && t0 >= Rational::ZERO
+                    && t1 >= Rational::ZERO
+                {
                     intersections += 1;
                 }
             }
@@ -57,69 +60,149 @@ pub fn part_one(input: impl AsRef<[HailStone]>) -> usize {
     intersections
 }
 
+#[allow(clippy::type_complexity)]
 fn intersects_xy(
     a: &HailStone,
     b: &HailStone,
-) -> Option<(/* XY */ (f64, f64), /* Time */ (f64, f64))> {
+) -> Option<(
+    /* XY */ (Rational, Rational),
+    /* Time */ (Rational, Rational),
+)> {
     let (x0, y0, vx0, vy0) = (a.position.x, a.position.y, a.velocity.x, a.velocity.y);
     let (x1, y1, vx1, vy1) = (b.position.x, b.position.y, b.velocity.x, b.velocity.y);
 
-    let tn = (y0 - y1) * vx1 - (x0 - x1) * vy1;
-    let un = (y0 - y1) * vx0 - (x0 - x1) * vy0;
+    // x0 + vx0 * t0 = x1 + vx1 * t1
+    // y0 + vy0 * t0 = y1 + vy1 * t1
+    let coefficients = vec![
+        vec![Rational::from(vx0), Rational::from(-vx1)],
+        vec![Rational::from(vy0), Rational::from(-vy1)],
+    ];
+    let rhs = vec![Rational::from(x1 - x0), Rational::from(y1 - y0)];
 
-    let d = vx0 * vy1 - vy0 * vx1;
-    if d == 0 {
+    // Parallel paths never cross, and there are no overlapping paths in the input
+    let Solution::Unique(t) = linalg::solve(coefficients, rhs) else {
         return None;
-    }
+    };
 
-    let x = (x0 as f64) + (vx0 as f64 / d as f64) * tn as f64;
-    let y = (y0 as f64) + (vy0 as f64 / d as f64) * tn as f64;
+    let x = Rational::from(x0) + Rational::from(vx0) * t[0];
+    let y = Rational::from(y0) + Rational::from(vy0) * t[0];
 
-    Some(((x, y), (tn as f64 / d as f64, un as f64 / d as f64)))
+    Some(((x, y), (t[0], t[1])))
 }
 
-// solution from: https://github.com/arthomnix/aoc23/blob/master/src/days/day24.rs
 pub fn part_two(input: impl AsRef<[HailStone]>) -> i64 {
     let hailstones = input.as_ref();
 
-    let cfg = Config::new();
-    let ctx = Context::new(&cfg);
-    let solver = Solver::new(&ctx);
+    // For the rock (P, V) and each hailstone (p, v) there is some time `t`, such that:
+    // P + V * t = p + v * t, i.e. (P - p) = -t * (V - v), thus (P - p) x (V - v) = 0.
+    // Expanding the cross product gives P x V - P x v - p x V + p x v = 0, where the
+    // non-linear term P x V is the same for all hailstones. By subtracting the equations
+    // for two hailstones `i` and `j` we get a system of 3 linear equations:
+    // P x (v_i - v_j) + (p_i - p_j) x V = p_i x v_i - p_j x v_j
+    //
+    // Solving all 6 unknowns at once overflows even an i128 fraction, so first we use
+    // the Z component of the equations of 4 pairs to find Px, Py, Vx and Vy, and then
+    // the Y component of the equations of 2 pairs to find the remaining Pz and Vz.
+    //
+    // Some pairs don't help, e.g. those of parallel hailstones, so when a group of
+    // pairs doesn't determine the unknowns, the next group is tried.
+    let equations: Vec<_> = hailstones
+        .windows(2)
+        .map(|w| equation(&w[0], &w[1]))
+        .collect();
+
+    // (v_i - v_j).y * Px - (v_i - v_j).x * Py - (p_i - p_j).y * Vx + (p_i - p_j).x * Vy = r.z
+    let xy = equations
+        .windows(4)
+        .find_map(|group| {
+            let (coefficients, rhs) = group
+                .iter()
+                .map(|(d, e, r)| (vec![d.y, -d.x, -e.y, e.x], r.z))
+                .unzip();
+
+            match linalg::solve(to_rational(coefficients), to_rational_vec(rhs)) {
+                Solution::Unique(xy) => Some(xy),
+                _ => None,
+            }
+        })
+        .expect("the rock's trajectory is not uniquely determined by the input");
+    let (px, py, vx) = (xy[0], xy[1], xy[2]);
+
+    // -(v_i - v_j).z * Px + (v_i - v_j).x * Pz + (p_i - p_j).z * Vx - (p_i - p_j).x * Vz = r.y
+    let z = equations
+        .windows(2)
+        .find_map(|group| {
+            let (coefficients, rhs) = group
+                .iter()
+                .map(|(d, e, r)| {
+                    let rhs = Rational::integer(r.y) + Rational::integer(d.z) * px
+                        - Rational::integer(e.z) * vx;
+                    (vec![d.x, -e.x], rhs)
+                })
+                .unzip();
+
+            match linalg::solve(to_rational(coefficients), rhs) {
+                Solution::Unique(z) => Some(z),
+                _ => None,
+            }
+        })
+        .expect("the rock's trajectory is not uniquely determined by the input");
+    let pz = z[0];
 
-    // positions
-    let px = Int::new_const(&ctx, "px");
-    let py = Int::new_const(&ctx, "py");
-    let pz = Int::new_const(&ctx, "pz");
+    (px + py + pz)
+        .to_integer()
+        .expect("the rock must start at integer coordinates") as i64
+}
 
-    // velocities
-    let vx = Int::new_const(&ctx, "vx");
-    let vy = Int::new_const(&ctx, "vy");
-    let vz = Int::new_const(&ctx, "vz");
+// Returns (v_i - v_j, p_i - p_j, p_i x v_i - p_j x v_j)
+fn equation(a: &HailStone, b: &HailStone) -> (Wide, Wide, Wide) {
+    let (pa, va) = (Wide::from(&a.position), Wide::from(&a.velocity));
+    let (pb, vb) = (Wide::from(&b.position), Wide::from(&b.velocity));
 
-    for hailstone in hailstones.iter() {
-        let pxn = Int::from_i64(&ctx, hailstone.position.x);
-        let pyn = Int::from_i64(&ctx, hailstone.position.y);
-        let pzn = Int::from_i64(&ctx, hailstone.position.z);
+    (va.sub(&vb), pa.sub(&pb), pa.cross(&va).sub(&pb.cross(&vb)))
+}
 
-        let vxn = Int::from_i64(&ctx, hailstone.velocity.x);
-        let vyn = Int::from_i64(&ctx, hailstone.velocity.y);
-        let vzn = Int::from_i64(&ctx, hailstone.velocity.z);
+fn to_rational(matrix: Vec<Vec<i128>>) -> Vec<Vec<Rational>> {
+    matrix.into_iter().map(to_rational_vec).collect()
+}
 
-        let tn = Int::fresh_const(&ctx, "t");
+fn to_rational_vec(values: Vec<i128>) -> Vec<Rational> {
+    values.into_iter().map(Rational::integer).collect()
+}
 
-        solver.assert(&(&pxn + &vxn * &tn)._eq(&(&px + &vx * &tn)));
-        solver.assert(&(&pyn + &vyn * &tn)._eq(&(&py + &vy * &tn)));
-        solver.assert(&(&pzn + &vzn * &tn)._eq(&(&pz + &vz * &tn)));
-    }
+// The cross products do not fit in an i64
+struct Wide {
+    x: i128,
+    y: i128,
+    z: i128,
+}
 
-    solver.check();
+impl Wide {
+    fn sub(&self, other: &Wide) -> Wide {
+        Wide {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
 
-    let model = solver.get_model().unwrap();
-    let x = model.get_const_interp(&px).unwrap().as_i64().unwrap();
-    let y = model.get_const_interp(&py).unwrap().as_i64().unwrap();
-    let z = model.get_const_interp(&pz).unwrap().as_i64().unwrap();
+    fn cross(&self, other: &Wide) -> Wide {
+        Wide {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+}
 
-    x + y + z
+impl From<&X3D> for Wide {
+    fn from(value: &X3D) -> Self {
+        Wide {
+            x: value.x as i128,
+            y: value.y as i128,
+            z: value.z as i128,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -145,4 +228,20 @@ mod tests {
         let answer = part_two(&parsed);
         assert_eq!(888_708_704_663_413, answer);
     }
+
+    #[test]
+    fn test_part_two_skips_degenerate_pairs() {
+        const EXAMPLE: &str = "19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3
+";
+        assert_eq!(47, part_two(parse_input(EXAMPLE)));
+
+        // the pairs of the same hailstone give only zeros
+        let first = EXAMPLE.lines().next().unwrap();
+        let input = format!("{}\n", first).repeat(3) + EXAMPLE;
+        assert_eq!(47, part_two(parse_input(&input)));
+    }
 }
@@ -1,13 +1,13 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{criterion_group, criterion_main, Criterion};
 
-use aoc_day_25::{parse_input, v2, v3};
+use aoc_day_25::{parse_input, v2, v3, v4, v5};
 
 criterion_group!(benches, benchmark_parsing, benchmark_part_one);
 criterion_main!(benches);
 
 fn benchmark_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2023, 25).load().unwrap();
 
     c.bench_function("parsing", |b| {
         b.iter(|| parse_input(&input));
@@ -15,13 +15,21 @@ fn benchmark_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2023, 25).load().unwrap();
 
     c.bench_function("v2 (bfs)", |b| {
         b.iter(|| v2::part_one(&input));
     });
 
-    c.bench_function("v3 (Karger)", |b| {
+    c.bench_function("v3 (Karger-Stein)", |b| {
         b.iter(|| v3::part_one(&input));
     });
+
+    c.bench_function("v4 (Stoer-Wagner)", |b| {
+        b.iter(|| v4::part_one(&input));
+    });
+
+    c.bench_function("v5 (max-flow)", |b| {
+        b.iter(|| v5::part_one(&input));
+    });
 }
@@ -5,6 +5,8 @@ type HashMap<K, V> = FxHashMap<K, V>;
 pub mod v1;
 pub mod v2;
 pub mod v3;
+pub mod v4;
+pub mod v5;
 
 pub fn parse_input(input: &str) -> (Vec<Vec<usize>>, Vec<(usize, usize)>) {
     let mut labels = HashMap::default();
@@ -1,3 +1,4 @@
+use aoc_shared::algo::bridges;
 use rand::prelude::*;
 
 use crate::{count_reachable, parse_input};
@@ -25,7 +26,7 @@ pub fn part_one(input: &str) -> usize {
             graph[q].retain(|&x| x != p);
 
             // If we find a third bridge, then we know the first and second edges we removed are correct
-            if let Some((m, n)) = critical_connection(&graph) {
+            if let Some(&(m, n)) = bridges(&graph).first() {
                 // remove the third connection
                 graph[m].retain(|&x| x != n);
                 graph[n].retain(|&x| x != m);
@@ -48,53 +49,6 @@ pub fn part_one(input: &str) -> usize {
     panic!("failed to find solution")
 }
 
-pub fn critical_connection(graph: &[Vec<usize>]) -> Option<(usize, usize)> {
-    let mut discovery = vec![0; graph.len()];
-    let mut earliest = vec![0; graph.len()];
-
-    dfs(&graph, &mut discovery, &mut earliest, 1, graph.len(), 0)
-}
-
-fn dfs(
-    graph: &[Vec<usize>],
-    discovery: &mut [usize],
-    earliest: &mut [usize],
-    counter: usize,
-    parent: usize,
-    node: usize,
-) -> Option<(usize, usize)> {
-    // Add a guard to avoid visiting a node for a second time
-    // if discovery[node] != 0 {
-    //     return;
-    // }
-
-    discovery[node] = counter;
-    earliest[node] = counter;
-
-    for &child in graph[node].iter() {
-        if child == parent {
-            continue;
-        }
-
-        // a discovery time of 0, means that the node has not been visited yet
-        if discovery[child] == 0 {
-            let bridge = dfs(graph, discovery, earliest, counter + 1, node, child);
-            if bridge.is_some() {
-                return bridge;
-            }
-
-            earliest[node] = earliest[node].min(earliest[child]);
-            if discovery[node] < earliest[child] {
-                return Some((node, child));
-            }
-        } else {
-            earliest[node] = earliest[node].min(discovery[child]);
-        }
-    }
-
-    None
-}
-
 #[cfg(test)]
 mod tests {
     use aoc_shared::input::load_text_input_from_file;
@@ -1,42 +1,23 @@
-use aoc_shared::algo::UnionFind;
-use rand::prelude::*;
-use rand::thread_rng;
+use aoc_shared::algo::karger_stein;
 
 use crate::parse_input;
 
-const EXPECTED_CUTS: i32 = 3;
+const EXPECTED_CUTS: usize = 3;
 
 pub fn part_one(input: &str) -> usize {
-    let (graph, mut connections) = parse_input(input);
-    let mut uf = UnionFind::new(graph.len());
-
-    loop {
-        // shuffle in order to be able to easily choose a "random" edge
-        connections.shuffle(&mut thread_rng());
-        let mut edges = connections.as_slice();
-        uf.reset();
-
-        while uf.number_of_groups() > 2 {
-            let (a, b) = edges[0];
-            edges = &edges[1..];
-
-            uf.union(a, b);
-        }
-
-        let mut cuts = 0;
-        for &(a, b) in edges.iter() {
-            if uf.find(a) != uf.find(b) {
-                cuts += 1;
-            }
-        }
-
-        if cuts == EXPECTED_CUTS {
-            let root = uf.find(0);
-            let size = uf.group_size(root);
-
-            return (graph.len() - size) * size;
+    let (graph, _) = parse_input(input);
+
+    // Karger-Stein finds the minimum cut only with some probability,
+    // so keep trying with different seeds until we find the 3 wires
+    for seed in 0.. {
+        let cut = karger_stein(&graph, 1, seed).unwrap();
+        if cut.size() == EXPECTED_CUTS {
+            let (a, b) = cut.partition_sizes();
+            return a * b;
         }
     }
+
+    unreachable!()
 }
 
 #[cfg(test)]
@@ -0,0 +1,28 @@
+use aoc_shared::algo::stoer_wagner;
+
+use crate::parse_input;
+
+pub fn part_one(input: &str) -> usize {
+    let (graph, _) = parse_input(input);
+
+    let cut = stoer_wagner(&graph).unwrap();
+    debug_assert_eq!(3, cut.size());
+
+    let (a, b) = cut.partition_sizes();
+    a * b
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_shared::input::load_text_input_from_file;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = load_text_input_from_file("inputs/input.txt");
+
+        let answer = part_one(&input);
+        assert_eq!(580_800, answer);
+    }
+}
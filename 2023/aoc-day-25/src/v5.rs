@@ -0,0 +1,43 @@
+use aoc_shared::algo::FlowNetwork;
+
+use crate::parse_input;
+
+const EXPECTED_CUTS: u64 = 3;
+
+/*
+   Each wire has a capacity of 1, so the max flow between two nodes is the number of wires
+   that have to be cut to separate them. Node 0 is on one side of the cut, so there must be
+   a node on the other side, for which the max flow is exactly 3.
+*/
+
+pub fn part_one(input: &str) -> usize {
+    let (graph, _) = parse_input(input);
+    let mut network = FlowNetwork::from_undirected(&graph);
+
+    for sink in 1..graph.len() {
+        network.reset();
+
+        // No need to compute the full flow for the nodes on the same side as node 0
+        if network.max_flow_with_limit(0, sink, EXPECTED_CUTS + 1) == EXPECTED_CUTS {
+            let size = network.reachable(0).into_iter().filter(|&x| x).count();
+            return size * (graph.len() - size);
+        }
+    }
+
+    panic!("failed to find solution")
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_shared::input::load_text_input_from_file;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = load_text_input_from_file("inputs/input.txt");
+
+        let answer = part_one(&input);
+        assert_eq!(580_800, answer);
+    }
+}
@@ -0,0 +1,160 @@
+const UNVISITED: usize = usize::MAX;
+
+/// Finds all bridges of an undirected graph given as adjacency lists, where
+/// each edge is present in the lists of both of its nodes. A bridge is an edge
+/// whose removal disconnects the graph. Each bridge is reported once, with the
+/// smaller node first. Parallel edges are never bridges.
+pub fn bridges(graph: &[Vec<usize>]) -> Vec<(usize, usize)> {
+    let mut result = vec![];
+
+    tarjan(graph, |parent, node, _, low, disc| {
+        if low[node] > disc[parent] {
+            result.push((parent.min(node), parent.max(node)));
+        }
+    });
+
+    result.sort_unstable();
+    result
+}
+
+/// Finds all articulation points (cut vertices) of an undirected graph given
+/// as adjacency lists. An articulation point is a node whose removal increases
+/// the number of connected components. The result is sorted.
+pub fn articulation_points(graph: &[Vec<usize>]) -> Vec<usize> {
+    let mut is_cut = vec![false; graph.len()];
+    let mut root_children = vec![0usize; graph.len()];
+
+    tarjan(graph, |parent, node, parent_is_root, low, disc| {
+        if parent_is_root {
+            root_children[parent] += 1;
+        } else if low[node] >= disc[parent] {
+            is_cut[parent] = true;
+        }
+    });
+
+    // A DFS root is an articulation point only if it has more than one child
+    for (node, &children) in root_children.iter().enumerate() {
+        if children > 1 {
+            is_cut[node] = true;
+        }
+    }
+
+    (0..graph.len()).filter(|&x| is_cut[x]).collect()
+}
+
+// Iterative Tarjan's DFS. The callback is invoked with `(parent, node, parent_is_root,
+// low, disc)` every time the DFS returns from `node` to `parent` along a tree edge.
+fn tarjan<F>(graph: &[Vec<usize>], mut on_tree_edge: F)
+where
+    F: FnMut(usize, usize, bool, &[usize], &[usize]),
+{
+    let n = graph.len();
+    let mut disc = vec![UNVISITED; n];
+    let mut low = vec![UNVISITED; n];
+
+    // (node, parent, index of the next neighbour, parent edge already skipped)
+    let mut stack: Vec<(usize, usize, usize, bool)> = vec![];
+
+    let mut time = 0;
+    for root in 0..n {
+        if disc[root] != UNVISITED {
+            continue;
+        }
+
+        disc[root] = time;
+        low[root] = time;
+        stack.push((root, UNVISITED, 0, false));
+
+        while let Some(top) = stack.last_mut() {
+            let (node, parent, idx, skipped) = *top;
+
+            if idx < graph[node].len() {
+                top.2 += 1;
+                let next = graph[node][idx];
+
+                // Skip the edge leading back to the parent only once,
+                // so that parallel edges are treated as a cycle
+                if next == parent && !skipped {
+                    top.3 = true;
+                    continue;
+                }
+
+                if disc[next] == UNVISITED {
+                    time += 1;
+                    disc[next] = time;
+                    low[next] = time;
+                    stack.push((next, node, 0, false));
+                } else {
+                    low[node] = low[node].min(disc[next]);
+                }
+
+                continue;
+            }
+
+            stack.pop();
+            if parent != UNVISITED {
+                low[parent] = low[parent].min(low[node]);
+                on_tree_edge(parent, node, parent == root, &low, &disc);
+            }
+        }
+
+        time += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn undirected(n: usize, edges: &[(usize, usize)]) -> Vec<Vec<usize>> {
+        let mut graph = vec![vec![]; n];
+        for &(a, b) in edges {
+            graph[a].push(b);
+            graph[b].push(a);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_bridges() {
+        // Two triangles connected by the bridge 2-3, plus a dangling node 6
+        let graph = undirected(
+            7,
+            &[
+                (0, 1),
+                (1, 2),
+                (2, 0),
+                (2, 3),
+                (3, 4),
+                (4, 5),
+                (5, 3),
+                (5, 6),
+            ],
+        );
+
+        assert_eq!(vec![(2, 3), (5, 6)], bridges(&graph));
+        assert_eq!(vec![2, 3, 5], articulation_points(&graph));
+    }
+
+    #[test]
+    fn test_parallel_edges() {
+        let graph = undirected(3, &[(0, 1), (0, 1), (1, 2)]);
+        assert_eq!(vec![(1, 2)], bridges(&graph));
+        assert_eq!(vec![1], articulation_points(&graph));
+    }
+
+    #[test]
+    fn test_path_and_forest() {
+        // 0 - 1 - 2    3 - 4
+        let graph = undirected(5, &[(0, 1), (1, 2), (3, 4)]);
+        assert_eq!(vec![(0, 1), (1, 2), (3, 4)], bridges(&graph));
+        assert_eq!(vec![1], articulation_points(&graph));
+    }
+
+    #[test]
+    fn test_cycle() {
+        let graph = undirected(4, &[(0, 1), (1, 2), (2, 3), (3, 0)]);
+        assert!(bridges(&graph).is_empty());
+        assert!(articulation_points(&graph).is_empty());
+    }
+}
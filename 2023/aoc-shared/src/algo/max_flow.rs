@@ -0,0 +1,252 @@
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone)]
+struct Edge {
+    to: usize,
+    capacity: u64,
+    residual: u64,
+}
+
+/// A flow network over nodes `0..n`, solved with Dinic's algorithm
+#[derive(Debug, Clone)]
+pub struct FlowNetwork {
+    // Edge `i` and its residual edge are stored at indexes `i` and `i ^ 1`
+    edges: Vec<Edge>,
+    graph: Vec<Vec<usize>>,
+    level: Vec<u32>,
+    next: Vec<usize>,
+}
+
+impl FlowNetwork {
+    pub fn new(nodes: usize) -> Self {
+        Self {
+            edges: vec![],
+            graph: vec![vec![]; nodes],
+            level: vec![0; nodes],
+            next: vec![0; nodes],
+        }
+    }
+
+    /// Builds a network from an undirected graph given as adjacency lists,
+    /// where each edge has a capacity of 1 in both directions
+    pub fn from_undirected(graph: &[Vec<usize>]) -> Self {
+        let mut network = Self::new(graph.len());
+        for (a, neighbours) in graph.iter().enumerate() {
+            for &b in neighbours {
+                if a < b {
+                    network.add_undirected_edge(a, b, 1);
+                }
+            }
+        }
+
+        network
+    }
+
+    pub fn nodes(&self) -> usize {
+        self.graph.len()
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize, capacity: u64) {
+        self.push_edges(from, to, capacity, 0);
+    }
+
+    pub fn add_undirected_edge(&mut self, a: usize, b: usize, capacity: u64) {
+        self.push_edges(a, b, capacity, capacity);
+    }
+
+    fn push_edges(&mut self, a: usize, b: usize, forward: u64, backward: u64) {
+        self.graph[a].push(self.edges.len());
+        self.edges.push(Edge {
+            to: b,
+            capacity: forward,
+            residual: forward,
+        });
+
+        self.graph[b].push(self.edges.len());
+        self.edges.push(Edge {
+            to: a,
+            capacity: backward,
+            residual: backward,
+        });
+    }
+
+    /// Removes all the flow, so that the network can be reused with another source or sink
+    pub fn reset(&mut self) {
+        self.edges.iter_mut().for_each(|e| e.residual = e.capacity);
+    }
+
+    /// Computes the maximum flow from `source` to `sink`. The flow is added
+    /// on top of any flow left from previous calls.
+    pub fn max_flow(&mut self, source: usize, sink: usize) -> u64 {
+        self.max_flow_with_limit(source, sink, u64::MAX)
+    }
+
+    /// Like [`FlowNetwork::max_flow`], but stops as soon as the flow reaches
+    /// `limit`. Useful to check if two nodes are connected by at least `limit`
+    /// edge-disjoint paths.
+    pub fn max_flow_with_limit(&mut self, source: usize, sink: usize, limit: u64) -> u64 {
+        if source == sink {
+            return 0;
+        }
+
+        let mut total = 0;
+        while total < limit && self.bfs(source, sink) {
+            self.next.iter_mut().for_each(|x| *x = 0);
+
+            while total < limit {
+                let pushed = self.dfs(source, sink, limit - total);
+                if pushed == 0 {
+                    break;
+                }
+                total += pushed;
+            }
+        }
+
+        total
+    }
+
+    /// The nodes reachable from `source` in the residual network. After
+    /// computing the maximum flow, these are the source side of a minimum cut.
+    pub fn reachable(&self, source: usize) -> Vec<bool> {
+        let mut seen = vec![false; self.nodes()];
+        let mut queue = VecDeque::new();
+
+        seen[source] = true;
+        queue.push_back(source);
+
+        while let Some(node) = queue.pop_front() {
+            for &e in self.graph[node].iter() {
+                let edge = &self.edges[e];
+                if edge.residual > 0 && !seen[edge.to] {
+                    seen[edge.to] = true;
+                    queue.push_back(edge.to);
+                }
+            }
+        }
+
+        seen
+    }
+
+    /// The saturated edges `(from, to)` going out of the source side of the minimum
+    /// cut. Must be called after [`FlowNetwork::max_flow`] with the same source.
+    pub fn min_cut(&self, source: usize) -> Vec<(usize, usize)> {
+        let reachable = self.reachable(source);
+
+        let mut cut = vec![];
+        for (from, edges) in self.graph.iter().enumerate() {
+            if !reachable[from] {
+                continue;
+            }
+
+            for &e in edges {
+                let edge = &self.edges[e];
+                if !reachable[edge.to] && edge.capacity > 0 {
+                    cut.push((from, edge.to));
+                }
+            }
+        }
+
+        cut
+    }
+
+    fn bfs(&mut self, source: usize, sink: usize) -> bool {
+        self.level.iter_mut().for_each(|x| *x = u32::MAX);
+        self.level[source] = 0;
+
+        let mut queue = VecDeque::new();
+        queue.push_back(source);
+
+        while let Some(node) = queue.pop_front() {
+            for &e in self.graph[node].iter() {
+                let edge = &self.edges[e];
+                if edge.residual > 0 && self.level[edge.to] == u32::MAX {
+                    self.level[edge.to] = self.level[node] + 1;
+                    queue.push_back(edge.to);
+                }
+            }
+        }
+
+        self.level[sink] != u32::MAX
+    }
+
+    fn dfs(&mut self, node: usize, sink: usize, pushed: u64) -> u64 {
+        if node == sink {
+            return pushed;
+        }
+
+        while self.next[node] < self.graph[node].len() {
+            let e = self.graph[node][self.next[node]];
+            let Edge { to, residual, .. } = self.edges[e];
+
+            if residual > 0 && self.level[to] == self.level[node] + 1 {
+                let result = self.dfs(to, sink, pushed.min(residual));
+                if result > 0 {
+                    self.edges[e].residual -= result;
+                    self.edges[e ^ 1].residual += result;
+                    return result;
+                }
+            }
+
+            self.next[node] += 1;
+        }
+
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directed() {
+        // The classic CLRS example
+        let mut network = FlowNetwork::new(6);
+        network.add_edge(0, 1, 16);
+        network.add_edge(0, 2, 13);
+        network.add_edge(1, 2, 10);
+        network.add_edge(2, 1, 4);
+        network.add_edge(1, 3, 12);
+        network.add_edge(3, 2, 9);
+        network.add_edge(2, 4, 14);
+        network.add_edge(4, 3, 7);
+        network.add_edge(3, 5, 20);
+        network.add_edge(4, 5, 4);
+
+        assert_eq!(23, network.max_flow(0, 5));
+
+        let cut = network.min_cut(0);
+        assert_eq!(vec![(1, 3), (4, 3), (4, 5)], cut);
+    }
+
+    #[test]
+    fn test_undirected() {
+        // Two triangles connected by two edges
+        let graph = vec![
+            vec![1, 2, 3],
+            vec![0, 2],
+            vec![0, 1, 4],
+            vec![0, 4, 5],
+            vec![2, 3, 5],
+            vec![3, 4],
+        ];
+
+        let mut network = FlowNetwork::from_undirected(&graph);
+        assert_eq!(2, network.max_flow(0, 5));
+
+        let mut cut = network.min_cut(0);
+        cut.sort_unstable();
+        assert_eq!(vec![(0, 3), (2, 4)], cut);
+
+        network.reset();
+        assert_eq!(1, network.max_flow_with_limit(0, 5, 1));
+    }
+
+    #[test]
+    fn test_disconnected() {
+        let mut network = FlowNetwork::new(3);
+        network.add_edge(0, 1, 5);
+        assert_eq!(0, network.max_flow(0, 2));
+        assert_eq!(vec![true, true, false], network.reachable(0));
+    }
+}
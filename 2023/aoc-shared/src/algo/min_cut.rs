@@ -0,0 +1,394 @@
+use std::collections::BinaryHeap;
+
+use crate::algo::UnionFind;
+use crate::hashing::FxHashMap;
+
+/// A cut splitting an undirected graph in two partitions
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MinCut {
+    /// The edges connecting the two partitions. Each edge is reported
+    /// once, with the smaller node first, even if it has parallel edges.
+    pub edges: Vec<(usize, usize)>,
+    /// `true` for the nodes in the first partition
+    pub partition: Vec<bool>,
+    size: usize,
+}
+
+impl MinCut {
+    fn from_partition(graph: &[Vec<usize>], partition: Vec<bool>) -> Self {
+        let mut edges = vec![];
+        for (a, neighbours) in graph.iter().enumerate() {
+            for &b in neighbours {
+                if a < b && partition[a] != partition[b] {
+                    edges.push((a, b));
+                }
+            }
+        }
+
+        let size = edges.len();
+        edges.sort_unstable();
+        edges.dedup();
+
+        Self {
+            edges,
+            partition,
+            size,
+        }
+    }
+
+    /// The number of edges crossing the cut. Parallel edges are counted
+    /// separately, just like the algorithms weigh them when looking for the
+    /// minimum cut, so this can be more than the length of `edges`.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The number of nodes in the first and in the second partition
+    pub fn partition_sizes(&self) -> (usize, usize) {
+        let first = self.partition.iter().filter(|&&x| x).count();
+        (first, self.partition.len() - first)
+    }
+}
+
+/// Stoer-Wagner deterministic global minimum cut of an undirected graph,
+/// given as adjacency lists, where each edge is present in the lists of both
+/// of its nodes. Parallel edges are allowed and add up. Returns `None` if the
+/// graph has less than two nodes.
+pub fn stoer_wagner(graph: &[Vec<usize>]) -> Option<MinCut> {
+    if graph.len() < 2 {
+        return None;
+    }
+
+    let n = graph.len();
+    let mut adj: Vec<FxHashMap<usize, u64>> = vec![FxHashMap::default(); n];
+    for (a, neighbours) in graph.iter().enumerate() {
+        for &b in neighbours {
+            if a != b {
+                *adj[a].entry(b).or_default() += 1;
+            }
+        }
+    }
+
+    let mut members: Vec<Vec<usize>> = (0..n).map(|x| vec![x]).collect();
+    let mut active: Vec<usize> = (0..n).collect();
+
+    let mut best_weight = u64::MAX;
+    let mut best_members = vec![];
+
+    let mut weights = vec![0u64; n];
+    let mut added = vec![false; n];
+    let mut heap = BinaryHeap::new();
+
+    while active.len() > 1 {
+        // Minimum cut phase: grow a set by always adding the most tightly
+        // connected node. The last added node is separated from the rest
+        // by the "cut of the phase"
+        for &x in active.iter() {
+            weights[x] = 0;
+            added[x] = false;
+        }
+
+        heap.clear();
+        heap.push((0, active[0]));
+
+        let (mut s, mut t) = (usize::MAX, usize::MAX);
+        let mut cut_of_phase = 0;
+
+        while let Some((w, node)) = heap.pop() {
+            if added[node] || w != weights[node] {
+                continue;
+            }
+
+            added[node] = true;
+            (s, t) = (t, node);
+            cut_of_phase = w;
+
+            for (&next, &weight) in adj[node].iter() {
+                if !added[next] {
+                    weights[next] += weight;
+                    heap.push((weights[next], next));
+                }
+            }
+        }
+
+        // The graph is not connected
+        if active.iter().any(|&x| !added[x]) {
+            let mut partition = vec![false; n];
+            for &x in active.iter().filter(|&&x| added[x]) {
+                members[x].iter().for_each(|&m| partition[m] = true);
+            }
+
+            return Some(MinCut::from_partition(graph, partition));
+        }
+
+        if cut_of_phase < best_weight {
+            best_weight = cut_of_phase;
+            best_members = members[t].clone();
+        }
+
+        // Merge `t` into `s`
+        let t_edges = std::mem::take(&mut adj[t]);
+        for (next, weight) in t_edges {
+            adj[next].remove(&t);
+            if next != s {
+                *adj[s].entry(next).or_default() += weight;
+                *adj[next].entry(s).or_default() += weight;
+            }
+        }
+
+        let t_members = std::mem::take(&mut members[t]);
+        members[s].extend(t_members);
+        active.retain(|&x| x != t);
+    }
+
+    let mut partition = vec![false; n];
+    for x in best_members {
+        partition[x] = true;
+    }
+
+    Some(MinCut::from_partition(graph, partition))
+}
+
+/// Karger-Stein randomized minimum cut of an undirected graph, given as
+/// adjacency lists. Each trial finds the minimum cut with a probability of
+/// at least `1 / log(n)`, so the best cut out of all trials is returned.
+/// The `seed` makes the result reproducible. Returns `None` if the graph
+/// has less than two nodes.
+pub fn karger_stein(graph: &[Vec<usize>], trials: usize, seed: u64) -> Option<MinCut> {
+    if graph.len() < 2 {
+        return None;
+    }
+
+    let mut edges = vec![];
+    for (a, neighbours) in graph.iter().enumerate() {
+        for &b in neighbours {
+            if a < b {
+                edges.push((a, b, 1));
+            }
+        }
+    }
+    let edges = merge_parallel(edges);
+
+    let mut rng = XorShift::new(seed);
+    let mut best: Option<MinCut> = None;
+
+    for _ in 0..trials.max(1) {
+        let (_, partition) = recursive_contract(&mut rng, graph.len(), &edges);
+        let cut = MinCut::from_partition(graph, partition);
+
+        if best.as_ref().map(|b| cut.size() < b.size()).unwrap_or(true) {
+            best = Some(cut);
+        }
+    }
+
+    best
+}
+
+// (a, b, weight) with a < b. Parallel edges are merged into a single heavier one,
+// which keeps the number of edges small as the graph gets contracted.
+type WeightedEdge = (usize, usize, u64);
+
+// Returns the weight of the cut and the side of each of the `nodes` super-nodes
+fn recursive_contract(
+    rng: &mut XorShift,
+    nodes: usize,
+    edges: &[WeightedEdge],
+) -> (u64, Vec<bool>) {
+    if nodes <= 6 {
+        let (mapping, edges) = contract(rng, nodes, edges, 2);
+        let weight = edges.iter().map(|e| e.2).sum();
+        return (weight, mapping.into_iter().map(|x| x == 0).collect());
+    }
+
+    let target = (1.0 + nodes as f64 / std::f64::consts::SQRT_2).ceil() as usize;
+
+    let mut best: Option<(u64, Vec<bool>)> = None;
+    for _ in 0..2 {
+        let (mapping, contracted) = contract(rng, nodes, edges, target);
+        let (weight, side) = recursive_contract(rng, target, &contracted);
+
+        if best.as_ref().map(|b| weight < b.0).unwrap_or(true) {
+            best = Some((weight, mapping.into_iter().map(|x| side[x]).collect()));
+        }
+    }
+
+    best.unwrap()
+}
+
+// Randomly contracts edges until only `target` super-nodes remain. Returns the super-node
+// in `0..target` of each node and the edges between the super-nodes.
+fn contract(
+    rng: &mut XorShift,
+    nodes: usize,
+    edges: &[WeightedEdge],
+    target: usize,
+) -> (Vec<usize>, Vec<WeightedEdge>) {
+    // Contracting the edges in the order of exponentially distributed random keys
+    // is the same as repeatedly picking an edge with a probability proportional
+    // to its weight, but without having to skip the self-loops
+    let mut order: Vec<(f64, usize)> = edges
+        .iter()
+        .enumerate()
+        .map(|(idx, e)| (-rng.next_f64().ln() / e.2 as f64, idx))
+        .collect();
+    order.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut uf = UnionFind::new(nodes);
+    for &(_, idx) in order.iter() {
+        if uf.number_of_groups() <= target {
+            break;
+        }
+
+        let (a, b, _) = edges[idx];
+        uf.union(a, b);
+    }
+
+    // A disconnected graph may still have more super-nodes than the target
+    for node in 1..nodes {
+        if uf.number_of_groups() <= target {
+            break;
+        }
+        uf.union(0, node);
+    }
+
+    let mut relabel = vec![usize::MAX; nodes];
+    let mut next = 0;
+    for node in 0..nodes {
+        let root = uf.find(node);
+        if relabel[root] == usize::MAX {
+            relabel[root] = next;
+            next += 1;
+        }
+    }
+
+    let mapping: Vec<usize> = (0..nodes).map(|x| relabel[uf.find(x)]).collect();
+    let edges = edges
+        .iter()
+        .map(|&(a, b, w)| (mapping[a], mapping[b], w))
+        .filter(|(a, b, _)| a != b)
+        .map(|(a, b, w)| (a.min(b), a.max(b), w))
+        .collect();
+
+    (mapping, merge_parallel(edges))
+}
+
+fn merge_parallel(mut edges: Vec<WeightedEdge>) -> Vec<WeightedEdge> {
+    edges.sort_unstable();
+
+    let mut merged: Vec<WeightedEdge> = Vec::with_capacity(edges.len());
+    for (a, b, w) in edges {
+        match merged.last_mut() {
+            Some(last) if last.0 == a && last.1 == b => last.2 += w,
+            _ => merged.push((a, b, w)),
+        }
+    }
+
+    merged
+}
+
+struct XorShift(u64);
+
+impl XorShift {
+    fn new(seed: u64) -> Self {
+        // The state must never be zero
+        Self(seed ^ 0x9E37_79B9_7F4A_7C15 | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+
+    // Uniformly distributed in (0, 1]
+    fn next_f64(&mut self) -> f64 {
+        ((self.next() >> 11) + 1) as f64 / (1u64 << 53) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Two 4-cliques connected by two edges: 0-4 and 3-7
+    fn two_cliques() -> Vec<Vec<usize>> {
+        let mut graph = vec![vec![]; 8];
+        let mut connect = |a: usize, b: usize| {
+            graph[a].push(b);
+            graph[b].push(a);
+        };
+
+        for base in [0, 4] {
+            for i in 0..4 {
+                for j in i + 1..4 {
+                    connect(base + i, base + j);
+                }
+            }
+        }
+
+        connect(0, 4);
+        connect(3, 7);
+        graph
+    }
+
+    #[test]
+    fn test_stoer_wagner() {
+        let cut = stoer_wagner(&two_cliques()).unwrap();
+        assert_eq!(vec![(0, 4), (3, 7)], cut.edges);
+        assert_eq!((4, 4), cut.partition_sizes());
+    }
+
+    #[test]
+    fn test_stoer_wagner_parallel_edges() {
+        // 0 =2= 1 -1- 2
+        let graph = vec![vec![1, 1], vec![0, 0, 2], vec![1]];
+        let cut = stoer_wagner(&graph).unwrap();
+        assert_eq!(vec![(1, 2)], cut.edges);
+    }
+
+    #[test]
+    fn test_parallel_edge_crossing_the_cut() {
+        // 0 =3= 1 =2= 2
+        let graph = vec![vec![1, 1, 1], vec![0, 0, 0, 2, 2], vec![1, 1]];
+
+        for cut in [
+            stoer_wagner(&graph).unwrap(),
+            karger_stein(&graph, 10, 42).unwrap(),
+        ] {
+            assert_eq!(vec![(1, 2)], cut.edges);
+            assert_eq!(2, cut.size());
+            assert_eq!(cut.partition[0], cut.partition[1]);
+            assert_ne!(cut.partition[1], cut.partition[2]);
+        }
+    }
+
+    #[test]
+    fn test_stoer_wagner_disconnected() {
+        let graph = vec![vec![1], vec![0], vec![3], vec![2]];
+        let cut = stoer_wagner(&graph).unwrap();
+        assert_eq!(0, cut.size());
+        assert_eq!((2, 2), cut.partition_sizes());
+    }
+
+    #[test]
+    fn test_stoer_wagner_too_small() {
+        assert_eq!(None, stoer_wagner(&[vec![]]));
+    }
+
+    #[test]
+    fn test_karger_stein() {
+        let cut = karger_stein(&two_cliques(), 10, 42).unwrap();
+        assert_eq!(vec![(0, 4), (3, 7)], cut.edges);
+        assert_eq!((4, 4), cut.partition_sizes());
+    }
+
+    #[test]
+    fn test_karger_stein_disconnected() {
+        let graph = vec![vec![1], vec![0], vec![3], vec![2]];
+        let cut = karger_stein(&graph, 10, 42).unwrap();
+        assert_eq!(0, cut.size());
+    }
+}
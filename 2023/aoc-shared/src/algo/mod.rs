@@ -1,6 +1,12 @@
+pub use aoc_core::union_find::{UnionFind, UnionFindAny};
+pub use bridges::{articulation_points, bridges};
 pub use bucket_queue::BucketQueue;
-pub use union_find::UnionFind;
-pub use union_find::UnionFindAny;
+pub use max_flow::FlowNetwork;
+pub use min_cut::{karger_stein, stoer_wagner, MinCut};
 
+pub use aoc_core::cycle;
+
+mod bridges;
 mod bucket_queue;
-mod union_find;
+mod max_flow;
+mod min_cut;
@@ -1,7 +1,4 @@
-pub use direction::Direction;
-pub use point::Point;
+pub use aoc_core::grid::{Direction, Point, DIR4, DIR8};
+pub use polygon::Polygon;
 
-pub const DIR4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
-
-mod direction;
-mod point;
+mod polygon;
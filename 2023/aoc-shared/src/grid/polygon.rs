@@ -0,0 +1,227 @@
+use crate::grid::{Direction, Point};
+use crate::math::gcd;
+
+/// A simple (i.e. not self-intersecting) polygon with vertices on the integer
+/// lattice. The vertices can be in either clockwise or counter-clockwise order
+/// and the last vertex is implicitly connected to the first one.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point>) -> Self {
+        Self { vertices }
+    }
+
+    /// Builds the polygon traced by walking from `start` in the given
+    /// directions. The walk is expected to end where it started.
+    pub fn from_walk<I>(start: Point, steps: I) -> Self
+    where
+        I: IntoIterator<Item = (Direction, i64)>,
+    {
+        let mut vertices = vec![];
+
+        let mut p = start;
+        for (dir, len) in steps {
+            vertices.push(p);
+            p += Point::from(dir) * len;
+        }
+
+        debug_assert_eq!(start, p, "the walk does not form a closed loop");
+        Self { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    /// Twice the area of the polygon, computed with the shoelace formula.
+    /// Unlike the area, it's always an integer.
+    pub fn double_area(&self) -> i128 {
+        self.edges()
+            .map(|(a, b)| a.x as i128 * b.y as i128 - a.y as i128 * b.x as i128)
+            .sum::<i128>()
+            .abs()
+    }
+
+    /// The area of the polygon. Polygons with diagonal edges can have an area
+    /// ending in .5, in which case the result is rounded down.
+    pub fn area(&self) -> i128 {
+        self.double_area() / 2
+    }
+
+    /// The number of lattice points lying on the edges of the polygon.
+    /// For polygons with only horizontal and vertical edges this is the
+    /// same as the perimeter.
+    pub fn boundary(&self) -> i128 {
+        self.edges()
+            .map(|(a, b)| gcd((b.x - a.x).unsigned_abs(), (b.y - a.y).unsigned_abs()) as i128)
+            .sum()
+    }
+
+    /// The number of lattice points strictly inside the polygon, computed
+    /// with Pick's theorem: A = I + B/2 - 1
+    pub fn interior(&self) -> i128 {
+        (self.double_area() - self.boundary() + 2) / 2
+    }
+
+    /// The number of lattice points inside or on the edges of the polygon,
+    /// i.e. the number of grid cells covered by a path of the same shape
+    pub fn lattice_points(&self) -> i128 {
+        self.interior() + self.boundary()
+    }
+
+    pub fn on_boundary(&self, p: Point) -> bool {
+        self.edges().any(|(a, b)| {
+            let cross = (b.x - a.x) as i128 * (p.y - a.y) as i128
+                - (b.y - a.y) as i128 * (p.x - a.x) as i128;
+
+            cross == 0
+                && p.x >= a.x.min(b.x)
+                && p.x <= a.x.max(b.x)
+                && p.y >= a.y.min(b.y)
+                && p.y <= a.y.max(b.y)
+        })
+    }
+
+    /// Checks if the point is strictly inside the polygon
+    pub fn contains(&self, p: Point) -> bool {
+        if self.on_boundary(p) {
+            return false;
+        }
+
+        // Count how many edges cross the ray going from the point
+        // to the right. Each edge includes its lower end, but not the
+        // upper one, so that crossing a vertex is counted only once.
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            if (a.y > p.y) == (b.y > p.y) {
+                continue;
+            }
+
+            // p.x < a.x + (p.y - a.y) * (b.x - a.x) / (b.y - a.y)
+            let lhs = (p.x - a.x) as i128 * (b.y - a.y) as i128;
+            let rhs = (p.y - a.y) as i128 * (b.x - a.x) as i128;
+
+            if (b.y > a.y && lhs < rhs) || (b.y < a.y && lhs > rhs) {
+                inside = !inside;
+            }
+        }
+
+        inside
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next.copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(side: i64) -> Polygon {
+        Polygon::from_walk(
+            Point::ZERO,
+            [
+                (Direction::Right, side),
+                (Direction::Down, side),
+                (Direction::Left, side),
+                (Direction::Up, side),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_square() {
+        let p = square(4);
+        assert_eq!(16, p.area());
+        assert_eq!(16, p.boundary());
+        assert_eq!(9, p.interior());
+        assert_eq!(25, p.lattice_points());
+    }
+
+    #[test]
+    fn test_orientation_does_not_matter() {
+        let mut vertices = square(4).vertices().to_vec();
+        vertices.reverse();
+
+        let p = Polygon::new(vertices);
+        assert_eq!(16, p.area());
+        assert_eq!(9, p.interior());
+    }
+
+    #[test]
+    fn test_triangle() {
+        let p = Polygon::new(vec![Point::new(0, 0), Point::new(4, 0), Point::new(0, 3)]);
+        assert_eq!(12, p.double_area());
+        assert_eq!(6, p.area());
+        assert_eq!(4 + 3 + 1, p.boundary());
+        assert_eq!(3, p.interior());
+    }
+
+    #[test]
+    fn test_dig_plan_example() {
+        // The example from 2023 day 18
+        let plan = "R 6\nD 5\nL 2\nD 2\nR 2\nD 2\nL 5\nU 2\nL 1\nU 2\nR 2\nU 3\nL 2\nU 2";
+        let steps = plan.lines().map(|l| {
+            let (d, n) = l.split_once(' ').unwrap();
+            let d = match d {
+                "U" => Direction::Up,
+                "D" => Direction::Down,
+                "L" => Direction::Left,
+                _ => Direction::Right,
+            };
+
+            (d, n.parse().unwrap())
+        });
+
+        let p = Polygon::from_walk(Point::ZERO, steps);
+        assert_eq!(38, p.boundary());
+        assert_eq!(62, p.lattice_points());
+    }
+
+    #[test]
+    fn test_contains() {
+        let p = square(4);
+
+        for y in -1..=5 {
+            for x in -1..=5 {
+                let point = Point::new(x, y);
+                let inside = (1..4).contains(&x) && (1..4).contains(&y);
+                let edge = (0..=4).contains(&x)
+                    && (0..=4).contains(&y)
+                    && (x == 0 || x == 4 || y == 0 || y == 4);
+
+                assert_eq!(inside, p.contains(point), "{:?}", point);
+                assert_eq!(edge, p.on_boundary(point), "{:?}", point);
+            }
+        }
+    }
+
+    #[test]
+    fn test_contains_matches_pick() {
+        // A "U" shaped polygon, with a vertex at the same height as the tested points
+        let p = Polygon::new(vec![
+            Point::new(0, 0),
+            Point::new(2, 0),
+            Point::new(2, 4),
+            Point::new(4, 4),
+            Point::new(4, 0),
+            Point::new(6, 0),
+            Point::new(6, 6),
+            Point::new(0, 6),
+        ]);
+
+        let mut inside = 0;
+        for y in -1..=7 {
+            for x in -1..=7 {
+                inside += p.contains(Point::new(x, y)) as i128;
+            }
+        }
+
+        assert_eq!(p.interior(), inside);
+    }
+}
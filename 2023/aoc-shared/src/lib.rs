@@ -1,6 +1,7 @@
+pub use aoc_core::{blocks, hashing, input_locator, linalg, math, parallel};
+
 pub mod algo;
 pub mod grid;
-pub mod hashing;
 pub mod input;
 pub mod parsing;
 pub mod util;
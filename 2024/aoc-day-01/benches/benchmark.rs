@@ -1,4 +1,4 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{Criterion, black_box, criterion_group, criterion_main};
 
 use aoc_day_01::{
@@ -14,7 +14,7 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_input_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2024, 1).load().unwrap();
 
     c.bench_function("parsing/generic", |b| {
         b.iter(|| parse_input_generic(&input));
@@ -30,7 +30,7 @@ fn benchmark_input_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2024, 1).load().unwrap();
     let (x, y) = parse_input_fast(&input).unwrap();
 
     c.bench_function("part-1", |b| {
@@ -39,7 +39,7 @@ fn benchmark_part_one(c: &mut Criterion) {
 }
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2024, 1).load().unwrap();
     let (x, y) = parse_input_fast(&input).unwrap();
 
     c.bench_function("part-2/v1", |b| {
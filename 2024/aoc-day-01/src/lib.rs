@@ -1,7 +1,7 @@
 use aoc_shared::hashing::{FxHashMap, FxHashSet};
+use aoc_shared::scanner::{Scanner, parse_digits_swar};
 use std::error::Error;
 
-// https://lemire.me/blog/2022/01/21/swar-explained-parsing-eight-digits/
 pub fn parse_input_swar(input: &str) -> Result<(Vec<u32>, Vec<u32>), Box<dyn Error>> {
     const LINE_LENGTH: usize = 14;
 
@@ -10,37 +10,13 @@ pub fn parse_input_swar(input: &str) -> Result<(Vec<u32>, Vec<u32>), Box<dyn Err
     let mut y = vec![0; elements];
 
     for (idx, line) in input.as_bytes().chunks_exact(LINE_LENGTH).enumerate() {
-        let a = parse_swar([
-            b'0', b'0', b'0', line[0], line[1], line[2], line[3], line[4],
-        ]);
-        let b = parse_swar([
-            b'0', b'0', b'0', line[8], line[9], line[10], line[11], line[12],
-        ]);
-
-        x[idx] = a as u32;
-        y[idx] = b as u32;
+        x[idx] = parse_digits_swar(&line[0..5]);
+        y[idx] = parse_digits_swar(&line[8..13]);
     }
 
     Ok((x, y))
 }
 
-// https://lemire.me/blog/2022/01/21/swar-explained-parsing-eight-digits/
-fn parse_swar(input: [u8; 8]) -> u64 {
-    const MASK: u64 = 0x000000FF000000FF;
-    const MUL_1: u64 = 0x000F424000000064; // 100 + (1000000ULL << 32)
-    const MUL_2: u64 = 0x0000271000000001; // 1 + (10000ULL << 32)
-
-    let mut n = u64::from_le_bytes(input);
-
-    // Subtract b'0' from each byte
-    n -= 0x3030303030303030;
-
-    // pyramidal computation
-    n = (n * 10) + (n >> 8);
-    n = ((n & MASK).wrapping_mul(MUL_1) + ((n >> 16) & MASK).wrapping_mul(MUL_2)) >> 32;
-    n
-}
-
 pub fn parse_input_fast(input: &str) -> Result<(Vec<u32>, Vec<u32>), Box<dyn Error>> {
     const LINE_LENGTH: usize = 14;
 
@@ -74,13 +50,14 @@ pub fn parse_input_generic(input: &str) -> Result<(Vec<u32>, Vec<u32>), Box<dyn
     let mut x = vec![];
     let mut y = vec![];
 
-    for line in input.lines() {
-        let Some((a, b)) = line.split_once("   ") else {
-            return Err(format!("Invalid line: {}", line).into());
+    for line in Scanner::from(input).lines() {
+        let mut scanner = Scanner::new(line);
+        let (Some(Ok(a)), Some(Ok(b))) = (scanner.next_uint(), scanner.next_uint()) else {
+            return Err(format!("Invalid line: {}", String::from_utf8_lossy(line)).into());
         };
 
-        x.push(a.trim().parse()?);
-        y.push(b.trim().parse()?);
+        x.push(a);
+        y.push(b);
     }
 
     Ok((x, y))
@@ -109,6 +86,8 @@ pub fn part_two_v1(a: &Vec<u32>, b: &Vec<u32>) -> u32 {
     })
 }
 
+/// Assumes that the numbers in the left list are unique, which is the case for the
+/// real inputs, but not for the example
 pub fn part_two_v2(a: &Vec<u32>, b: &Vec<u32>) -> u32 {
     let seen = a.iter().copied().collect::<FxHashSet<_>>();
     b.iter()
@@ -168,3 +147,13 @@ mod tests {
         assert_eq!(23082277, answer);
     }
 }
+
+aoc_shared::example_tests! {
+    parse: |input| parse_input_generic(input).unwrap(),
+    part_one: {
+        part_one: |(a, b)| part_one(a, b),
+    },
+    part_two: {
+        part_two_v1: |(a, b)| part_two_v1(a, b),
+    },
+}
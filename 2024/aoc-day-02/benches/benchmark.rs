@@ -1,4 +1,4 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{Criterion, black_box, criterion_group, criterion_main};
 
 use aoc_day_02::{part_one_v1, part_one_v2, part_two};
@@ -7,7 +7,7 @@ criterion_group!(benches, benchmark_part_one, benchmark_part_two);
 criterion_main!(benches);
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2024, 2).load().unwrap();
 
     c.bench_function("part-1/v1", |b| {
         b.iter(|| part_one_v1(black_box(&input)));
@@ -19,7 +19,7 @@ fn benchmark_part_one(c: &mut Criterion) {
 }
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2024, 2).load().unwrap();
 
     c.bench_function("part-2", |b| {
         b.iter(|| part_two(black_box(&input)));
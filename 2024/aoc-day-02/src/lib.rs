@@ -171,3 +171,8 @@ mod tests {
         assert_eq!(621, answer);
     }
 }
+
+aoc_shared::example_tests! {
+    part_one: { part_one_v1, part_one_v2 },
+    part_two: { part_two },
+}
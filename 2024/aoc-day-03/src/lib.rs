@@ -1,5 +1,6 @@
 pub mod with_fsm;
 pub mod with_regex;
+pub mod with_scanner;
 
 pub fn part_one(input: &str) -> u64 {
     with_fsm::part_one_v2(input)
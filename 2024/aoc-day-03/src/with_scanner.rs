@@ -0,0 +1,79 @@
+use aoc_shared::scanner::Scanner;
+
+pub fn part_one_v3(input: &str) -> u64 {
+    let mut scanner = Scanner::from(input);
+
+    let mut sum = 0;
+    while scanner.skip_until(|b| b == b'm') {
+        if let Some((a, b)) = mul(&mut scanner) {
+            sum += a * b;
+        }
+    }
+
+    sum
+}
+
+pub fn part_two_v3(input: &str) -> u64 {
+    let mut scanner = Scanner::from(input);
+
+    let mut sum = 0;
+    let mut enabled = true;
+
+    while scanner.skip_until(|b| b == b'm' || b == b'd') {
+        if scanner.expect_literal(b"do()") {
+            enabled = true;
+        } else if scanner.expect_literal(b"don't()") {
+            enabled = false;
+        } else if let Some((a, b)) = mul(&mut scanner)
+            && enabled
+        {
+            sum += a * b;
+        }
+    }
+
+    sum
+}
+
+// Parses `mul(X,Y)`. On failure, the scanner is moved past the first byte,
+// so that the next search can start from there
+fn mul(scanner: &mut Scanner) -> Option<(u64, u64)> {
+    if !scanner.expect_literal(b"mul(") {
+        scanner.skip(1);
+        return None;
+    }
+
+    let a = scanner.read_uint()?;
+    if !scanner.expect_literal(b",") {
+        return None;
+    }
+
+    let b = scanner.read_uint()?;
+    if !scanner.expect_literal(b")") {
+        return None;
+    }
+
+    Some((a, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_shared::input::load_text_input_from_file;
+
+    use super::*;
+
+    #[test]
+    fn test_part_one_v3() {
+        let input = load_text_input_from_file("inputs/input.txt");
+
+        let answer = part_one_v3(&input);
+        assert_eq!(175_615_763, answer);
+    }
+
+    #[test]
+    fn test_part_two_v3() {
+        let input = load_text_input_from_file("inputs/input.txt");
+
+        let answer = part_two_v3(&input);
+        assert_eq!(74_361_272, answer);
+    }
+}
@@ -1,4 +1,4 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{Criterion, black_box, criterion_group, criterion_main};
 
 use aoc_day_04::{parse_input, part_one_v1, part_one_v2, part_two_v1, part_two_v2};
@@ -12,7 +12,7 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_input_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2024, 4).load().unwrap();
 
     c.bench_function("parsing", |b| {
         b.iter(|| parse_input(&input));
@@ -20,7 +20,7 @@ fn benchmark_input_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2024, 4).load().unwrap();
     let parsed = parse_input(&input).unwrap();
 
     c.bench_function("part-1/recursive", |b| {
@@ -33,7 +33,7 @@ fn benchmark_part_one(c: &mut Criterion) {
 }
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2024, 4).load().unwrap();
     let parsed = parse_input(&input).unwrap();
 
     c.bench_function("part-2/v1", |b| {
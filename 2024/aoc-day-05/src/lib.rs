@@ -1,3 +1,4 @@
+use aoc_shared::blocks::try_split_blocks;
 use std::cmp::Ordering;
 use std::collections::VecDeque;
 use std::error::Error;
@@ -6,35 +7,28 @@ pub fn parse_input(input: &str) -> Result<(Vec<u128>, Vec<Vec<usize>>), Box<dyn
     let mut graph = Vec::with_capacity(100);
     let mut updates = Vec::with_capacity(200);
 
-    let mut parsing_graph = true;
-    for line in input.lines() {
-        if line.is_empty() {
-            parsing_graph = false;
-            continue;
-        }
-
-        match parsing_graph {
-            true => {
-                let line = line.as_bytes();
-                let a = (line[0] - b'0') as usize * 10 + (line[1] - b'0') as usize;
-                let b = (line[3] - b'0') as usize * 10 + (line[4] - b'0') as usize;
+    let [rules, pages] = try_split_blocks(input)?;
 
-                let max = a.max(b);
-                if graph.len() <= max {
-                    graph.resize(max + 1, 0u128);
-                }
+    for line in rules.lines() {
+        let line = line.as_bytes();
+        let a = (line[0] - b'0') as usize * 10 + (line[1] - b'0') as usize;
+        let b = (line[3] - b'0') as usize * 10 + (line[4] - b'0') as usize;
 
-                graph[a] = graph[a] | (1 << b);
-            }
-            false => {
-                let update = line
-                    .as_bytes()
-                    .chunks(3)
-                    .map(|x| (x[0] - b'0') as usize * 10 + (x[1] - b'0') as usize)
-                    .collect::<Vec<_>>();
-                updates.push(update);
-            }
+        let max = a.max(b);
+        if graph.len() <= max {
+            graph.resize(max + 1, 0u128);
         }
+
+        graph[a] = graph[a] | (1 << b);
+    }
+
+    for line in pages.lines() {
+        let update = line
+            .as_bytes()
+            .chunks(3)
+            .map(|x| (x[0] - b'0') as usize * 10 + (x[1] - b'0') as usize)
+            .collect::<Vec<_>>();
+        updates.push(update);
     }
 
     Ok((graph, updates))
@@ -1,4 +1,5 @@
 use aoc_shared::grid::Direction;
+use aoc_shared::parallel;
 
 pub fn part_one(input: &str) -> usize {
     let grid = input.as_bytes();
@@ -26,6 +27,7 @@ pub fn part_two_v1(input: &str) -> u32 {
     // because otherwise he will never reach them.
     let route = mark_route(grid, height, width, start_row, start_col);
 
+    let start = (start_row, start_col);
     let mut visited = vec![0u8; grid.len()];
     let mut answer = 0;
 
@@ -34,41 +36,85 @@ pub fn part_two_v1(input: &str) -> u32 {
             continue;
         }
 
-        let obs_r = idx / width;
-        let obs_c = idx % width;
-        if (start_row, start_col) == (obs_r, obs_c) {
+        let obstacle = (idx / width, idx % width);
+        if start == obstacle {
             continue;
         }
 
         visited.fill(0);
-        let mut row = start_row;
-        let mut col = start_col;
-        let mut dir = Direction::Up;
-
-        loop {
-            if visited[(row * width + col) as usize] & (1 << dir as usize) != 0 {
-                answer += 1;
-                break;
-            }
-            visited[(row * width + col) as usize] |= 1 << dir as usize;
-
-            let (r, c) = dir.apply_signed(row, col);
-            if r < 0 || c < 0 || r >= height || c + 1 >= width {
-                break;
-            }
-
-            if grid[(r * width + c) as usize] == b'#' || (r, c) == (obs_r, obs_c) {
-                dir = dir.rotr();
-            } else {
-                row = r;
-                col = c;
-            }
+        if is_loop(grid, &mut visited, height, width, start, obstacle) {
+            answer += 1;
         }
     }
 
     answer
 }
 
+pub fn part_two_parallel(input: &str) -> u32 {
+    let grid = input.as_bytes();
+    let width = grid.iter().position(|&c| c == b'\n').unwrap() as isize + 1;
+    let height = grid.len() as isize / width;
+
+    let start_idx = grid.iter().position(|&c| c == b'^').unwrap() as isize;
+    let start = (start_idx / width, start_idx % width);
+
+    let route = mark_route(grid, height, width, start.0, start.1);
+    let obstacles: Vec<(isize, isize)> = (0..route.len() as isize)
+        .filter(|&idx| route[idx as usize] != 0)
+        .map(|idx| (idx / width, idx % width))
+        .filter(|&obstacle| obstacle != start)
+        .collect();
+
+    // Each task checks a whole chunk, so that it can reuse its visited buffer
+    let chunks: Vec<_> = obstacles.chunks(64).collect();
+    parallel::map_reduce(
+        &chunks,
+        |chunk| {
+            let mut visited = vec![0u8; grid.len()];
+            chunk
+                .iter()
+                .filter(|&&obstacle| {
+                    visited.fill(0);
+                    is_loop(grid, &mut visited, height, width, start, obstacle)
+                })
+                .count() as u32
+        },
+        |a, b| a + b,
+    )
+    .unwrap_or(0)
+}
+
+fn is_loop(
+    grid: &[u8],
+    visited: &mut [u8],
+    height: isize,
+    width: isize,
+    start: (isize, isize),
+    obstacle: (isize, isize),
+) -> bool {
+    let (mut row, mut col) = start;
+    let mut dir = Direction::Up;
+
+    loop {
+        if visited[(row * width + col) as usize] & (1 << dir as usize) != 0 {
+            return true;
+        }
+        visited[(row * width + col) as usize] |= 1 << dir as usize;
+
+        let (r, c) = dir.apply_signed(row, col);
+        if r < 0 || c < 0 || r >= height || c + 1 >= width {
+            return false;
+        }
+
+        if grid[(r * width + c) as usize] == b'#' || (r, c) == obstacle {
+            dir = dir.rotr();
+        } else {
+            row = r;
+            col = c;
+        }
+    }
+}
+
 fn mark_route(
     grid: &[u8],
     height: isize,
@@ -207,4 +253,11 @@ mod tests {
         let answer = part_two_v2(&input);
         assert_eq!(1928, answer);
     }
+
+    #[test]
+    fn test_part_two_parallel() {
+        let input = load_text_input_from_file("inputs/input.txt");
+        let answer = part_two_parallel(&input);
+        assert_eq!(1928, answer);
+    }
 }
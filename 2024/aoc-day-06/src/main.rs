@@ -1,14 +1,15 @@
 use std::error::Error;
 
-use aoc_shared::input::load_text_input_from_autodetect;
+use aoc_shared::input::try_load_text_input_from_autodetect;
+use aoc_shared::input_locator;
 
-use aoc_day_06::{part_one, part_two_v1};
+use aoc_day_06::{part_one, part_two_parallel};
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let input = load_text_input_from_autodetect();
+    let input = try_load_text_input_from_autodetect(input_locator!(2024, 6))?;
 
     println!("Part 1: {:?}", part_one(&input));
-    println!("Part 2: {:?}", part_two_v1(&input));
+    println!("Part 2: {:?}", part_two_parallel(&input));
 
     Ok(())
 }
@@ -1,13 +1,6 @@
-use regex::Regex;
+use aoc_shared::linalg::{self, Rational, Solution};
+use aoc_shared::scanner::{Scanner, uints};
 use std::error::Error;
-use std::sync::LazyLock;
-
-static GAME_REGEX: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(
-        r#"Button A: X\+(\d+), Y\+(\d+)\nButton B: X\+(\d+), Y\+(\d+)\nPrize: X=(\d+), Y=(\d+)"#,
-    )
-    .unwrap()
-});
 
 #[derive(Debug)]
 pub struct Point {
@@ -25,43 +18,23 @@ pub struct Game {
 pub fn parse_input(input: &str) -> Result<Vec<Game>, Box<dyn Error>> {
     let mut games = vec![];
 
-    for c in GAME_REGEX.captures_iter(input) {
-        let Some(ax) = c.get(1) else {
-            return Err("Invalid input".into());
-        };
-
-        let Some(ay) = c.get(2) else {
-            return Err("Invalid input".into());
-        };
-
-        let Some(bx) = c.get(3) else {
-            return Err("Invalid input".into());
-        };
-
-        let Some(by) = c.get(4) else {
-            return Err("Invalid input".into());
-        };
-
-        let Some(px) = c.get(5) else {
-            return Err("Invalid input".into());
-        };
-
-        let Some(py) = c.get(6) else {
-            return Err("Invalid input".into());
-        };
+    for block in Scanner::from(input).paragraphs() {
+        let mut values = uints(block);
+        let mut next =
+            || -> Result<i64, Box<dyn Error>> { Ok(values.next().ok_or("Invalid input")??) };
 
         games.push(Game {
             a: Point {
-                x: ax.as_str().parse()?,
-                y: ay.as_str().parse()?,
+                x: next()?,
+                y: next()?,
             },
             b: Point {
-                x: bx.as_str().parse()?,
-                y: by.as_str().parse()?,
+                x: next()?,
+                y: next()?,
             },
             p: Point {
-                x: px.as_str().parse()?,
-                y: py.as_str().parse()?,
+                x: next()?,
+                y: next()?,
             },
         })
     }
@@ -82,17 +55,41 @@ pub fn solve<const DIFF: i64>(games: &[Game]) -> u64 {
     let mut tokens = 0;
 
     for g in games {
-        let dividend_a = g.b.y * (g.p.x + DIFF) - g.b.x * (g.p.y + DIFF);
-        let divisor_a = g.b.y * g.a.x - g.b.x * g.a.y;
-        let (a, ok_a) = (dividend_a / divisor_a, dividend_a % divisor_a);
-        if ok_a != 0 {
+        // a * A + b * B = P
+        let coefficients = vec![
+            vec![Rational::from(g.a.x), Rational::from(g.b.x)],
+            vec![Rational::from(g.a.y), Rational::from(g.b.y)],
+        ];
+        let prize = vec![Rational::from(g.p.x + DIFF), Rational::from(g.p.y + DIFF)];
+
+        let presses = match linalg::solve(coefficients, prize) {
+            Solution::Unique(presses) => presses[0].to_integer().zip(presses[1].to_integer()),
+            // The buttons and the prize are on one line, so a single coordinate
+            // is enough. The puzzle inputs never have such games.
+            Solution::Infinite => {
+                let (a, b, p) = if g.a.x != 0 || g.b.x != 0 {
+                    (g.a.x, g.b.x, g.p.x + DIFF)
+                } else {
+                    (g.a.y, g.b.y, g.p.y + DIFF)
+                };
+
+                // `A` costs 3 tokens for `a` and `B` 1 token for `b`, so the
+                // cheaper one is pressed as many times as possible
+                let presses = if 3 * b >= a {
+                    fewest_first(a, b, p)
+                } else {
+                    fewest_first(b, a, p).map(|(b, a)| (a, b))
+                };
+                presses.map(|(a, b)| (a as i128, b as i128))
+            }
+            Solution::None => None,
+        };
+
+        let Some((a, b)) = presses else {
             continue;
-        }
+        };
 
-        let dividend_b = g.a.y * (g.p.x + DIFF) - g.a.x * (g.p.y + DIFF);
-        let divisor_b = g.a.y * g.b.x - g.a.x * g.b.y;
-        let (b, ok_b) = (dividend_b / divisor_b, dividend_b % divisor_b);
-        if ok_b != 0 {
+        if a < 0 || b < 0 {
             continue;
         }
 
@@ -102,6 +99,23 @@ pub fn solve<const DIFF: i64>(games: &[Game]) -> u64 {
     tokens
 }
 
+// Moves `target` with buttons that move `first` and `second`, all non-negative,
+// pressing the first one as few times as possible. Returns the presses of both.
+fn fewest_first(first: i64, second: i64, target: i64) -> Option<(i64, i64)> {
+    if second == 0 {
+        return match first {
+            0 => (target == 0).then_some((0, 0)),
+            _ => (target % first == 0).then_some((target / first, 0)),
+        };
+    }
+
+    // `first * presses` modulo `second` repeats after `second` presses
+    (0..second)
+        .take_while(|&presses| first * presses <= target)
+        .find(|&presses| (target - first * presses) % second == 0)
+        .map(|presses| (presses, (target - first * presses) / second))
+}
+
 #[cfg(test)]
 mod tests {
     use aoc_shared::input::load_text_input_from_file;
@@ -125,4 +139,35 @@ mod tests {
         let answer = part_two(&parsed);
         assert_eq!(79_352_015_273_424, answer);
     }
+
+    #[test]
+    fn test_collinear_buttons() {
+        let game = |a: (i64, i64), b: (i64, i64), p: i64| {
+            format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                a.0,
+                a.1,
+                b.0,
+                b.1,
+                p,
+                2 * p
+            )
+        };
+
+        // only B
+        let input = game((2, 4), (1, 2), 10);
+        assert_eq!(10, part_one(&parse_input(&input).unwrap()));
+
+        // as many A as possible: 2 * 6 + 2 * 1 beats 14 * 1
+        let input = game((6, 12), (1, 2), 14);
+        assert_eq!(2 * 3 + 2, part_one(&parse_input(&input).unwrap()));
+
+        // unreachable
+        let input = game((2, 4), (4, 8), 3);
+        assert_eq!(0, part_one(&parse_input(&input).unwrap()));
+
+        // a button that doesn't move the claw
+        let input = game((0, 0), (3, 6), 9);
+        assert_eq!(3, part_one(&parse_input(&input).unwrap()));
+    }
 }
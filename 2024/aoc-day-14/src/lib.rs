@@ -1,14 +1,10 @@
 use aoc_shared::grid::DIR4;
-use regex::Regex;
+use aoc_shared::scan;
 use std::error::Error;
-use std::sync::LazyLock;
 
 const WIDTH: i32 = 101;
 const HEIGHT: i32 = 103;
 
-static REGEX: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^p=(\d+),(\d+) v=(-?\d+),(-?\d+)$").unwrap());
-
 #[derive(Debug, Copy, Clone)]
 pub struct Robot {
     x: i32,
@@ -20,32 +16,8 @@ pub fn parse_input(input: &str) -> Result<Vec<Robot>, Box<dyn Error>> {
     let mut robots = vec![];
 
     for line in input.lines() {
-        let Some(c) = REGEX.captures(line) else {
-            return Err("Invalid input".into());
-        };
-
-        let Some(x) = c.get(1).and_then(|m| Some(m.as_str())) else {
-            return Err("Invalid input".into());
-        };
-
-        let Some(y) = c.get(2).and_then(|m| Some(m.as_str())) else {
-            return Err("Invalid input".into());
-        };
-
-        let Some(vx) = c.get(3).and_then(|m| Some(m.as_str())) else {
-            return Err("Invalid input".into());
-        };
-
-        let Some(vy) = c.get(4).and_then(|m| Some(m.as_str())) else {
-            return Err("Invalid input".into());
-        };
-
-        robots.push(Robot {
-            x: x.parse()?,
-            y: y.parse()?,
-            vx: vx.parse()?,
-            vy: vy.parse()?,
-        })
+        let (x, y, vx, vy) = scan!(line, "p={},{} v={},{}" => (i32, i32, i32, i32))?;
+        robots.push(Robot { x, y, vx, vy });
     }
 
     Ok(robots)
@@ -1,3 +1,4 @@
+use aoc_shared::blocks::try_split_blocks;
 use aoc_shared::grid::Direction;
 use std::error::Error;
 
@@ -8,13 +9,6 @@ const BOX: u8 = b'O';
 const LB: u8 = b'[';
 const RB: u8 = b']';
 
-#[derive(Debug, Copy, Clone)]
-enum ParseState {
-    ParseGrid,
-    ParseNewLine,
-    ParseRobot,
-}
-
 pub struct Input {
     pub grid: Vec<u8>,
     pub robot: Vec<Direction>,
@@ -25,8 +19,7 @@ pub struct Input {
 }
 
 pub fn parse_input(input: &str) -> Result<Input, Box<dyn Error>> {
-    let input = input.as_bytes();
-    let mut state = ParseState::ParseGrid;
+    let [map, moves] = try_split_blocks(input)?;
 
     let mut rows = 0;
     let mut cols = 0;
@@ -35,51 +28,27 @@ pub fn parse_input(input: &str) -> Result<Input, Box<dyn Error>> {
     let mut grid = vec![];
     let mut robot = vec![];
 
-    for (idx, &ch) in input.iter().enumerate() {
-        match state {
-            ParseState::ParseGrid => match ch {
-                b'\n' => {
-                    state = ParseState::ParseNewLine;
-                    rows += 1;
-                    if cols == 0 {
-                        cols = idx;
-                    }
-                }
-
-                ROBOT => {
-                    start_row = rows;
-                    start_col = idx;
-                    if cols != 0 {
-                        start_col %= cols + 1;
-                    }
-
-                    grid.push(ch);
-                }
-
-                _ => grid.push(ch),
-            },
-
-            ParseState::ParseNewLine => match ch {
-                b'\n' => state = ParseState::ParseRobot,
-                ch => {
-                    if ch == ROBOT {
-                        start_row = rows;
-                        start_col = 0;
-                    }
+    for line in map.lines() {
+        let line = line.as_bytes();
+        if let Some(col) = line.iter().position(|&ch| ch == ROBOT) {
+            start_row = rows;
+            start_col = col;
+        }
 
-                    grid.push(ch);
-                    state = ParseState::ParseGrid;
-                }
-            },
+        cols = line.len();
+        rows += 1;
+        grid.extend_from_slice(line);
+    }
 
-            ParseState::ParseRobot => match ch {
+    for line in moves.lines() {
+        for ch in line.bytes() {
+            match ch {
                 b'<' => robot.push(Direction::Left),
                 b'>' => robot.push(Direction::Right),
                 b'^' => robot.push(Direction::Up),
                 b'v' => robot.push(Direction::Down),
-                b'\n' => {}
                 _ => return Err(format!("Invalid character '{}'", ch).into()),
-            },
+            }
         }
     }
 
@@ -1,7 +1,7 @@
-use aoc_shared::input::load_text_input_from_file;
+use aoc_shared::input_locator;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
-use aoc_day_17::{parse_input, part_one_v1, part_one_v2, part_two_v1, part_two_v2};
+use aoc_day_17::{parse_input, part_one_v1, part_one_v2, part_two_v1, part_two_v2, part_two_v3};
 
 criterion_group!(
     benches,
@@ -12,7 +12,7 @@ criterion_group!(
 criterion_main!(benches);
 
 fn benchmark_input_parsing(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2024, 17).load().unwrap();
 
     c.bench_function("parsing", |b| {
         b.iter(|| parse_input(&input));
@@ -20,7 +20,7 @@ fn benchmark_input_parsing(c: &mut Criterion) {
 }
 
 fn benchmark_part_one(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2024, 17).load().unwrap();
     let parsed = parse_input(&input).unwrap();
 
     c.bench_function("part-1/v1", |b| {
@@ -33,7 +33,7 @@ fn benchmark_part_one(c: &mut Criterion) {
 }
 
 fn benchmark_part_two(c: &mut Criterion) {
-    let input = load_text_input_from_file("inputs/input.txt");
+    let input = input_locator!(2024, 17).load().unwrap();
     let parsed = parse_input(&input).unwrap();
 
     c.bench_function("part-2/v1", |b| {
@@ -43,4 +43,8 @@ fn benchmark_part_two(c: &mut Criterion) {
     c.bench_function("part-2/v2", |b| {
         b.iter(|| part_two_v2(black_box(&parsed)));
     });
+
+    c.bench_function("part-2/v3", |b| {
+        b.iter(|| part_two_v3(black_box(&parsed)));
+    });
 }
@@ -15,6 +15,9 @@ const CDV: u8 = 7;
 
 mod compiled;
 mod interpreted;
+mod symbolic;
+
+pub use symbolic::{disassemble, AnalysisError};
 
 pub struct Input {
     rom: Vec<u8>,
@@ -88,6 +91,10 @@ pub fn part_two_v2(input: &Input) -> u64 {
     compiled::part_two(input)
 }
 
+pub fn part_two_v3(input: &Input) -> Result<u64, AnalysisError> {
+    symbolic::part_two(input)
+}
+
 #[cfg(test)]
 mod tests {
     use aoc_shared::input::load_text_input_from_file;
@@ -129,4 +136,13 @@ mod tests {
         let answer = part_two_v2(&parsed);
         assert_eq!(106086382266778, answer);
     }
+
+    #[test]
+    fn test_part_two_v3() {
+        let input = load_text_input_from_file("inputs/input.txt");
+        let parsed = parse_input(&input).unwrap();
+
+        let answer = part_two_v3(&parsed);
+        assert_eq!(Ok(106086382266778), answer);
+    }
 }
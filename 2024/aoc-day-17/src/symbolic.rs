@@ -0,0 +1,414 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Write};
+
+use crate::{interpreted, Input, A, ADV, B, BDV, BST, BXC, BXL, C, CDV, JNZ, OUT};
+
+/// Why a program cannot be disassembled or solved by [`part_two`]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum AnalysisError {
+    /// The program ends in the middle of an instruction
+    MissingOperand,
+    InvalidOpcode {
+        pc: usize,
+        opcode: u8,
+    },
+    /// The combo operand 7 is reserved
+    InvalidOperand {
+        pc: usize,
+    },
+    /// The program must end with `jnz 0` and contain no other jumps
+    NotALoop {
+        pc: usize,
+    },
+    /// The loop must shift `a` right by 3 bits exactly once
+    Shift {
+        found: usize,
+    },
+    /// The loop must print exactly one value
+    Output {
+        found: usize,
+    },
+    /// The register is read before it is written in the loop, so the output
+    /// depends on more than the value of `a`
+    CarriedRegister {
+        pc: usize,
+        register: char,
+    },
+    /// No value of `a` makes the program print itself
+    NoSolution,
+}
+
+impl Display for AnalysisError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnalysisError::MissingOperand => write!(f, "the last instruction has no operand"),
+            AnalysisError::InvalidOpcode { pc, opcode } => {
+                write!(f, "{}: invalid opcode {}", pc, opcode)
+            }
+            AnalysisError::InvalidOperand { pc } => {
+                write!(f, "{}: the combo operand 7 is reserved", pc)
+            }
+            AnalysisError::NotALoop { pc } => write!(
+                f,
+                "{}: the program must be a single loop ending with `jnz 0`",
+                pc
+            ),
+            AnalysisError::Shift { found } => write!(
+                f,
+                "the loop must contain exactly one `adv 3`, found {} writes to register A",
+                found
+            ),
+            AnalysisError::Output { found } => write!(
+                f,
+                "the loop must contain exactly one `out`, found {}",
+                found
+            ),
+            AnalysisError::CarriedRegister { pc, register } => write!(
+                f,
+                "{}: register {} is read before it is written in the loop",
+                pc, register
+            ),
+            AnalysisError::NoSolution => write!(f, "the program cannot print itself"),
+        }
+    }
+}
+
+impl Error for AnalysisError {}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Operand {
+    Literal(u8),
+    Register(usize),
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operand::Literal(value) => write!(f, "{}", value),
+            Operand::Register(reg) => write!(f, "{}", register_name(*reg)),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Instruction {
+    pc: usize,
+    opcode: u8,
+    operand: Operand,
+}
+
+fn register_name(reg: usize) -> char {
+    (b'a' + reg as u8) as char
+}
+
+fn decode(rom: &[u8]) -> Result<Vec<Instruction>, AnalysisError> {
+    if rom.len() & 1 != 0 {
+        return Err(AnalysisError::MissingOperand);
+    }
+
+    let mut program = vec![];
+    for (idx, chunk) in rom.chunks_exact(2).enumerate() {
+        let (pc, opcode, operand) = (2 * idx, chunk[0], chunk[1]);
+
+        let operand = match opcode {
+            BXL | JNZ | BXC => Operand::Literal(operand),
+            ADV | BST | OUT | BDV | CDV => match operand {
+                0..4 => Operand::Literal(operand),
+                4..7 => Operand::Register((operand - 4) as usize),
+                _ => return Err(AnalysisError::InvalidOperand { pc }),
+            },
+            _ => return Err(AnalysisError::InvalidOpcode { pc, opcode }),
+        };
+
+        program.push(Instruction {
+            pc,
+            opcode,
+            operand,
+        });
+    }
+
+    Ok(program)
+}
+
+// Whether the program is a `do { ... } while (a != 0)` loop
+fn is_loop(program: &[Instruction]) -> bool {
+    let Some((last, body)) = program.split_last() else {
+        return false;
+    };
+
+    last.opcode == JNZ
+        && last.operand == Operand::Literal(0)
+        && body.iter().all(|instr| instr.opcode != JNZ)
+}
+
+fn format_instruction(instr: &Instruction) -> String {
+    let divide = |target: char| match instr.operand {
+        Operand::Literal(shift) => format!("{} = a / {};", target, 1u64 << shift),
+        Operand::Register(reg) => format!("{} = a / 2.pow({});", target, register_name(reg)),
+    };
+
+    let modulo = |operand: Operand| match operand {
+        Operand::Literal(value) => format!("{}", value),
+        Operand::Register(reg) => format!("{} % 8", register_name(reg)),
+    };
+
+    match instr.opcode {
+        ADV => divide('a'),
+        BXL => format!("b = b ^ {};", instr.operand),
+        BST => format!("b = {};", modulo(instr.operand)),
+        JNZ => format!("if a != 0 {{ goto {}; }}", instr.operand),
+        BXC => "b = b ^ c;".to_string(),
+        OUT => format!("print {};", modulo(instr.operand)),
+        BDV => divide('b'),
+        CDV => divide('c'),
+        _ => unreachable!(),
+    }
+}
+
+/// Prints the program as pseudo-code. Programs of the usual shape are printed as
+/// a `do-while` loop, and any other jumps as `goto` to the instruction offset.
+pub fn disassemble(rom: &[u8]) -> Result<String, AnalysisError> {
+    let program = decode(rom)?;
+    let mut out = String::new();
+
+    if is_loop(&program) {
+        writeln!(out, "do {{").unwrap();
+        for instr in program[..program.len() - 1].iter() {
+            writeln!(out, "    {}", format_instruction(instr)).unwrap();
+        }
+        writeln!(out, "}} while (a != 0)").unwrap();
+    } else {
+        for instr in program.iter() {
+            writeln!(out, "{:>3}: {}", instr.pc, format_instruction(instr)).unwrap();
+        }
+    }
+
+    Ok(out)
+}
+
+// Verifies that the program is a loop whose output depends only on the value of
+// `a` at the start of each iteration, and that `a` loses its 3 lowest bits in
+// each iteration. Returns the loop body.
+fn analyse(rom: &[u8]) -> Result<Vec<Instruction>, AnalysisError> {
+    let mut program = decode(rom)?;
+
+    if !is_loop(&program) {
+        let pc = program
+            .iter()
+            .find(|instr| instr.opcode == JNZ)
+            .or(program.last())
+            .map_or(0, |instr| instr.pc);
+        return Err(AnalysisError::NotALoop { pc });
+    }
+    program.pop();
+
+    let shifts = program.iter().filter(|instr| instr.opcode == ADV);
+    let found = shifts.clone().count();
+    if found != 1
+        || shifts
+            .clone()
+            .any(|instr| instr.operand != Operand::Literal(3))
+    {
+        return Err(AnalysisError::Shift { found });
+    }
+
+    let found = program.iter().filter(|instr| instr.opcode == OUT).count();
+    if found != 1 {
+        return Err(AnalysisError::Output { found });
+    }
+
+    // B and C must not carry any state from the previous iteration
+    let mut written = [true, false, false];
+    for instr in program.iter() {
+        let mut reads = vec![];
+        if let Operand::Register(reg) = instr.operand {
+            reads.push(reg);
+        }
+
+        match instr.opcode {
+            BXL => reads.push(B),
+            BXC => reads.extend([B, C]),
+            _ => {}
+        }
+
+        if let Some(&reg) = reads.iter().find(|&&reg| !written[reg]) {
+            return Err(AnalysisError::CarriedRegister {
+                pc: instr.pc,
+                register: register_name(reg).to_ascii_uppercase(),
+            });
+        }
+
+        match instr.opcode {
+            BXL | BST | BXC | BDV => written[B] = true,
+            CDV => written[C] = true,
+            _ => {}
+        }
+    }
+
+    Ok(program)
+}
+
+// Runs one iteration of the loop body and returns the printed value
+fn run_body(body: &[Instruction], a: u64) -> u8 {
+    let mut reg = [a, 0, 0];
+    let mut printed = 0;
+
+    for instr in body {
+        let value = match instr.operand {
+            Operand::Literal(value) => value as u64,
+            Operand::Register(reg_idx) => reg[reg_idx],
+        };
+
+        match instr.opcode {
+            ADV => reg[A] >>= value,
+            BXL => reg[B] ^= value,
+            BST => reg[B] = value % 8,
+            BXC => reg[B] ^= reg[C],
+            OUT => printed = (value % 8) as u8,
+            BDV => reg[B] = reg[A].checked_shr(value as u32).unwrap_or(0),
+            CDV => reg[C] = reg[A].checked_shr(value as u32).unwrap_or(0),
+            _ => unreachable!(),
+        }
+    }
+
+    printed
+}
+
+/// Finds the lowest value of `a` that makes the program print itself, for any
+/// program of the `do { ...; a = a / 8; ...; print x; } while (a != 0)` family.
+pub fn part_two(input: &Input) -> Result<u64, AnalysisError> {
+    let body = analyse(&input.rom)?;
+
+    // Every iteration removes the lowest 3 bits of `a`, so the last value is
+    // printed from the highest 3 bits. Build `a` from the highest bits while
+    // keeping every candidate that prints the end of the program, in ascending
+    // order, so that the first complete candidate is the lowest one.
+    let mut candidates = vec![0u64];
+    for &expected in input.rom.iter().rev() {
+        let mut next = vec![];
+        for value in candidates {
+            if value.leading_zeros() < 3 {
+                continue;
+            }
+
+            for n in 0..8 {
+                let candidate = (value << 3) | n;
+
+                // `a` must not become 0 before the whole program is printed
+                if candidate != 0 && run_body(&body, candidate) == expected {
+                    next.push(candidate);
+                }
+            }
+        }
+
+        candidates = next;
+    }
+
+    let answer = *candidates.first().ok_or(AnalysisError::NoSolution)?;
+
+    let mut reg = input.reg;
+    reg[A] = answer;
+    let out = interpreted::part_one(&Input {
+        rom: input.rom.clone(),
+        reg,
+    });
+
+    let expected = input
+        .rom
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    if out != expected {
+        return Err(AnalysisError::NoSolution);
+    }
+
+    Ok(answer)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_shared::input::load_text_input_from_file;
+
+    use crate::parse_input;
+    use crate::symbolic::{disassemble, part_two, AnalysisError};
+
+    #[test]
+    fn test_part_two() {
+        let input = load_text_input_from_file("inputs/input.txt");
+        let parsed = parse_input(&input).unwrap();
+        assert_eq!(Ok(106086382266778), part_two(&parsed));
+
+        let example = "Register A: 2024\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,5,4,3,0\n";
+        let parsed = parse_input(example).unwrap();
+        assert_eq!(Ok(117440), part_two(&parsed));
+    }
+
+    #[test]
+    fn test_other_programs() {
+        // a single `bxl`, and the value is printed before the shift
+        let input =
+            "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 2,4,1,3,7,5,5,5,4,0,0,3,3,0\n";
+        let parsed = parse_input(input).unwrap();
+        assert_eq!(Ok(1653023129785), part_two(&parsed));
+    }
+
+    #[test]
+    fn test_errors() {
+        let program = |rom: &str| {
+            parse_input(&format!(
+                "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+                rom
+            ))
+            .unwrap()
+        };
+
+        // the example from part one shifts by 1 bit
+        assert_eq!(
+            Err(AnalysisError::Shift { found: 1 }),
+            part_two(&program("0,1,5,4,3,0"))
+        );
+        assert_eq!(
+            Err(AnalysisError::NotALoop { pc: 2 }),
+            part_two(&program("0,3,3,2,5,4,3,0"))
+        );
+        assert_eq!(
+            Err(AnalysisError::Output { found: 2 }),
+            part_two(&program("0,3,5,4,5,4,3,0"))
+        );
+        assert_eq!(
+            Err(AnalysisError::CarriedRegister {
+                pc: 2,
+                register: 'B'
+            }),
+            part_two(&program("0,3,1,2,5,5,3,0"))
+        );
+        assert_eq!(
+            Err(AnalysisError::InvalidOperand { pc: 0 }),
+            part_two(&program("2,7,5,4,3,0"))
+        );
+    }
+
+    #[test]
+    fn test_disassemble() {
+        let input = load_text_input_from_file("inputs/input.txt");
+        let parsed = parse_input(&input).unwrap();
+
+        let expected = "\
+do {
+    b = a % 8;
+    b = b ^ 5;
+    c = a / 2.pow(b);
+    b = b ^ 6;
+    a = a / 8;
+    b = b ^ c;
+    print b % 8;
+} while (a != 0)
+";
+        assert_eq!(Ok(expected.to_string()), disassemble(&parsed.rom));
+
+        let expected = "  0: a = a / 2;\n  2: print a % 8;\n  4: if a != 0 { goto 2; }\n";
+        assert_eq!(Ok(expected.to_string()), disassemble(&[0, 1, 5, 4, 3, 2]));
+    }
+}
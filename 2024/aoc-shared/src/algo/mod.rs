@@ -1,6 +1,4 @@
+pub use aoc_core::union_find::{UnionFind, UnionFindAny};
 pub use bucket_queue::BucketQueue;
-pub use union_find::UnionFind;
-pub use union_find::UnionFindAny;
 
 mod bucket_queue;
-mod union_find;
@@ -0,0 +1,213 @@
+use std::collections::BTreeMap;
+use std::fs::read_dir;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use crate::input::{normalize_input, try_load_text_input_from_file};
+
+/// Generates tests that run each variant of each part on the examples in the
+/// `inputs` directory of the calling crate. An example is an `example-<N>.txt`
+/// file with an `example-<N>.answers` sidecar, containing the expected answer
+/// of each part, e.g. `part_one: 11`. Examples without an answer for a part are
+/// skipped, so adding a regression case is only a matter of dropping in the files.
+///
+/// ```ignore
+/// aoc_shared::example_tests! {
+///     parse: |input| parse_input(input).unwrap(),
+///     part_one: { part_one_v1, part_one_v2 },
+///     part_two: { part_two: |(a, b)| part_two(a, b) },
+/// }
+/// ```
+///
+/// Each variant is a function that receives a reference to the parsed input, or a
+/// closure if it needs more than that. Without `parse`, the input is passed as is.
+#[macro_export]
+macro_rules! example_tests {
+    (parse: $parse:expr, $($part:ident: { $($variant:ident $(: $solve:expr)?),+ $(,)? }),+ $(,)?) => {
+        // Only modules are generated, so that their names never shadow the solutions
+        #[cfg(test)]
+        mod examples {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $(
+                mod $part {
+                    #[allow(unused_imports)]
+                    use super::*;
+
+                    $(
+                        mod $variant {
+                            #[allow(unused_imports)]
+                            use super::*;
+
+                            #[test]
+                            fn matches_examples() {
+                                $crate::examples::check_examples(
+                                    env!("CARGO_MANIFEST_DIR"),
+                                    stringify!($part),
+                                    $parse,
+                                    $crate::example_tests!(@solve $variant $(: $solve)?),
+                                );
+                            }
+                        }
+                    )+
+                }
+            )+
+        }
+    };
+
+    ($($part:ident: { $($body:tt)* }),+ $(,)?) => {
+        $crate::example_tests!(
+            parse: |input: &str| input.to_owned(),
+            $($part: { $($body)* }),+
+        );
+    };
+
+    (@solve $variant:ident) => {
+        |parsed| $variant(parsed)
+    };
+
+    (@solve $variant:ident: $solve:expr) => {
+        $solve
+    };
+}
+
+/// An answer that can be compared with the text in an `.answers` file
+pub trait Answer {
+    fn to_answer(&self) -> String;
+}
+
+macro_rules! impl_answer {
+    ($($t:ty),+) => {
+        $(
+            impl Answer for $t {
+                fn to_answer(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )+
+    };
+}
+
+impl_answer!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char, bool
+);
+impl_answer!(String, &str);
+
+impl<T: Answer> Answer for Option<T> {
+    fn to_answer(&self) -> String {
+        match self {
+            Some(value) => value.to_answer(),
+            None => "None".to_owned(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Example {
+    pub path: PathBuf,
+    pub input: String,
+    /// The expected answer of each part
+    pub answers: BTreeMap<String, String>,
+}
+
+/// Loads all `inputs/example-<N>.txt` files of a crate, together with their answers
+pub fn load_examples<P: AsRef<Path>>(manifest_dir: P) -> std::io::Result<Vec<Example>> {
+    let dir = manifest_dir.as_ref().join("inputs");
+
+    let mut paths = vec![];
+    for entry in read_dir(&dir)? {
+        let path = entry?.path();
+        let name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default();
+        if name.starts_with("example-") && name.ends_with(".txt") {
+            paths.push(path);
+        }
+    }
+    paths.sort_unstable();
+
+    let mut examples = vec![];
+    for path in paths {
+        let input = normalize_input(&try_load_text_input_from_file(&path)?);
+        let answers = match try_load_text_input_from_file(path.with_extension("answers")) {
+            Ok(text) => parse_answers(&text),
+            Err(e) if e.kind() == ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(e),
+        };
+
+        examples.push(Example {
+            path,
+            input,
+            answers,
+        });
+    }
+
+    Ok(examples)
+}
+
+fn parse_answers(text: &str) -> BTreeMap<String, String> {
+    text.lines()
+        .filter_map(|line| line.split_once(':'))
+        .map(|(part, answer)| (part.trim().to_owned(), answer.trim().to_owned()))
+        .collect()
+}
+
+/// Solves each example that has an answer for `part` and panics on the first wrong
+/// answer. Also panics if there are no such examples, so that a test cannot pass
+/// without checking anything.
+pub fn check_examples<P, A, F, S>(manifest_dir: &str, part: &str, parse: F, solve: S)
+where
+    A: Answer,
+    F: Fn(&str) -> P,
+    S: Fn(&P) -> A,
+{
+    let examples = load_examples(manifest_dir).unwrap();
+
+    let mut checked = 0;
+    for example in examples.iter() {
+        let Some(expected) = example.answers.get(part) else {
+            continue;
+        };
+
+        let answer = solve(&parse(&example.input)).to_answer();
+        assert_eq!(
+            expected,
+            &answer,
+            "wrong answer of {} for {}",
+            part,
+            example.path.display()
+        );
+
+        checked += 1;
+    }
+
+    assert!(
+        checked > 0,
+        "no examples with an answer for {} in {}/inputs",
+        part,
+        manifest_dir
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = parse_answers("part_one: 11\n\npart_two:31 \n");
+        assert_eq!(Some("11"), answers.get("part_one").map(String::as_str));
+        assert_eq!(Some("31"), answers.get("part_two").map(String::as_str));
+        assert_eq!(2, answers.len());
+    }
+
+    #[test]
+    fn test_answer() {
+        assert_eq!("42", 42u64.to_answer());
+        assert_eq!("-1", Some(-1i32).to_answer());
+        assert_eq!("None", None::<u32>.to_answer());
+        assert_eq!("abc", "abc".to_answer());
+    }
+}
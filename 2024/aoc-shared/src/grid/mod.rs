@@ -1,17 +1 @@
-pub use direction::Direction;
-pub use point::Point;
-
-pub const DIR4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
-pub const DIR8: [(isize, isize); 8] = [
-    (-1, - 1),
-    (-1, 0),
-    (-1, 1),
-    (0, -1),
-    (0, 1),
-    (1, -1),
-    (1, 0),
-    (1, 1),
-];
-
-mod direction;
-mod point;
+pub use aoc_core::grid::{DIR4, DIR8, Direction, Point};
@@ -1,7 +1,9 @@
+pub use aoc_core::{blocks, hashing, input_locator, linalg, math, parallel, scan};
+
 pub mod algo;
+pub mod examples;
 pub mod grid;
-pub mod hashing;
 pub mod input;
 pub mod parsing;
+pub mod scanner;
 pub mod util;
-pub mod math;
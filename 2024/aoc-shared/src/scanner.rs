@@ -0,0 +1,499 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// A cursor over the bytes of the puzzle input, which extracts numbers and
+/// tokens without allocating. The `read_` functions return `None` instead of
+/// panicking, and leave the scanner where the failing token starts.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Scanner<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(input: &'a [u8]) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    #[inline(always)]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    #[inline(always)]
+    pub fn remaining(&self) -> &'a [u8] {
+        &self.input[self.pos..]
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.pos >= self.input.len()
+    }
+
+    #[inline(always)]
+    pub fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).copied()
+    }
+
+    #[inline(always)]
+    pub fn next_byte(&mut self) -> Option<u8> {
+        let b = self.peek()?;
+        self.pos += 1;
+        Some(b)
+    }
+
+    /// Moves forward by `n` bytes, but not past the end of the input
+    #[inline(always)]
+    pub fn skip(&mut self, n: usize) {
+        self.pos = (self.pos + n).min(self.input.len());
+    }
+
+    /// Skips bytes until `pred` matches. Returns `false` if the end of the
+    /// input was reached without a match.
+    #[inline]
+    pub fn skip_until<P: Fn(u8) -> bool>(&mut self, pred: P) -> bool {
+        match self.remaining().iter().position(|&b| pred(b)) {
+            Some(offset) => {
+                self.pos += offset;
+                true
+            }
+            None => {
+                self.pos = self.input.len();
+                false
+            }
+        }
+    }
+
+    /// Returns the longest prefix whose bytes match `pred` and moves past it
+    #[inline]
+    pub fn take_while<P: Fn(u8) -> bool>(&mut self, pred: P) -> &'a [u8] {
+        let rest = self.remaining();
+        let len = rest.iter().position(|&b| !pred(b)).unwrap_or(rest.len());
+
+        self.pos += len;
+        &rest[..len]
+    }
+
+    #[inline]
+    pub fn skip_whitespace(&mut self) {
+        self.take_while(|b| b.is_ascii_whitespace());
+    }
+
+    /// Moves past `literal` if the input continues with it
+    #[inline]
+    pub fn expect_literal(&mut self, literal: &[u8]) -> bool {
+        if self.remaining().starts_with(literal) {
+            self.pos += literal.len();
+            return true;
+        }
+
+        false
+    }
+
+    /// Parses the unsigned number at the current position
+    #[inline]
+    pub fn read_uint<T: Integer>(&mut self) -> Option<T> {
+        let start = self.pos;
+
+        let mut value = T::ZERO;
+        let mut digits = 0;
+        while let Some(d) = self.peek().and_then(digit) {
+            value = match value.push_digit(d) {
+                Some(v) => v,
+                None => {
+                    self.pos = start;
+                    return None;
+                }
+            };
+
+            self.pos += 1;
+            digits += 1;
+        }
+
+        (digits > 0).then_some(value)
+    }
+
+    /// Parses the number, with an optional sign, at the current position
+    #[inline]
+    pub fn read_int<T: Integer>(&mut self) -> Option<T> {
+        let start = self.pos;
+
+        let negative = match self.peek() {
+            Some(b'-') => true,
+            Some(b'+') => false,
+            _ => return self.read_uint(),
+        };
+
+        self.pos += 1;
+
+        let mut value = T::ZERO;
+        let mut digits = 0;
+        while let Some(d) = self.peek().and_then(digit) {
+            let next = if negative {
+                value.push_negative_digit(d)
+            } else {
+                value.push_digit(d)
+            };
+
+            value = match next {
+                Some(v) => v,
+                None => break,
+            };
+
+            self.pos += 1;
+            digits += 1;
+        }
+
+        if digits == 0 || self.peek().and_then(digit).is_some() {
+            self.pos = start;
+            return None;
+        }
+
+        Some(value)
+    }
+
+    /// Skips to the next digit and parses the unsigned number starting there.
+    /// Returns `None` at the end of the input. A number that does not fit in `T`
+    /// is an [`Overflow`] error, and the scanner moves past it, so that the
+    /// following numbers can still be read.
+    #[inline]
+    pub fn next_uint<T: Integer>(&mut self) -> Option<Result<T, Overflow>> {
+        if !self.skip_until(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        Some(self.read_uint().ok_or_else(|| self.overflow()))
+    }
+
+    /// Like [`Scanner::next_uint`], but a `-` right before the digits makes the number negative
+    #[inline]
+    pub fn next_int<T: Integer>(&mut self) -> Option<Result<T, Overflow>> {
+        loop {
+            if !self.skip_until(|b| b.is_ascii_digit() || b == b'-') {
+                return None;
+            }
+
+            if self.peek() == Some(b'-')
+                && !self.input.get(self.pos + 1).is_some_and(u8::is_ascii_digit)
+            {
+                self.pos += 1;
+                continue;
+            }
+
+            return Some(self.read_int().ok_or_else(|| self.overflow()));
+        }
+    }
+
+    // Called when a number at the current position could not be read, which
+    // only happens on overflow. Moves past the number.
+    #[cold]
+    fn overflow(&mut self) -> Overflow {
+        let position = self.pos;
+        self.expect_literal(b"-");
+        self.take_while(|b| b.is_ascii_digit());
+        Overflow { position }
+    }
+
+    /// Parses exactly `width` digits at the current position
+    ///
+    /// # Panics
+    ///
+    /// If `width` is more than 8
+    #[inline]
+    pub fn read_fixed_uint(&mut self, width: usize) -> Option<u32> {
+        assert!(
+            width <= 8,
+            "cannot read {} digits at once, at most 8",
+            width
+        );
+
+        let digits = self.remaining().get(..width)?;
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+
+        self.pos += width;
+        Some(parse_digits_swar(digits))
+    }
+
+    /// Returns the rest of the current line, without the line terminator
+    /// (`\n` or `\r\n`) and moves to the start of the next one
+    #[inline]
+    pub fn next_line(&mut self) -> Option<&'a [u8]> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let line = self.take_while(|b| b != b'\n');
+        self.skip(1);
+
+        Some(line.strip_suffix(b"\r").unwrap_or(line))
+    }
+
+    /// Returns all lines up to the next blank line and moves past it
+    #[inline]
+    pub fn next_paragraph(&mut self) -> Option<&'a [u8]> {
+        while self.remaining().starts_with(b"\n") || self.remaining().starts_with(b"\r\n") {
+            self.next_line();
+        }
+
+        if self.is_empty() {
+            return None;
+        }
+
+        let start = self.pos;
+        let mut end = self.pos;
+        while let Some(line) = self.next_line() {
+            if line.is_empty() {
+                break;
+            }
+            end = self.pos;
+        }
+
+        let paragraph = &self.input[start..end];
+        let paragraph = paragraph.strip_suffix(b"\n").unwrap_or(paragraph);
+        Some(paragraph.strip_suffix(b"\r").unwrap_or(paragraph))
+    }
+
+    pub fn lines(self) -> impl Iterator<Item = &'a [u8]> {
+        let mut scanner = self;
+        std::iter::from_fn(move || scanner.next_line())
+    }
+
+    pub fn paragraphs(self) -> impl Iterator<Item = &'a [u8]> {
+        let mut scanner = self;
+        std::iter::from_fn(move || scanner.next_paragraph())
+    }
+}
+
+impl<'a> From<&'a str> for Scanner<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::new(value.as_bytes())
+    }
+}
+
+impl<'a> From<&'a [u8]> for Scanner<'a> {
+    fn from(value: &'a [u8]) -> Self {
+        Self::new(value)
+    }
+}
+
+/// The error returned when a number in the input does not fit in the requested type
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Overflow {
+    /// The offset in the input at which the number starts
+    pub position: usize,
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "the number at {} is out of range", self.position)
+    }
+}
+
+impl Error for Overflow {}
+
+/// All the signed integers in the input, i.e. `"p=0,4 v=3,-3"` yields `0, 4, 3, -3`.
+/// A number that does not fit in `T` yields an [`Overflow`] error, and the
+/// iteration continues with the next number.
+pub fn ints<'a, T: Integer>(
+    input: impl Into<Scanner<'a>>,
+) -> impl Iterator<Item = Result<T, Overflow>> + 'a {
+    let mut scanner = input.into();
+    std::iter::from_fn(move || scanner.next_int())
+}
+
+/// All the unsigned integers in the input, ignoring any signs. Overflows are
+/// handled like in [`ints`].
+pub fn uints<'a, T: Integer>(
+    input: impl Into<Scanner<'a>>,
+) -> impl Iterator<Item = Result<T, Overflow>> + 'a {
+    let mut scanner = input.into();
+    std::iter::from_fn(move || scanner.next_uint())
+}
+
+/// Parses up to 8 ASCII digits at once. The digits are not validated.
+///
+/// # Panics
+///
+/// If there are more than 8 digits
+// https://lemire.me/blog/2022/01/21/swar-explained-parsing-eight-digits/
+#[inline]
+pub fn parse_digits_swar(digits: &[u8]) -> u32 {
+    assert!(
+        digits.len() <= 8,
+        "cannot parse {} digits at once, at most 8",
+        digits.len()
+    );
+
+    const MASK: u64 = 0x000000FF000000FF;
+    const MUL_1: u64 = 0x000F424000000064; // 100 + (1000000ULL << 32)
+    const MUL_2: u64 = 0x0000271000000001; // 1 + (10000ULL << 32)
+
+    // Left-pad with zeroes up to 8 digits
+    let mut buffer = [b'0'; 8];
+    buffer[8 - digits.len()..].copy_from_slice(digits);
+
+    let mut n = u64::from_le_bytes(buffer);
+
+    // Subtract b'0' from each byte
+    n -= 0x3030303030303030;
+
+    // pyramidal computation
+    n = (n * 10) + (n >> 8);
+    n = ((n & MASK).wrapping_mul(MUL_1) + ((n >> 16) & MASK).wrapping_mul(MUL_2)) >> 32;
+    n as u32
+}
+
+#[inline(always)]
+fn digit(b: u8) -> Option<u8> {
+    b.is_ascii_digit().then(|| b - b'0')
+}
+
+/// The integer types that the [`Scanner`] can parse
+pub trait Integer: Copy {
+    const ZERO: Self;
+
+    /// `self * 10 + digit`, or `None` on overflow
+    fn push_digit(self, digit: u8) -> Option<Self>;
+
+    /// `self * 10 - digit`, or `None` on overflow (or if the type is unsigned)
+    fn push_negative_digit(self, digit: u8) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+
+                #[inline(always)]
+                fn push_digit(self, digit: u8) -> Option<Self> {
+                    self.checked_mul(10)?.checked_add(digit as Self)
+                }
+
+                #[inline(always)]
+                fn push_negative_digit(self, digit: u8) -> Option<Self> {
+                    self.checked_mul(10)?.checked_sub(digit as Self)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_uint() {
+        let mut s = Scanner::from("#1 @ 12,3: 4x45");
+        let values: Vec<u32> = std::iter::from_fn(|| s.next_uint())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(vec![1, 12, 3, 4, 45], values);
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn test_next_int() {
+        let values: Vec<i32> = ints("p=0,4 v=3,-3 - -x -7-2")
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(vec![0, 4, 3, -3, -7, -2], values);
+
+        let values: Vec<i8> = ints("-128 127").collect::<Result<_, _>>().unwrap();
+        assert_eq!(vec![i8::MIN, i8::MAX], values);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let mut s = Scanner::from("256");
+        assert_eq!(None, s.read_uint::<u8>());
+        assert_eq!(0, s.position());
+        assert_eq!(Some(256), s.read_uint::<u16>());
+
+        let mut s = Scanner::from("-129");
+        assert_eq!(None, s.read_int::<i8>());
+        assert_eq!(None, s.read_int::<u32>());
+        assert_eq!(0, s.position());
+    }
+
+    #[test]
+    fn test_overflow_does_not_end_iteration() {
+        let values: Vec<_> = uints::<u8>("1 300 2").collect();
+        assert_eq!(vec![Ok(1), Err(Overflow { position: 2 }), Ok(2)], values);
+
+        let values: Vec<_> = ints::<i8>("x=-200,y=-3").collect();
+        assert_eq!(vec![Err(Overflow { position: 2 }), Ok(-3)], values);
+
+        let mut s = Scanner::from("99999999999 ");
+        assert_eq!(Some(Err(Overflow { position: 0 })), s.next_uint::<u32>());
+        assert_eq!(None, s.next_uint::<u32>());
+    }
+
+    #[test]
+    fn test_literals() {
+        let mut s = Scanner::from("mul(2,4)");
+        assert!(!s.expect_literal(b"mul["));
+        assert!(s.expect_literal(b"mul("));
+        assert_eq!(Some(2u32), s.read_uint());
+        assert!(s.expect_literal(b","));
+        assert_eq!(Some(4u32), s.read_uint());
+        assert_eq!(Some(b')'), s.next_byte());
+        assert_eq!(None, s.next_byte());
+    }
+
+    #[test]
+    fn test_take_while() {
+        let mut s = Scanner::from("abc123");
+        assert_eq!(b"abc", s.take_while(|b| b.is_ascii_alphabetic()));
+        assert!(s.skip_until(|b| b == b'3'));
+        assert_eq!(b"3", s.remaining());
+        assert!(!s.skip_until(|b| b == b'x'));
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn test_lines() {
+        let lines: Vec<&[u8]> = Scanner::from("a\r\nb\n\nc").lines().collect();
+        assert_eq!(vec![&b"a"[..], b"b", b"", b"c"], lines);
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        let input = "a\nb\n\n\nc\r\nd\r\n\r\ne\n";
+        let paragraphs: Vec<&[u8]> = Scanner::from(input).paragraphs().collect();
+        assert_eq!(vec![&b"a\nb"[..], b"c\r\nd", b"e"], paragraphs);
+    }
+
+    #[test]
+    fn test_swar() {
+        assert_eq!(12345678, parse_digits_swar(b"12345678"));
+        assert_eq!(42, parse_digits_swar(b"42"));
+        assert_eq!(7, parse_digits_swar(b"007"));
+        assert_eq!(0, parse_digits_swar(b""));
+
+        let mut s = Scanner::from("12345   67890");
+        assert_eq!(Some(12345), s.read_fixed_uint(5));
+        s.skip(3);
+        assert_eq!(Some(67890), s.read_fixed_uint(5));
+        assert_eq!(None, s.read_fixed_uint(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "at most 8")]
+    fn test_swar_too_many_digits() {
+        parse_digits_swar(b"123456789");
+    }
+
+    #[test]
+    #[should_panic(expected = "at most 8")]
+    fn test_read_fixed_uint_too_wide() {
+        Scanner::from("1234567890").read_fixed_uint(9);
+    }
+}
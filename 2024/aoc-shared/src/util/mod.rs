@@ -1,4 +1 @@
-pub use bitset::BitSet;
-pub use bitset::SmallBitSet;
-
-mod bitset;
+pub use aoc_core::bitset::{BitSet, SmallBitSet};
@@ -6,11 +6,8 @@ pub struct BitSet {
 impl BitSet {
     #[inline(always)]
     pub fn new(bits: usize) -> Self {
-        let mut size = bits / usize::BITS as usize;
-        size += ((bits % usize::BITS as usize) != 0) as usize;
-
         Self {
-            bits: vec![0usize; size],
+            bits: vec![0usize; bits.div_ceil(usize::BITS as usize)],
         }
     }
 
@@ -70,6 +67,12 @@ pub struct SmallBitSet<const N: usize> {
     bits: [u64; N],
 }
 
+impl<const N: usize> Default for SmallBitSet<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<const N: usize> SmallBitSet<N> {
     #[inline(always)]
     pub fn new() -> Self {
@@ -144,7 +147,7 @@ mod tests {
     fn test_bitset_no_set_bits() {
         let bitset = BitSet::new(usize::BITS as usize);
         for bit in 0..usize::BITS as usize {
-            assert_eq!(false, bitset.is_set(bit));
+            assert!(!bitset.is_set(bit));
         }
     }
 
@@ -179,6 +182,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_bitset_mark_and_count() {
+        let mut bitset = BitSet::new(200);
+        assert_eq!(0, bitset.count_ones());
+
+        assert!(bitset.mark(3));
+        assert!(bitset.mark(150));
+        assert!(!bitset.mark(3));
+        assert_eq!(2, bitset.count_ones());
+
+        bitset.unset(3);
+        assert_eq!(1, bitset.count_ones());
+    }
+
     #[test]
     fn test_create_small_bitset() {
         let bitset = SmallBitSet::<1>::new();
@@ -192,7 +209,7 @@ mod tests {
     fn test_small_bitset_no_set_bits() {
         let bitset = SmallBitSet::<1>::new();
         for bit in 0..bitset.bits() {
-            assert_eq!(false, bitset.is_set(bit));
+            assert!(!bitset.is_set(bit));
         }
     }
 
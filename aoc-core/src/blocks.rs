@@ -0,0 +1,251 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::parsing::{parse_trimmed, ParseError};
+
+/// A group of consecutive non-blank lines, separated from the
+/// other blocks by one or more blank lines
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Block<'a> {
+    /// 1-based number of the first line of the block
+    pub line: usize,
+    /// The text of the block, without the line break after the last line
+    pub text: &'a str,
+}
+
+impl<'a> Block<'a> {
+    pub fn lines(&self) -> impl Iterator<Item = &'a str> {
+        self.text.lines()
+    }
+
+    /// The lines of the block, together with their 1-based line number in the whole input
+    pub fn numbered_lines(&self) -> impl Iterator<Item = (usize, &'a str)> {
+        (self.line..).zip(self.text.lines())
+    }
+
+    /// The first line of the block, which is usually a header such as
+    /// `"seed-to-soil map:"`, and the rest of the block
+    pub fn split_header(&self) -> (&'a str, Block<'a>) {
+        let (header, body) = self.text.split_once('\n').unwrap_or((self.text, ""));
+        let header = header.strip_suffix('\r').unwrap_or(header);
+
+        let body = Block {
+            line: self.line + 1,
+            text: body,
+        };
+
+        (header, body)
+    }
+
+    /// Parses each trimmed line of the block. The error points to the line in the whole input.
+    pub fn try_parse_lines<R: FromStr>(&self) -> Result<Vec<R>, ParseError<R::Err>> {
+        self.numbered_lines()
+            .map(|(line, text)| parse_trimmed(text, line - 1))
+            .collect()
+    }
+
+    /// Parses the whole block with `parser`. Errors point to the first line of the block.
+    pub fn try_parse_with<T, E, F>(self, parser: F) -> Result<T, ParseError<E>>
+    where
+        F: FnOnce(Block<'a>) -> Result<T, E>,
+    {
+        parser(self).map_err(|source| ParseError {
+            line: self.line,
+            column: 1,
+            snippet: self.lines().next().unwrap_or_default().to_owned(),
+            source,
+        })
+    }
+}
+
+/// Splits the input into blocks of non-blank lines. Both `\n` and `\r\n` line endings
+/// are supported and lines containing only whitespace count as blank.
+pub fn blocks(input: &str) -> Blocks<'_> {
+    Blocks {
+        input,
+        offset: 0,
+        line: 1,
+    }
+}
+
+/// Splits the input into blocks, whose first line is a header, e.g.:
+///
+/// ```text
+/// seed-to-soil map:
+/// 50 98 2
+/// 52 50 48
+/// ```
+pub fn sections(input: &str) -> impl Iterator<Item = (&str, Block<'_>)> {
+    blocks(input).map(|block| block.split_header())
+}
+
+/// Parses each block of the input with `parser`
+pub fn try_parse_blocks<'a, T, E, F>(input: &'a str, mut parser: F) -> Result<Vec<T>, ParseError<E>>
+where
+    F: FnMut(Block<'a>) -> Result<T, E>,
+{
+    blocks(input)
+        .map(|block| block.try_parse_with(&mut parser))
+        .collect()
+}
+
+/// Splits the input into exactly `N` blocks, e.g. a grid followed by a list of moves
+pub fn try_split_blocks<const N: usize>(input: &str) -> Result<[Block<'_>; N], BlockCountError> {
+    let blocks: Vec<_> = blocks(input).collect();
+    let found = blocks.len();
+
+    blocks
+        .try_into()
+        .map_err(|_| BlockCountError { expected: N, found })
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct BlockCountError {
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl Display for BlockCountError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expected {} blocks of lines, but found {}",
+            self.expected, self.found
+        )
+    }
+}
+
+impl Error for BlockCountError {}
+
+pub struct Blocks<'a> {
+    input: &'a str,
+    offset: usize,
+    line: usize,
+}
+
+impl<'a> Iterator for Blocks<'a> {
+    type Item = Block<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut start = None;
+        let mut end = self.offset;
+
+        for raw in self.input[self.offset..].split_inclusive('\n') {
+            let blank = raw.trim().is_empty();
+
+            match start {
+                None if blank => {}
+                None => {
+                    start = Some((self.offset, self.line));
+                    end = self.offset + raw.trim_end_matches(['\r', '\n']).len();
+                }
+                Some(_) if blank => break,
+                Some(_) => end = self.offset + raw.trim_end_matches(['\r', '\n']).len(),
+            }
+
+            self.offset += raw.len();
+            self.line += 1;
+        }
+
+        start.map(|(start, line)| Block {
+            line,
+            text: &self.input[start..end],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocks() {
+        let input = "\n1000\n2000\n\n\n4000\r\n\r\n5000\r\n6000\r\n  \n";
+        let blocks: Vec<_> = blocks(input).collect();
+
+        assert_eq!(
+            vec![
+                Block {
+                    line: 2,
+                    text: "1000\n2000"
+                },
+                Block {
+                    line: 6,
+                    text: "4000"
+                },
+                Block {
+                    line: 8,
+                    text: "5000\r\n6000"
+                },
+            ],
+            blocks
+        );
+    }
+
+    #[test]
+    fn test_blocks_keep_leading_whitespace() {
+        let input = "    [D]\n[N] [C]\n 1   2\n\nmove 1 from 2 to 1";
+        let [stacks, moves] = try_split_blocks(input).unwrap();
+
+        assert_eq!("    [D]\n[N] [C]\n 1   2", stacks.text);
+        assert_eq!(
+            vec!["move 1 from 2 to 1"],
+            moves.lines().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "seeds: 79 14\r\n\r\nseed-to-soil map:\r\n50 98 2\r\n52 50 48\r\n";
+        let sections: Vec<_> = sections(input).collect();
+
+        assert_eq!(2, sections.len());
+        assert_eq!("seeds: 79 14", sections[0].0);
+        assert_eq!("", sections[0].1.text);
+
+        let (header, body) = sections[1];
+        assert_eq!("seed-to-soil map:", header);
+        assert_eq!(4, body.line);
+        assert_eq!(
+            vec![(4, "50 98 2"), (5, "52 50 48")],
+            body.numbered_lines().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_try_parse_lines_error() {
+        let input = "1\n2\n\n3\nx\n";
+        let parsed: Result<Vec<Vec<u32>>, _> = blocks(input).map(|b| b.try_parse_lines()).collect();
+
+        let err = parsed.unwrap_err();
+        assert_eq!(5, err.line);
+        assert_eq!("x", err.snippet);
+    }
+
+    #[test]
+    fn test_try_parse_blocks_error() {
+        let input = "a\nb\n\nc\n\nd\ne";
+        let parsed = try_parse_blocks(input, |block| match block.lines().count() {
+            2 => Ok(block.text),
+            n => Err(n),
+        });
+
+        let err = parsed.unwrap_err();
+        assert_eq!(4, err.line);
+        assert_eq!("c", err.snippet);
+        assert_eq!(1, err.source);
+    }
+
+    #[test]
+    fn test_try_split_blocks_count() {
+        let err = try_split_blocks::<2>("a\n\nb\n\nc").unwrap_err();
+        assert_eq!(
+            BlockCountError {
+                expected: 2,
+                found: 3
+            },
+            err
+        );
+    }
+}
@@ -0,0 +1,254 @@
+use std::hash::Hash;
+
+use crate::hashing::FxHashMap;
+
+/// A cycle in the sequence of states `x0, f(x0), f(f(x0)), ...`
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Cycle {
+    /// The index of the first state that is part of the cycle
+    pub mu: usize,
+    /// The number of steps after which the states start to repeat
+    pub lambda: usize,
+}
+
+impl Cycle {
+    /// Returns the smallest step which has the same state as step `n`
+    #[inline(always)]
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.mu {
+            return n;
+        }
+
+        self.mu + (n - self.mu) % self.lambda
+    }
+
+    /// Returns how many more steps are needed to get from step `current`
+    /// to a state that is equal to the one at step `target`. The `current`
+    /// step must already be inside the cycle.
+    #[inline(always)]
+    pub fn remaining(&self, current: usize, target: usize) -> usize {
+        debug_assert!(current >= self.mu && target >= current);
+        (target - current) % self.lambda
+    }
+
+    /// Returns how many full cycles fit between steps `current` and `target`
+    #[inline(always)]
+    pub fn repetitions(&self, current: usize, target: usize) -> usize {
+        debug_assert!(target >= current);
+        (target - current) / self.lambda
+    }
+}
+
+/// Floyd's "tortoise and hare" cycle detection. It needs only two states
+/// in memory, but calls the step function more often than Brent's algorithm.
+pub fn floyd<S, F>(initial: S, mut step: F) -> Cycle
+where
+    S: Eq,
+    F: FnMut(&S) -> S,
+{
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let next = step(&hare);
+        hare = step(&next);
+    }
+
+    let mut mu = 0;
+    let mut tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        mu += 1;
+    }
+
+    let mut lambda = 1;
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        lambda += 1;
+    }
+
+    Cycle { mu, lambda }
+}
+
+/// Brent's cycle detection. Like Floyd's algorithm it keeps only two states
+/// in memory, but it finds the cycle length with fewer state transitions.
+pub fn brent<S, F>(initial: S, mut step: F) -> Cycle
+where
+    S: Eq + Clone,
+    F: FnMut(&S) -> S,
+{
+    let mut power = 1;
+    let mut lambda = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+
+    while tortoise != hare {
+        if power == lambda {
+            tortoise = hare.clone();
+            power *= 2;
+            lambda = 0;
+        }
+
+        hare = step(&hare);
+        lambda += 1;
+    }
+
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..lambda {
+        hare = step(&hare);
+    }
+
+    let mut mu = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        mu += 1;
+    }
+
+    Cycle { mu, lambda }
+}
+
+/// Hash based cycle detection, which remembers the key of every visited state.
+/// The state is modified in-place and when the function returns it's left at
+/// step `mu + lambda`, i.e. at the first repetition of the state at step `mu`.
+///
+/// The `key` projection allows the state to carry data that does not take part
+/// in the comparison (i.e. a counter that grows with each step), as long as the
+/// projected part fully determines the future projected values.
+pub fn find_by_key<S, K, F, P>(state: &mut S, mut step: F, mut key: P) -> Cycle
+where
+    K: Hash + Eq,
+    F: FnMut(&mut S),
+    P: FnMut(&S) -> K,
+{
+    let mut seen = FxHashMap::default();
+    seen.insert(key(state), 0);
+
+    let mut n = 0;
+    loop {
+        step(state);
+        n += 1;
+
+        if let Some(mu) = seen.insert(key(state), n) {
+            return Cycle { mu, lambda: n - mu };
+        }
+    }
+}
+
+/// Computes the state after `n` steps, without actually doing all of them
+pub fn state_at<S, F>(initial: S, mut step: F, n: usize) -> S
+where
+    S: Eq + Clone,
+    F: FnMut(&S) -> S,
+{
+    let cycle = brent(initial.clone(), &mut step);
+
+    let mut state = initial;
+    for _ in 0..cycle.reduce(n) {
+        state = step(&state);
+    }
+
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 3
+    fn rho(x: &u32) -> u32 {
+        if *x == 6 {
+            3
+        } else {
+            x + 1
+        }
+    }
+
+    fn lcg(x: &u64) -> u64 {
+        (x * x + 1) % 255
+    }
+
+    #[test]
+    fn test_floyd() {
+        assert_eq!(Cycle { mu: 3, lambda: 4 }, floyd(0, rho));
+        assert_eq!(Cycle { mu: 0, lambda: 4 }, floyd(3, rho));
+    }
+
+    #[test]
+    fn test_brent() {
+        assert_eq!(Cycle { mu: 3, lambda: 4 }, brent(0, rho));
+        assert_eq!(Cycle { mu: 0, lambda: 4 }, brent(3, rho));
+    }
+
+    #[test]
+    fn test_find_by_key() {
+        let mut state = 0;
+        let cycle = find_by_key(&mut state, |x| *x = rho(x), |&x| x);
+        assert_eq!(Cycle { mu: 3, lambda: 4 }, cycle);
+        assert_eq!(3, state);
+    }
+
+    #[test]
+    fn test_find_by_key_with_projection() {
+        // (step counter, value) - the counter must not prevent the detection
+        let mut state = (0u32, 0u32);
+        let cycle = find_by_key(
+            &mut state,
+            |(n, x)| {
+                *n += 1;
+                *x = rho(x);
+            },
+            |&(_, x)| x,
+        );
+
+        assert_eq!(Cycle { mu: 3, lambda: 4 }, cycle);
+        assert_eq!((7, 3), state);
+    }
+
+    #[test]
+    fn test_algorithms_agree() {
+        for initial in 0..255 {
+            let a = floyd(initial, lcg);
+            let b = brent(initial, lcg);
+
+            let mut state = initial;
+            let c = find_by_key(&mut state, |x| *x = lcg(x), |&x| x);
+
+            assert_eq!(a, b);
+            assert_eq!(a, c);
+        }
+    }
+
+    #[test]
+    fn test_state_at() {
+        for n in 0..1000 {
+            let mut expected = 42;
+            for _ in 0..n {
+                expected = lcg(&expected);
+            }
+
+            assert_eq!(expected, state_at(42, lcg, n));
+        }
+    }
+
+    #[test]
+    fn test_reduce() {
+        let cycle = Cycle { mu: 3, lambda: 4 };
+        assert_eq!(2, cycle.reduce(2));
+        assert_eq!(3, cycle.reduce(3));
+        assert_eq!(6, cycle.reduce(6));
+        assert_eq!(3, cycle.reduce(7));
+        assert_eq!(5, cycle.reduce(1_000_000_000 + 1));
+    }
+
+    #[test]
+    fn test_remaining() {
+        let cycle = Cycle { mu: 3, lambda: 4 };
+        assert_eq!(0, cycle.remaining(7, 7));
+        assert_eq!(1, cycle.remaining(7, 12));
+        assert_eq!(1, cycle.repetitions(7, 12));
+    }
+}
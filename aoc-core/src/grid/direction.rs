@@ -10,9 +10,9 @@ impl Direction {
     #[inline(always)]
     pub fn apply(self, r: usize, c: usize) -> Option<(usize, usize)> {
         match self {
-            Direction::Up => r.checked_sub(1).and_then(|r| Some((r, c))),
+            Direction::Up => r.checked_sub(1).map(|r| (r, c)),
             Direction::Down => Some((r + 1, c)),
-            Direction::Left => c.checked_sub(1).and_then(|c| Some((r, c))),
+            Direction::Left => c.checked_sub(1).map(|c| (r, c)),
             Direction::Right => Some((r, c + 1)),
         }
     }
@@ -1,5 +1,7 @@
 use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
 
+use crate::grid::Direction;
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct Point {
     pub x: i64,
@@ -66,6 +68,18 @@ impl Point {
     }
 }
 
+impl From<Direction> for Point {
+    #[inline(always)]
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::Up => Point::UP,
+            Direction::Down => Point::DOWN,
+            Direction::Left => Point::LEFT,
+            Direction::Right => Point::RIGHT,
+        }
+    }
+}
+
 impl Add for Point {
     type Output = Self;
 
@@ -125,3 +139,38 @@ impl MulAssign<i64> for Point {
         self.y *= rhs;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotations() {
+        let mut point = Point::new(3, -2);
+        for _ in 0..4 {
+            assert_eq!(point, point.rotr().rotl());
+            point = point.rotr();
+        }
+        assert_eq!(Point::new(3, -2), point);
+
+        let mut dir = Direction::Up;
+        for _ in 0..4 {
+            assert_eq!(dir, dir.rotl().rotr());
+            assert_eq!(Point::from(dir).rotr(), Point::from(dir.rotl()));
+            dir = dir.rotr();
+        }
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut p = Point::new(2, 3) + Point::RIGHT * 4 - Point::DOWN;
+        assert_eq!(Point::new(6, 2), p);
+
+        p -= Point::new(6, 0);
+        p *= 3;
+        assert_eq!(Point::new(0, 6), p);
+        assert_eq!(7, p.manhattan(Point::new(1, 0)));
+        assert_eq!(Ok(Point::LEFT), Point::from_direction("W"));
+        assert!(Point::from_direction("X").is_err());
+    }
+}
@@ -0,0 +1,97 @@
+use std::hash::{BuildHasher, BuildHasherDefault, Hasher};
+use std::marker::PhantomData;
+
+pub use rustc_hash::FxHasher;
+
+pub type FxHashBuilder = rustc_hash::FxBuildHasher;
+pub type FxHashMap<K, V> = rustc_hash::FxHashMap<K, V>;
+pub type FxHashSet<T> = rustc_hash::FxHashSet<T>;
+
+pub type FnvHasherBuilder = BuildHasherDefault<FnvHasher>;
+pub type FnvHashMap<K, V> = std::collections::HashMap<K, V, FnvHasherBuilder>;
+pub type FnvHashSet<T> = std::collections::HashSet<T, FnvHasherBuilder>;
+
+#[derive(Debug, Copy, Clone)]
+pub struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    #[inline(always)]
+    fn default() -> FnvHasher {
+        FnvHasher(0xcbf29ce484222325)
+    }
+}
+
+impl Hasher for FnvHasher {
+    #[inline(always)]
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        let FnvHasher(mut hash) = *self;
+        for byte in bytes {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+        *self = FnvHasher(hash);
+    }
+}
+
+/// Builds any default-constructible hasher, e.g. `HashMap<K, V, HashBuilder<FnvHasher>>`
+#[derive(Copy, Clone)]
+pub struct HashBuilder<H> {
+    _phantom: PhantomData<H>,
+}
+
+impl<H: Hasher + Default> BuildHasher for HashBuilder<H> {
+    type Hasher = H;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        H::default()
+    }
+}
+
+impl<H: Hasher + Default> Default for HashBuilder<H> {
+    fn default() -> Self {
+        HashBuilder {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn test_fnv_hasher() {
+        // FNV-1a test vectors
+        let mut hasher = FnvHasher::default();
+        assert_eq!(0xcbf29ce484222325, hasher.finish());
+
+        hasher.write(b"a");
+        assert_eq!(0xaf63dc4c8601ec8c, hasher.finish());
+
+        let mut hasher = FnvHasher::default();
+        hasher.write(b"foobar");
+        assert_eq!(0x85944171f73967e8, hasher.finish());
+    }
+
+    #[test]
+    fn test_hash_builder() {
+        let mut fnv: HashMap<&str, u32, HashBuilder<FnvHasher>> = HashMap::default();
+        fnv.insert("x", 1);
+
+        let mut fx: HashMap<&str, u32, HashBuilder<FxHasher>> = HashMap::default();
+        fx.insert("x", 1);
+
+        assert_eq!(Some(&1), fnv.get("x"));
+        assert_eq!(Some(&1), fx.get("x"));
+        assert_eq!(
+            HashBuilder::<FnvHasher>::default().hash_one(42u64),
+            FnvHasherBuilder::default().hash_one(42u64)
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+/// The environment variable pointing to a directory with the inputs of all years,
+/// laid out as `<dir>/<year>/day-<DD>.txt` and `<dir>/<year>/day-<DD>-example-<N>.txt`
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum InputKind {
+    /// The personal puzzle input
+    Real,
+    /// The n-th example from the puzzle description (1-based)
+    Example(usize),
+}
+
+/// Finds the input of a puzzle, regardless of the current working directory.
+/// Use [`input_locator!`](crate::input_locator) to create one for the calling crate.
+///
+/// The input is searched for in this order:
+/// 1. `$AOC_INPUT_DIR/<year>/day-<DD>.txt`
+/// 2. `<crate>/inputs/input.txt` (the layout of the `aoc-day-NN` crates)
+/// 3. `<crate>/../puzzle-inputs/day-<DD>.txt` (the layout of the workspaces)
+/// 4. `<crate>/puzzle-inputs/day-<DD>.txt`
+///
+/// Examples are named `example-<N>.txt` in `inputs` and `day-<DD>-example-<N>.txt` elsewhere.
+#[derive(Debug, Clone)]
+pub struct InputLocator {
+    year: u16,
+    day: u8,
+    kind: InputKind,
+    manifest_dir: PathBuf,
+    input_dir: Option<PathBuf>,
+}
+
+#[macro_export]
+macro_rules! input_locator {
+    ($year:expr, $day:expr) => {
+        $crate::input::InputLocator::new(env!("CARGO_MANIFEST_DIR"), $year, $day)
+    };
+}
+
+impl InputLocator {
+    pub fn new<P: Into<PathBuf>>(manifest_dir: P, year: u16, day: u8) -> Self {
+        Self {
+            year,
+            day,
+            kind: InputKind::Real,
+            manifest_dir: manifest_dir.into(),
+            input_dir: std::env::var_os(INPUT_DIR_ENV).map(PathBuf::from),
+        }
+    }
+
+    pub fn kind(mut self, kind: InputKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    pub fn example(self, n: usize) -> Self {
+        self.kind(InputKind::Example(n))
+    }
+
+    /// Overrides the directory from `AOC_INPUT_DIR`
+    pub fn input_dir<P: Into<PathBuf>>(mut self, dir: Option<P>) -> Self {
+        self.input_dir = dir.map(Into::into);
+        self
+    }
+
+    /// All the paths that are checked, in order
+    pub fn candidates(&self) -> Vec<PathBuf> {
+        let (local, shared) = match self.kind {
+            InputKind::Real => ("input.txt".to_owned(), format!("day-{:02}.txt", self.day)),
+            InputKind::Example(n) => (
+                format!("example-{}.txt", n),
+                format!("day-{:02}-example-{}.txt", self.day, n),
+            ),
+        };
+
+        let mut candidates = vec![];
+        if let Some(dir) = self.input_dir.as_ref() {
+            candidates.push(dir.join(self.year.to_string()).join(&shared));
+        }
+
+        candidates.push(self.manifest_dir.join("inputs").join(local));
+        if let Some(parent) = self.manifest_dir.parent() {
+            candidates.push(parent.join("puzzle-inputs").join(&shared));
+        }
+        candidates.push(self.manifest_dir.join("puzzle-inputs").join(shared));
+
+        candidates
+    }
+
+    pub fn locate(&self) -> std::io::Result<PathBuf> {
+        let candidates = self.candidates();
+        if let Some(path) = candidates.iter().find(|p| p.is_file()) {
+            return Ok(path.clone());
+        }
+
+        let searched: Vec<_> = candidates.iter().map(|p| p.display().to_string()).collect();
+        Err(std::io::Error::new(
+            ErrorKind::NotFound,
+            format!(
+                "no {:?} input for {} day {}, searched: {}",
+                self.kind,
+                self.year,
+                self.day,
+                searched.join(", ")
+            ),
+        ))
+    }
+
+    /// Loads the input and normalizes it with [`normalize_input`]
+    pub fn load(&self) -> std::io::Result<String> {
+        let path = self.locate()?;
+        let input = std::fs::read_to_string(&path)
+            .map_err(|e| std::io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+        Ok(normalize_input(&input))
+    }
+}
+
+/// Converts `\r\n` line endings to `\n` and replaces the line breaks at the end with a
+/// single one. Any other whitespace is kept, including trailing spaces on the last line,
+/// because it matters for some grids.
+pub fn normalize_input(input: &str) -> String {
+    let mut normalized = input.replace("\r\n", "\n");
+    normalized.truncate(normalized.trim_end_matches('\n').len());
+    normalized.push('\n');
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_input() {
+        assert_eq!("1\n 2 \n3\n", normalize_input("1\r\n 2 \r\n3\r\n\r\n"));
+        assert_eq!("#. \n .#\n", normalize_input("#. \n .#\n\n"));
+        assert_eq!(". .  \n", normalize_input(". .  "));
+        assert_eq!("\n", normalize_input(""));
+    }
+
+    #[test]
+    fn test_input_locator_candidates() {
+        let locator =
+            InputLocator::new("/aoc/2024/aoc-day-05", 2024, 5).input_dir(Some("/inputs"));
+        assert_eq!(
+            vec![
+                PathBuf::from("/inputs/2024/day-05.txt"),
+                PathBuf::from("/aoc/2024/aoc-day-05/inputs/input.txt"),
+                PathBuf::from("/aoc/2024/puzzle-inputs/day-05.txt"),
+                PathBuf::from("/aoc/2024/aoc-day-05/puzzle-inputs/day-05.txt"),
+            ],
+            locator.candidates()
+        );
+
+        let locator = locator.input_dir(None::<PathBuf>).example(2);
+        assert_eq!(
+            vec![
+                PathBuf::from("/aoc/2024/aoc-day-05/inputs/example-2.txt"),
+                PathBuf::from("/aoc/2024/puzzle-inputs/day-05-example-2.txt"),
+                PathBuf::from("/aoc/2024/aoc-day-05/puzzle-inputs/day-05-example-2.txt"),
+            ],
+            locator.candidates()
+        );
+    }
+
+    #[test]
+    fn test_input_locator_load() {
+        let dir = std::env::temp_dir().join(format!("aoc-input-locator-{}", std::process::id()));
+        let year_dir = dir.join("2024");
+        std::fs::create_dir_all(&year_dir).unwrap();
+        std::fs::write(year_dir.join("day-07.txt"), "1 2\r\n3 4\r\n\r\n").unwrap();
+
+        let locator = InputLocator::new("/does/not/exist", 2024, 7).input_dir(Some(&dir));
+        let loaded = locator.load();
+        let missing = locator.clone().example(1).locate();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!("1 2\n3 4\n", loaded.unwrap());
+        assert_eq!(ErrorKind::NotFound, missing.unwrap_err().kind());
+    }
+}
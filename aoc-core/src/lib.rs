@@ -0,0 +1,19 @@
+//! Utilities shared by every year. The per-year `aoc-shared` crates re-export these
+//! modules, so the solutions keep importing them from `aoc_shared`.
+
+pub mod bitset;
+pub mod blocks;
+pub mod cycle;
+pub mod grid;
+pub mod hashing;
+pub mod input;
+pub mod linalg;
+pub mod math;
+#[cfg(feature = "md5")]
+pub mod md5;
+pub mod ocr;
+pub mod parallel;
+pub mod parsing;
+pub mod scan;
+pub mod testing;
+pub mod union_find;
@@ -0,0 +1,157 @@
+use crate::linalg::Rational;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Solution {
+    /// The system has exactly one solution
+    Unique(Vec<Rational>),
+    /// The system has infinitely many solutions
+    Infinite,
+    /// The system is inconsistent
+    None,
+}
+
+/// Solves `A * x = b` with Gauss-Jordan elimination over exact fractions.
+/// All rows of `a` must have the same length - the number of unknowns.
+pub fn solve(mut a: Vec<Vec<Rational>>, mut b: Vec<Rational>) -> Solution {
+    assert_eq!(
+        a.len(),
+        b.len(),
+        "each equation must have a right-hand side"
+    );
+
+    let rows = a.len();
+    let cols = a.first().map(|r| r.len()).unwrap_or(0);
+    assert!(
+        a.iter().all(|r| r.len() == cols),
+        "all rows must have the same length"
+    );
+
+    let mut pivots = vec![];
+    let mut row = 0;
+
+    for col in 0..cols {
+        if row == rows {
+            break;
+        }
+
+        let Some(pivot) = (row..rows).find(|&r| !a[r][col].is_zero()) else {
+            // free variable
+            continue;
+        };
+
+        a.swap(row, pivot);
+        b.swap(row, pivot);
+
+        let factor = a[row][col];
+        a[row][col..].iter_mut().for_each(|x| *x /= factor);
+        b[row] /= factor;
+
+        let pivot_row = a[row].clone();
+
+        for r in 0..rows {
+            if r == row || a[r][col].is_zero() {
+                continue;
+            }
+
+            let factor = a[r][col];
+            for (x, &p) in a[r][col..].iter_mut().zip(&pivot_row[col..]) {
+                *x -= factor * p;
+            }
+
+            let delta = factor * b[row];
+            b[r] -= delta;
+        }
+
+        pivots.push(col);
+        row += 1;
+    }
+
+    // The rows below the last pivot have only zero coefficients
+    if b[row..].iter().any(|x| !x.is_zero()) {
+        return Solution::None;
+    }
+
+    if pivots.len() < cols {
+        return Solution::Infinite;
+    }
+
+    Solution::Unique(b.into_iter().take(cols).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix<const R: usize, const C: usize>(values: [[i128; C]; R]) -> Vec<Vec<Rational>> {
+        values
+            .into_iter()
+            .map(|r| r.into_iter().map(Rational::integer).collect())
+            .collect()
+    }
+
+    fn vector<const N: usize>(values: [i128; N]) -> Vec<Rational> {
+        values.into_iter().map(Rational::integer).collect()
+    }
+
+    #[test]
+    fn test_unique() {
+        // x + y + z = 6; 2y + 5z = -4; 2x + 5y - z = 27
+        let a = matrix([[1, 1, 1], [0, 2, 5], [2, 5, -1]]);
+        let b = vector([6, -4, 27]);
+
+        assert_eq!(Solution::Unique(vector([5, 3, -2])), solve(a, b));
+    }
+
+    #[test]
+    fn test_unique_fractional() {
+        // 2x = 1; 3y = 2
+        let a = matrix([[2, 0], [0, 3]]);
+        let b = vector([1, 2]);
+
+        let expected = vec![Rational::new(1, 2), Rational::new(2, 3)];
+        assert_eq!(Solution::Unique(expected), solve(a, b));
+    }
+
+    #[test]
+    fn test_needs_pivoting() {
+        let a = matrix([[0, 1], [1, 0]]);
+        let b = vector([7, 3]);
+
+        assert_eq!(Solution::Unique(vector([3, 7])), solve(a, b));
+    }
+
+    #[test]
+    fn test_overdetermined() {
+        let a = matrix([[1, 0], [0, 1], [1, 1]]);
+
+        assert_eq!(
+            Solution::Unique(vector([1, 2])),
+            solve(a.clone(), vector([1, 2, 3]))
+        );
+        assert_eq!(Solution::None, solve(a, vector([1, 2, 4])));
+    }
+
+    #[test]
+    fn test_infinite() {
+        let a = matrix([[1, 2], [2, 4]]);
+        let b = vector([3, 6]);
+
+        assert_eq!(Solution::Infinite, solve(a, b));
+    }
+
+    #[test]
+    fn test_none() {
+        let a = matrix([[1, 2], [2, 4]]);
+        let b = vector([3, 7]);
+
+        assert_eq!(Solution::None, solve(a, b));
+    }
+
+    #[test]
+    fn test_underdetermined() {
+        let a = matrix([[1, 1, 1]]);
+        let b = vector([1]);
+
+        assert_eq!(Solution::Infinite, solve(a, b));
+    }
+}
@@ -0,0 +1,6 @@
+pub use gauss::solve;
+pub use gauss::Solution;
+pub use rational::Rational;
+
+mod gauss;
+mod rational;
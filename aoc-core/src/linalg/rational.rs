@@ -0,0 +1,372 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// An exact fraction, always kept in its canonical form: the numerator and
+/// the denominator are co-prime and the denominator is always positive.
+/// The numerator is never `i128::MIN`, so that it can always be negated.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational::integer(0);
+    pub const ONE: Rational = Rational::integer(1);
+
+    /// Panics if the denominator is zero or if the fraction does not fit,
+    /// see [`Rational::checked_new`]
+    pub fn new(num: i128, den: i128) -> Self {
+        assert_ne!(0, den, "the denominator cannot be zero");
+        Self::checked_new(num, den).expect("rational overflow")
+    }
+
+    /// Returns `None` if the denominator is zero or if the canonical form does
+    /// not fit, which happens when its numerator would be `i128::MIN` or its
+    /// denominator `-i128::MIN`
+    pub fn checked_new(num: i128, den: i128) -> Option<Self> {
+        if den == 0 {
+            return None;
+        }
+
+        let g = gcd_unsigned(num.unsigned_abs(), den.unsigned_abs());
+        let num_abs = i128::try_from(num.unsigned_abs() / g).ok()?;
+        let den_abs = i128::try_from(den.unsigned_abs() / g).ok()?;
+        let sign = if (num < 0) == (den < 0) { 1 } else { -1 };
+
+        Some(Self {
+            num: sign * num_abs,
+            den: den_abs,
+        })
+    }
+
+    /// Panics if the value is `i128::MIN`
+    #[inline(always)]
+    pub const fn integer(value: i128) -> Self {
+        assert!(value != i128::MIN, "rational overflow");
+        Self { num: value, den: 1 }
+    }
+
+    #[inline(always)]
+    pub const fn numer(self) -> i128 {
+        self.num
+    }
+
+    #[inline(always)]
+    pub const fn denom(self) -> i128 {
+        self.den
+    }
+
+    #[inline(always)]
+    pub const fn is_zero(self) -> bool {
+        self.num == 0
+    }
+
+    #[inline(always)]
+    pub const fn is_integer(self) -> bool {
+        self.den == 1
+    }
+
+    #[inline(always)]
+    pub const fn to_integer(self) -> Option<i128> {
+        if self.den == 1 {
+            Some(self.num)
+        } else {
+            None
+        }
+    }
+
+    /// Panics if the value is zero
+    pub fn recip(self) -> Self {
+        Self::new(self.den, self.num)
+    }
+
+    pub fn abs(self) -> Self {
+        Self {
+            num: self.num.abs(),
+            den: self.den,
+        }
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        // Use the LCM of the denominators to keep the intermediate values small
+        let g = gcd(self.den, rhs.den);
+        let den = (self.den / g).checked_mul(rhs.den)?;
+
+        let a = self.num.checked_mul(rhs.den / g)?;
+        let b = rhs.num.checked_mul(self.den / g)?;
+
+        Self::checked_new(a.checked_add(b)?, den)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(-rhs)
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        // Cross-reduce before multiplying, so that the result is already canonical
+        let g1 = gcd(self.num, rhs.den);
+        let g2 = gcd(rhs.num, self.den);
+
+        let num = (self.num / g1).checked_mul(rhs.num / g2)?;
+        let den = (self.den / g2).checked_mul(rhs.den / g1)?;
+        if num == i128::MIN {
+            return None;
+        }
+
+        Some(Self { num, den })
+    }
+
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.num == 0 {
+            return None;
+        }
+
+        self.checked_mul(rhs.recip())
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Self::integer(value as i128)
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(value: i128) -> Self {
+        Self::integer(value)
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.den == other.den {
+            return self.num.cmp(&other.num);
+        }
+
+        // a/b ? c/d <=> a*d ? c*b, because both denominators are positive
+        match (
+            self.num.checked_mul(other.den),
+            other.num.checked_mul(self.den),
+        ) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            _ => cmp_fractions(self.num, self.den, other.num, other.den),
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Self {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).expect("rational overflow")
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs).expect("rational overflow")
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs).expect("rational overflow")
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        assert!(!rhs.is_zero(), "division by zero");
+        self.checked_div(rhs).expect("rational overflow")
+    }
+}
+
+impl AddAssign for Rational {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Rational {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Rational {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for Rational {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+// Compares a/b and c/d (with positive denominators) without overflowing,
+// by comparing the integer parts and then the reciprocals of the remainders
+fn cmp_fractions(a: i128, b: i128, c: i128, d: i128) -> Ordering {
+    let (qa, ra) = (a.div_euclid(b), a.rem_euclid(b));
+    let (qc, rc) = (c.div_euclid(d), c.rem_euclid(d));
+
+    if qa != qc {
+        return qa.cmp(&qc);
+    }
+
+    match (ra == 0, rc == 0) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => cmp_fractions(d, rc, b, ra),
+    }
+}
+
+// The numerators are never `i128::MIN`, so the GCD always fits
+fn gcd(a: i128, b: i128) -> i128 {
+    gcd_unsigned(a.unsigned_abs(), b.unsigned_abs()) as i128
+}
+
+fn gcd_unsigned(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let temp = b;
+        b = a % b;
+        a = temp;
+    }
+
+    // gcd(0, 0) is 0, but we never want to divide by it
+    a.max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_form() {
+        let r = Rational::new(6, -4);
+        assert_eq!(-3, r.numer());
+        assert_eq!(2, r.denom());
+
+        assert_eq!(Rational::ZERO, Rational::new(0, -5));
+        assert_eq!(Rational::new(1, 2), Rational::new(-7, -14));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_denominator() {
+        let _ = Rational::new(1, 0);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Rational::new(1, 2);
+        let b = Rational::new(1, 3);
+
+        assert_eq!(Rational::new(5, 6), a + b);
+        assert_eq!(Rational::new(1, 6), a - b);
+        assert_eq!(Rational::new(1, 6), a * b);
+        assert_eq!(Rational::new(3, 2), a / b);
+        assert_eq!(Rational::new(-1, 2), -a);
+        assert_eq!(Rational::integer(2), a.recip());
+    }
+
+    #[test]
+    fn test_overflow() {
+        let big = Rational::integer(i128::MAX);
+        assert_eq!(None, big.checked_add(Rational::ONE));
+        assert_eq!(None, big.checked_mul(Rational::integer(2)));
+        assert_eq!(None, Rational::ONE.checked_div(Rational::ZERO));
+
+        // cross-reduction avoids the overflow
+        let x = Rational::new(i128::MAX, 3);
+        assert_eq!(
+            Some(Rational::integer(i128::MAX)),
+            x.checked_mul(Rational::integer(3))
+        );
+    }
+
+    #[test]
+    fn test_min_numerator() {
+        let half_min = Rational::new(i128::MIN, 2);
+        assert_eq!(i128::MIN / 2, half_min.numer());
+        assert_eq!(Rational::ONE, Rational::new(i128::MIN, i128::MIN));
+
+        assert_eq!(None, Rational::checked_new(i128::MIN, 1));
+        assert_eq!(None, Rational::checked_new(1, i128::MIN));
+        assert_eq!(None, half_min.checked_mul(Rational::integer(2)));
+        assert_eq!(None, half_min.checked_add(half_min));
+        assert_eq!(
+            Rational::integer(i128::MAX),
+            Rational::new(-i128::MAX, 1).abs()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_min_integer() {
+        let _ = Rational::integer(i128::MIN);
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(Rational::new(1, 3) < Rational::new(1, 2));
+        assert!(Rational::new(-1, 2) < Rational::new(-1, 3));
+        assert!(Rational::integer(i128::MAX) > Rational::new(i128::MAX - 1, 2));
+    }
+
+    #[test]
+    fn test_integer() {
+        assert_eq!(Some(3), Rational::new(6, 2).to_integer());
+        assert_eq!(None, Rational::new(6, 4).to_integer());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("3", Rational::integer(3).to_string());
+        assert_eq!("-3/4", Rational::new(3, -4).to_string());
+    }
+}
@@ -0,0 +1,272 @@
+pub fn gcd(a: u64, b: u64) -> u64 {
+    let mut a = a;
+    let mut b = b;
+
+    while b != 0 {
+        let temp = b;
+        b = a % b;
+        a = temp;
+    }
+
+    a
+}
+
+/// Returns `None` if either number is 0 or if the result does not fit in an `u64`
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return None; // LCM is undefined if either number is 0
+    }
+
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// The LCM of all values. Returns `None` on empty input, zero values or overflow
+pub fn lcm_all<I: IntoIterator<Item = u64>>(values: I) -> Option<u64> {
+    let mut values = values.into_iter();
+    let first = values.next()?;
+
+    values
+        .try_fold(first, lcm)
+        .and_then(|x| (x != 0).then_some(x))
+}
+
+/// Extended Euclidean algorithm. Returns `(g, x, y)` such that `a*x + b*y = g`,
+/// where `g` is the (non-negative) GCD of `a` and `b`
+pub fn ext_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 {
+        return (-old_r, -old_x, -old_y);
+    }
+
+    (old_r, old_x, old_y)
+}
+
+/// The modular multiplicative inverse of `a` modulo `m`, in the range `[0, m)`.
+/// Returns `None` if `a` and `m` are not co-prime.
+pub fn mod_inv(a: i128, m: i128) -> Option<i128> {
+    if m <= 0 {
+        return None;
+    }
+
+    let (g, x, _) = ext_gcd(a.rem_euclid(m), m);
+    if g != 1 {
+        return None;
+    }
+
+    Some(x.rem_euclid(m))
+}
+
+/// Generalized Chinese Remainder Theorem. Takes a list of `(residue, modulus)`
+/// congruences (the moduli do not have to be co-prime) and returns the smallest
+/// non-negative `(x, lcm)` such that `x = residue (mod modulus)` for all of them.
+/// Returns `None` if the system has no solution or if the LCM overflows.
+pub fn crt<I: IntoIterator<Item = (i128, i128)>>(congruences: I) -> Option<(i128, i128)> {
+    let mut x = 0i128;
+    let mut m = 1i128;
+
+    for (r, n) in congruences {
+        if n <= 0 {
+            return None;
+        }
+
+        let r = r.rem_euclid(n);
+        let (g, p, _) = ext_gcd(m, n);
+        if (r - x) % g != 0 {
+            return None;
+        }
+
+        // x + m * k = r (mod n) => k = (r - x) / g * p (mod n / g)
+        let step = n / g;
+        let k = mul_mod_signed((r - x) / g, p, step);
+
+        let lcm = m.checked_mul(step)?;
+        x = (x + m.checked_mul(k)?).rem_euclid(lcm);
+        m = lcm;
+    }
+
+    Some((x, m))
+}
+
+/// Computes `(a * b) % m` without overflowing. Panics if `m` is zero.
+pub fn mulmod(a: u128, b: u128, m: u128) -> u128 {
+    assert_ne!(0, m, "the modulus cannot be zero");
+
+    let mut a = a % m;
+    let mut b = b % m;
+
+    if let Some(x) = a.checked_mul(b) {
+        return x % m;
+    }
+
+    let mut result = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = addmod(result, a, m);
+        }
+
+        a = addmod(a, a, m);
+        b >>= 1;
+    }
+
+    result
+}
+
+/// Computes `(base ^ exp) % m` without overflowing. Panics if `m` is zero.
+pub fn powmod(base: u128, exp: u128, m: u128) -> u128 {
+    assert_ne!(0, m, "the modulus cannot be zero");
+
+    if m == 1 {
+        return 0;
+    }
+
+    let mut base = base % m;
+    let mut exp = exp;
+    let mut result = 1;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mulmod(result, base, m);
+        }
+
+        base = mulmod(base, base, m);
+        exp >>= 1;
+    }
+
+    result
+}
+
+#[inline(always)]
+fn addmod(a: u128, b: u128, m: u128) -> u128 {
+    // both `a` and `b` are less than `m`
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+fn mul_mod_signed(a: i128, b: i128, m: i128) -> i128 {
+    let a = a.rem_euclid(m) as u128;
+    let b = b.rem_euclid(m) as u128;
+    mulmod(a, b, m as u128) as i128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(Some(12), lcm(4, 6));
+        assert_eq!(None, lcm(0, 6));
+        assert_eq!(None, lcm(u64::MAX, u64::MAX - 1));
+        assert_eq!(Some(u64::MAX), lcm(u64::MAX, u64::MAX));
+    }
+
+    #[test]
+    fn test_lcm_all() {
+        assert_eq!(Some(2520), lcm_all(1..=10));
+        assert_eq!(None, lcm_all([]));
+        assert_eq!(None, lcm_all([3, 0, 5]));
+        assert_eq!(None, lcm_all([u64::MAX, 2]));
+    }
+
+    #[test]
+    fn test_ext_gcd() {
+        for a in -50..50 {
+            for b in -50..50 {
+                let (g, x, y) = ext_gcd(a, b);
+                assert_eq!(
+                    g,
+                    gcd(a.unsigned_abs() as u64, b.unsigned_abs() as u64) as i128
+                );
+                assert_eq!(g, a * x + b * y);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mod_inv() {
+        assert_eq!(Some(4), mod_inv(3, 11));
+        assert_eq!(Some(7), mod_inv(-3, 11));
+        assert_eq!(None, mod_inv(4, 8));
+
+        for m in 2..100 {
+            for a in 0..m {
+                if let Some(inv) = mod_inv(a, m) {
+                    assert_eq!(1, a * inv % m);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_crt_coprime() {
+        assert_eq!(Some((23, 105)), crt([(2, 3), (3, 5), (2, 7)]));
+        assert_eq!(Some((0, 1)), crt([]));
+    }
+
+    #[test]
+    fn test_crt_not_coprime() {
+        assert_eq!(Some((10, 12)), crt([(2, 4), (4, 6)]));
+        assert_eq!(None, crt([(1, 4), (2, 6)]));
+    }
+
+    #[test]
+    fn test_crt_brute_force() {
+        for a in 1..12 {
+            for b in 1..12 {
+                for ra in 0..a {
+                    for rb in 0..b {
+                        let expected = (0..a * b).find(|x| x % a == ra && x % b == rb);
+                        let actual = crt([(ra, a), (rb, b)]).map(|(x, _)| x);
+                        assert_eq!(expected, actual);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_mulmod() {
+        assert_eq!(6, mulmod(3, 2, 7));
+        assert_eq!(1, mulmod(u128::MAX - 1, u128::MAX - 1, u128::MAX));
+
+        let m = (1u128 << 127) + 1;
+        assert_eq!(m - 1, mulmod(m - 1, 1, m));
+        assert_eq!(1, mulmod(m - 1, m - 1, m));
+    }
+
+    #[test]
+    fn test_powmod() {
+        assert_eq!(1024 % 1000, powmod(2, 10, 1000));
+        assert_eq!(0, powmod(5, 3, 1));
+        assert_eq!(1, powmod(5, 0, 7));
+
+        // Fermat's little theorem with the Mersenne prime 2^127 - 1
+        let p = (1u128 << 127) - 1;
+        assert_eq!(1, powmod(3, p - 1, p));
+    }
+
+    #[test]
+    #[should_panic(expected = "the modulus cannot be zero")]
+    fn test_mulmod_zero_modulus() {
+        mulmod(3, 2, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "the modulus cannot be zero")]
+    fn test_powmod_zero_modulus() {
+        powmod(5, 0, 0);
+    }
+}
@@ -0,0 +1,293 @@
+//! MD5 helpers for the puzzles that look for hashes of a secret followed by a
+//! counter. The counters are hashed in batches with [`crate::parallel`], so the
+//! searches use all cores when the `parallel` feature is enabled.
+
+use std::collections::VecDeque;
+
+use md5::{Digest as _, Md5};
+
+use crate::parallel;
+
+pub type Digest = [u8; 16];
+
+/// A hash in lowercase hexadecimal
+pub type HexDigest = [u8; 32];
+
+// Each batch hashes `SEGMENTS` runs of `SEGMENT_LEN` consecutive counters, one run per task
+const SEGMENT_LEN: u64 = 2048;
+const SEGMENTS: u64 = 32;
+
+// The number of stretched hashes computed at once by `StretchedHashes`
+const STRETCH_BATCH: u64 = 256;
+
+const HEX: &[u8; 16] = b"0123456789abcdef";
+
+/// The MD5 state after hashing a fixed prefix, so that it is hashed only once no
+/// matter how many suffixes are tried
+#[derive(Clone)]
+pub struct Prefix {
+    state: Md5,
+}
+
+impl Prefix {
+    pub fn new<P: AsRef<[u8]>>(prefix: P) -> Self {
+        Self {
+            state: Md5::new_with_prefix(prefix),
+        }
+    }
+
+    pub fn hash<S: AsRef<[u8]>>(&self, suffix: S) -> Digest {
+        let mut state = self.state.clone();
+        state.update(suffix);
+        state.finalize().into()
+    }
+
+    /// Hashes the prefix followed by the decimal representation of `counter`
+    pub fn hash_counter(&self, counter: u64) -> Digest {
+        let mut buffer = [0; 20];
+        self.hash(write_decimal(counter, &mut buffer))
+    }
+}
+
+/// The number of leading zeros in the hexadecimal representation of the digest
+pub fn leading_zero_nibbles(digest: &Digest) -> u32 {
+    let bits = u128::from_be_bytes(*digest).leading_zeros();
+    bits / 4
+}
+
+/// The `idx`-th hexadecimal digit of the digest
+pub fn nibble(digest: &Digest, idx: usize) -> u8 {
+    let byte = digest[idx / 2];
+    if idx & 1 == 0 {
+        byte >> 4
+    } else {
+        byte & 0x0F
+    }
+}
+
+pub fn to_hex(digest: &Digest) -> HexDigest {
+    let mut hex = [0; 32];
+    for (idx, byte) in digest.iter().enumerate() {
+        hex[idx * 2] = HEX[(byte >> 4) as usize];
+        hex[idx * 2 + 1] = HEX[(byte & 0x0F) as usize];
+    }
+    hex
+}
+
+/// Hashes `prefix` followed by each counter from `start` onwards and yields the
+/// counters for which `filter` returns a value, in increasing order. The first N
+/// matches are simply `matches(..).take(N)`.
+///
+/// The counters are hashed in batches, so `filter` may be called for counters past
+/// the last match that is consumed.
+pub fn matches<T, F>(prefix: &str, start: u64, filter: F) -> Matches<T, F>
+where
+    T: Send,
+    F: Fn(u64, &Digest) -> Option<T> + Sync + Send,
+{
+    Matches {
+        prefix: Prefix::new(prefix),
+        next: Some(start),
+        found: VecDeque::new(),
+        filter,
+    }
+}
+
+pub struct Matches<T, F> {
+    prefix: Prefix,
+    /// The first counter of the next batch, or `None` once all counters were hashed
+    next: Option<u64>,
+    found: VecDeque<(u64, T)>,
+    filter: F,
+}
+
+impl<T, F> Matches<T, F>
+where
+    T: Send,
+    F: Fn(u64, &Digest) -> Option<T> + Sync + Send,
+{
+    fn search_batch(&mut self, start: u64) {
+        let segments: Vec<u64> = (0..SEGMENTS)
+            .map_while(|idx| start.checked_add(idx * SEGMENT_LEN))
+            .collect();
+
+        let prefix = &self.prefix;
+        let filter = &self.filter;
+        let found = parallel::map(&segments, |&from| {
+            let to = from.saturating_add(SEGMENT_LEN);
+            (from..to)
+                .filter_map(|counter| {
+                    let digest = prefix.hash_counter(counter);
+                    filter(counter, &digest).map(|value| (counter, value))
+                })
+                .collect::<Vec<_>>()
+        });
+
+        self.found.extend(found.into_iter().flatten());
+        self.next = start.checked_add(SEGMENTS * SEGMENT_LEN);
+    }
+}
+
+impl<T, F> Iterator for Matches<T, F>
+where
+    T: Send,
+    F: Fn(u64, &Digest) -> Option<T> + Sync + Send,
+{
+    type Item = (u64, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.found.is_empty() {
+            let start = self.next?;
+            self.search_batch(start);
+        }
+
+        self.found.pop_front()
+    }
+}
+
+/// Memoized "stretched" hashes: the hexadecimal hash of the prefix and the index,
+/// rehashed `rounds` more times. Missing hashes are computed in batches ahead of the
+/// requested index, so scanning a window of the next hashes is cheap. The hashes
+/// before the window can be dropped with `evict_before`.
+pub struct StretchedHashes {
+    prefix: Prefix,
+    rounds: usize,
+    /// The index of the first cached hash
+    start: u64,
+    hashes: VecDeque<HexDigest>,
+}
+
+impl StretchedHashes {
+    pub fn new(prefix: &str, rounds: usize) -> Self {
+        Self {
+            prefix: Prefix::new(prefix),
+            rounds,
+            start: 0,
+            hashes: VecDeque::new(),
+        }
+    }
+
+    /// The stretched hash of `index`, which must not have been evicted
+    pub fn get(&mut self, index: u64) -> &HexDigest {
+        assert!(index >= self.start, "hash {} was evicted", index);
+
+        while self.start + self.hashes.len() as u64 <= index {
+            let from = self.start + self.hashes.len() as u64;
+            let indices: Vec<u64> = (from..from + STRETCH_BATCH).collect();
+            let hashes = parallel::map(&indices, |&idx| self.stretch(idx));
+            self.hashes.extend(hashes);
+        }
+
+        &self.hashes[(index - self.start) as usize]
+    }
+
+    /// Drops the hashes before `index`
+    pub fn evict_before(&mut self, index: u64) {
+        let count = index
+            .saturating_sub(self.start)
+            .min(self.hashes.len() as u64);
+        self.hashes.drain(..count as usize);
+        self.start += count;
+    }
+
+    fn stretch(&self, index: u64) -> HexDigest {
+        let mut hex = to_hex(&self.prefix.hash_counter(index));
+        for _ in 0..self.rounds {
+            hex = to_hex(&Md5::digest(hex).into());
+        }
+        hex
+    }
+}
+
+fn write_decimal(mut value: u64, buffer: &mut [u8; 20]) -> &[u8] {
+    let mut idx = buffer.len();
+    loop {
+        idx -= 1;
+        buffer[idx] = b'0' + (value % 10) as u8;
+        value /= 10;
+
+        if value == 0 {
+            return &buffer[idx..];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_counter() {
+        let prefix = Prefix::new("abcdef");
+        let digest = prefix.hash_counter(609043);
+
+        assert_eq!(b"000001dbbfa3a5c83a2d506429c7b00e", &to_hex(&digest));
+        assert_eq!(5, leading_zero_nibbles(&digest));
+        assert_eq!(1, nibble(&digest, 5));
+        assert_eq!(0xd, nibble(&digest, 6));
+    }
+
+    #[test]
+    fn test_write_decimal() {
+        let mut buffer = [0; 20];
+        assert_eq!(b"0", write_decimal(0, &mut buffer));
+        assert_eq!(b"1234567", write_decimal(1234567, &mut buffer));
+        assert_eq!(
+            u64::MAX.to_string().as_bytes(),
+            write_decimal(u64::MAX, &mut buffer)
+        );
+    }
+
+    #[test]
+    fn test_matches_in_order() {
+        let prefix = Prefix::new("abc");
+        let expected: Vec<u64> = (0..)
+            .filter(|&counter| leading_zero_nibbles(&prefix.hash_counter(counter)) >= 3)
+            .take(20)
+            .collect();
+
+        let found: Vec<u64> = matches("abc", 0, |_, digest| {
+            (leading_zero_nibbles(digest) >= 3).then_some(())
+        })
+        .take(20)
+        .map(|(counter, _)| counter)
+        .collect();
+
+        assert_eq!(expected, found);
+    }
+
+    #[test]
+    fn test_matches_start() {
+        // The first character of the example door password
+        let mut found = matches("abc", 3231900, |_, digest| {
+            (leading_zero_nibbles(digest) >= 5).then(|| nibble(digest, 5))
+        });
+
+        assert_eq!(Some((3231929, 1)), found.next());
+    }
+
+    #[test]
+    fn test_stretched_hashes() {
+        let mut hashes = StretchedHashes::new("abc", 2016);
+        assert_eq!(b"a107ff634856bb300138cac6568c0f24", hashes.get(0));
+
+        let mut plain = StretchedHashes::new("abc", 0);
+        assert_eq!(&to_hex(&Prefix::new("abc18").hash("")), plain.get(18));
+        assert!(plain.get(18).windows(3).any(|w| w == b"888"));
+
+        plain.evict_before(100);
+        assert_eq!(
+            &to_hex(&Prefix::new("abc").hash_counter(100)),
+            plain.get(100)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_evicted_hash() {
+        let mut hashes = StretchedHashes::new("abc", 0);
+        hashes.get(10);
+        hashes.evict_before(5);
+        hashes.get(4);
+    }
+}
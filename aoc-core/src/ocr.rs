@@ -0,0 +1,231 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+// The 4x6 font used by most puzzles that draw letters on a screen
+const SMALL_LETTERS: &str = "ABCEFGHIJKLOPRSUYZ";
+const SMALL_FONT: [&str; 6] = [
+    ".##..###...##..####.####..##..#..#.###...##.#..#.#.....##..###..###...###.#..#.#...#.####",
+    "#..#.#..#.#..#.#....#....#..#.#..#..#.....#.#.#..#....#..#.#..#.#..#.#....#..#.#...#....#",
+    "#..#.###..#....###..###..#....####..#.....#.##...#....#..#.#..#.#..#.#....#..#..#.#....#.",
+    "####.#..#.#....#....#....#.##.#..#..#.....#.#.#..#....#..#.###..###...##..#..#...#....#..",
+    "#..#.#..#.#..#.#....#....#..#.#..#..#..#..#.#.#..#....#..#.#....#.#.....#.#..#...#...#...",
+    "#..#.###...##..####.#.....###.#..#.###..##..#..#.####..##..#....#..#.###...##....#...####",
+];
+
+// The 6x10 font used by the star messages
+const LARGE_LETTERS: &str = "ABCEFGHJKLNPRXZ";
+const LARGE_FONT: [&str; 10] = [
+    "..##...#####...####..######.######..####..#....#....###.#....#.#......#....#.#####..#####..#....#.######",
+    ".#..#..#....#.#....#.#......#......#....#.#....#.....#..#...#..#......##...#.#....#.#....#.#....#......#",
+    "#....#.#....#.#......#......#......#......#....#.....#..#..#...#......##...#.#....#.#....#..#..#.......#",
+    "#....#.#....#.#......#......#......#......#....#.....#..#.#....#......#.#..#.#....#.#....#..#..#......#.",
+    "#....#.#####..#......#####..#####..#......######.....#..##.....#......#.#..#.#####..#####....##......#..",
+    "######.#....#.#......#......#......#..###.#....#.....#..##.....#......#..#.#.#......#..#.....##.....#...",
+    "#....#.#....#.#......#......#......#....#.#....#.....#..#.#....#......#..#.#.#......#...#...#..#...#....",
+    "#....#.#....#.#......#......#......#....#.#....#.#...#..#..#...#......#...##.#......#...#...#..#..#.....",
+    "#....#.#....#.#....#.#......#......#...##.#....#.#...#..#...#..#......#...##.#......#....#.#....#.#.....",
+    "#....#.#####...####..######.#.......###.#.#....#..###...#....#.######.#....#.#......#....#.#....#.######",
+];
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum OcrError {
+    /// The text is neither 6 nor 10 rows high
+    UnsupportedHeight(usize),
+    /// The glyph starting at `column` is not a known letter
+    UnknownGlyph { column: usize, glyph: String },
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OcrError::UnsupportedHeight(height) => {
+                write!(f, "letters must be 6 or 10 rows high, not {}", height)
+            }
+            OcrError::UnknownGlyph { column, glyph } => {
+                write!(f, "unknown letter at column {}:\n{}", column, glyph)
+            }
+        }
+    }
+}
+
+impl Error for OcrError {}
+
+/// Recognizes the letters drawn with `█` or `#` on a background of any other character
+pub fn recognize_text(text: &str) -> Result<String, OcrError> {
+    let grid: Vec<Vec<bool>> = text
+        .lines()
+        .map(|line| line.chars().map(|c| c == '█' || c == '#').collect())
+        .collect();
+
+    recognize(&grid)
+}
+
+/// Recognizes the letters drawn on a grid, where `true` is a lit pixel. Empty
+/// rows around the text are ignored. The letters are in cells of 5 columns for
+/// the 4x6 font and 8 columns for the 6x10 font, starting at the first column,
+/// so letters that fill their whole cell, like the small `Y`, can touch the next one.
+pub fn recognize<R: AsRef<[bool]>>(grid: &[R]) -> Result<String, OcrError> {
+    let lit_row = |row: &R| row.as_ref().iter().any(|&x| x);
+    let top = grid.iter().position(lit_row).unwrap_or(0);
+    let bottom = grid.iter().rposition(lit_row).map(|x| x + 1).unwrap_or(0);
+
+    let rows = &grid[top..bottom.max(top)];
+    let (letters, font, stride) = match rows.len() {
+        6 => (SMALL_LETTERS, &SMALL_FONT[..], 5),
+        10 => (LARGE_LETTERS, &LARGE_FONT[..], 8),
+        height => return Err(OcrError::UnsupportedHeight(height)),
+    };
+
+    let font = font_glyphs(font);
+    let width = rows.iter().map(|r| r.as_ref().len()).max().unwrap_or(0);
+    let pixel = |r: usize, c: usize| rows[r].as_ref().get(c) == Some(&true);
+    let glyphs = (0..width)
+        .step_by(stride)
+        .filter_map(|start| glyph_in(rows.len(), start..(start + stride).min(width), pixel));
+
+    let mut answer = String::new();
+    for (column, glyph) in glyphs {
+        match font.iter().position(|g| *g == glyph) {
+            Some(idx) => answer.push(letters.as_bytes()[idx] as char),
+            None => return Err(OcrError::UnknownGlyph { column, glyph }),
+        }
+    }
+
+    Ok(answer)
+}
+
+// The font tables are packed tighter than the screens, with a single empty
+// column between the letters, so they are split on the empty columns
+fn font_glyphs(font: &[&str]) -> Vec<String> {
+    let pixel = |r: usize, c: usize| font[r].as_bytes()[c] == b'#';
+    let width = font[0].len();
+
+    let mut glyphs = vec![];
+    let mut start = 0;
+    for end in (0..=width).filter(|&c| c == width || (0..font.len()).all(|r| !pixel(r, c))) {
+        glyphs.extend(glyph_in(font.len(), start..end, pixel).map(|(_, glyph)| glyph));
+        start = end + 1;
+    }
+
+    glyphs
+}
+
+// The glyph in the given columns without the empty columns around it, drawn
+// with `#` and `.`, and the column at which it starts. `None` if it's empty.
+fn glyph_in<F>(height: usize, columns: Range<usize>, pixel: F) -> Option<(usize, String)>
+where
+    F: Fn(usize, usize) -> bool,
+{
+    let lit_column = |c: &usize| (0..height).any(|r| pixel(r, *c));
+    let start = columns.clone().find(lit_column)?;
+    let end = columns.rev().find(lit_column)? + 1;
+
+    let glyph = (0..height)
+        .map(|r| {
+            (start..end)
+                .map(|c| if pixel(r, c) { '#' } else { '.' })
+                .collect()
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    Some((start, glyph))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_font() {
+        let text = "\
+            \n\
+            .##..####.###..#..#.###..####.###....##.###...###.\n\
+            #..#.#....#..#.#..#.#..#....#.#..#....#.#..#.#....\n\
+            #..#.###..###..#..#.#..#...#..###.....#.#..#.#....\n\
+            ####.#....#..#.#..#.###...#...#..#....#.###...##..\n\
+            #..#.#....#..#.#..#.#....#....#..#.#..#.#.......#.\n\
+            #..#.####.###...##..#....####.###...##..#....###..\n";
+
+        assert_eq!(Ok("AEBUPZBJPS".to_owned()), recognize_text(text));
+    }
+
+    #[test]
+    fn test_narrow_letters() {
+        let text = "\
+            ###..#...#\n\
+            .#...#...#\n\
+            .#....#.#.\n\
+            .#.....#..\n\
+            .#.....#..\n\
+            ###....#..\n";
+
+        assert_eq!(Ok("IY".to_owned()), recognize_text(text));
+    }
+
+    #[test]
+    fn test_letter_after_y() {
+        // the `Y` fills its whole cell, so there is no empty column before the `A`
+        let text = "\
+            #...#.##..###.\n\
+            #...##..#..#..\n\
+            .#.#.#..#..#..\n\
+            ..#..####..#..\n\
+            ..#..#..#..#..\n\
+            ..#..#..#.###.\n";
+
+        assert_eq!(Ok("YAI".to_owned()), recognize_text(text));
+
+        // an `I` in the first cell does not start at the first column
+        let text = "\
+            .###.#...#\n\
+            ..#..#...#\n\
+            ..#...#.#.\n\
+            ..#....#..\n\
+            ..#....#..\n\
+            .###...#..\n";
+
+        assert_eq!(Ok("IY".to_owned()), recognize_text(text));
+    }
+
+    #[test]
+    fn test_large_font() {
+        let rows = [
+            "#....#..######..#....#...####...#####",
+            "#...#........#..#....#..#....#..#....#",
+            "#..#.........#..#....#..#.......#....#",
+            "#.#.........#...#....#..#.......#....#",
+            "##.........#....######..#.......#####.",
+            "##........#.....#....#..#..###..#..#..",
+            "#.#......#......#....#..#....#..#...#.",
+            "#..#....#.......#....#..#....#..#...#.",
+            "#...#...#.......#....#..#...##..#....#",
+            "#....#..######..#....#...###.#..#....#",
+        ];
+
+        let grid: Vec<Vec<bool>> = rows
+            .iter()
+            .map(|r| r.bytes().map(|b| b == b'#').collect())
+            .collect();
+
+        assert_eq!(Ok("KZHGR".to_owned()), recognize(&grid));
+    }
+
+    #[test]
+    fn test_fonts() {
+        assert_eq!(SMALL_LETTERS.len(), font_glyphs(&SMALL_FONT).len());
+        assert_eq!(LARGE_LETTERS.len(), font_glyphs(&LARGE_FONT).len());
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(Err(OcrError::UnsupportedHeight(2)), recognize_text("#\n#"));
+
+        let text = "####\n####\n####\n####\n####\n####";
+        assert!(matches!(
+            recognize_text(text),
+            Err(OcrError::UnknownGlyph { column: 0, .. })
+        ));
+    }
+}
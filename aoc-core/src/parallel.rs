@@ -0,0 +1,94 @@
+//! Data-parallel helpers for the brute-force puzzles. They use rayon when the
+//! `parallel` feature is enabled and fall back to plain iterators otherwise, so the
+//! solutions can call them unconditionally and the threads stay opt-in.
+
+use std::ops::Range;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Whether the helpers run on multiple threads
+pub const ENABLED: bool = cfg!(feature = "parallel");
+
+/// Maps every item, keeping the order of the results
+pub fn map<T, R, M>(items: &[T], map: M) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    M: Fn(&T) -> R + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    let results = items.par_iter().map(map).collect();
+
+    #[cfg(not(feature = "parallel"))]
+    let results = items.iter().map(map).collect();
+
+    results
+}
+
+/// Maps every item, e.g. each start position of a grid, and combines the results.
+/// The order in which the results are combined is unspecified, so `reduce` must be
+/// associative. Returns `None` if there are no items.
+pub fn map_reduce<T, R, M, F>(items: &[T], map: M, reduce: F) -> Option<R>
+where
+    T: Sync,
+    R: Send,
+    M: Fn(&T) -> R + Sync + Send,
+    F: Fn(R, R) -> R + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    let result = items.par_iter().map(map).reduce_with(reduce);
+
+    #[cfg(not(feature = "parallel"))]
+    let result = items.iter().map(map).reduce(reduce);
+
+    result
+}
+
+/// Returns the result of `f` for the lowest seed for which it is `Some`. Seeds
+/// above the first match may be evaluated too, so `f` must not have side effects.
+pub fn find_first<T, F>(seeds: Range<u64>, f: F) -> Option<T>
+where
+    T: Send,
+    F: Fn(u64) -> Option<T> + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    let result = seeds.into_par_iter().find_map_first(f);
+
+    #[cfg(not(feature = "parallel"))]
+    let result = seeds.into_iter().find_map(f);
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map() {
+        let items: Vec<u32> = (0..1000).collect();
+        let squares = map(&items, |&x| x * x);
+        assert_eq!(items.iter().map(|x| x * x).collect::<Vec<_>>(), squares);
+    }
+
+    #[test]
+    fn test_map_reduce() {
+        let starts = [(0, 3), (5, 1), (2, 2)];
+        let best = map_reduce(&starts, |&(r, c)| r * 10 + c, usize::max);
+        assert_eq!(Some(51), best);
+
+        let empty: [u32; 0] = [];
+        assert_eq!(None, map_reduce(&empty, |&x| x, |a, b| a + b));
+    }
+
+    #[test]
+    fn test_find_first() {
+        let found = find_first(1..1_000_000, |seed| (seed % 7919 == 0).then_some(seed * 2));
+        assert_eq!(Some(7919 * 2), found);
+        assert_eq!(
+            None,
+            find_first(0..100, |seed| (seed > 100).then_some(seed))
+        );
+    }
+}
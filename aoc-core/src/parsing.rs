@@ -0,0 +1,168 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The error returned by the `try_` parsing functions. It points to the part
+/// of the input that could not be parsed.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError<E> {
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column (in bytes) at which the value starts
+    pub column: usize,
+    /// The value that could not be parsed
+    pub snippet: String,
+    /// The error returned by the parser of the value
+    pub source: E,
+}
+
+impl<E: Display> Display for ParseError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: cannot parse {:?}: {}",
+            self.line, self.column, self.snippet, self.source
+        )
+    }
+}
+
+impl<E: Error + 'static> Error for ParseError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl<E: Into<Box<dyn Error>>> ParseError<E> {
+    /// Boxes the source, so that errors from parsers that fail with a `String` or a
+    /// `Box<dyn Error>`, which don't implement [`Error`], can still be chained
+    pub fn into_boxed(self) -> ParseError<BoxedSource> {
+        ParseError {
+            line: self.line,
+            column: self.column,
+            snippet: self.snippet,
+            source: BoxedSource(self.source.into()),
+        }
+    }
+}
+
+/// The source of a [`ParseError`] made by [`ParseError::into_boxed`]. It is displayed
+/// as the boxed error and continues the chain with the boxed error's own source.
+#[derive(Debug)]
+pub struct BoxedSource(pub Box<dyn Error>);
+
+impl Display for BoxedSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Error for BoxedSource {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0.source()
+    }
+}
+
+/// The error returned when a grid of digits contains something else
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct NotADigit(pub char);
+
+impl Display for NotADigit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} is not a digit", self.0)
+    }
+}
+
+impl Error for NotADigit {}
+
+/// Parses the trimmed `line`, whose 0-based index in the input is `idx`
+pub fn parse_trimmed<R: FromStr>(line: &str, idx: usize) -> Result<R, ParseError<R::Err>> {
+    let value = line.trim();
+    value.parse().map_err(|source| ParseError {
+        line: idx + 1,
+        column: line.len() - line.trim_start().len() + 1,
+        snippet: value.to_owned(),
+        source,
+    })
+}
+
+/// Parses the comma-separated values of `input`, skipping the empty ones
+pub fn try_parse_csv<I, R>(input: I) -> Result<Vec<R>, ParseError<R::Err>>
+where
+    I: AsRef<str>,
+    R: FromStr,
+{
+    let input = input.as_ref();
+
+    let mut offset = 0;
+    let mut values = vec![];
+    for value in input.split(',') {
+        let trimmed = value.trim();
+        if !trimmed.is_empty() {
+            let start = offset + value.len() - value.trim_start().len();
+            let parsed = trimmed.parse().map_err(|source| {
+                // Only look for the position when it's actually needed
+                let line_start = input[..start].rfind('\n').map(|x| x + 1).unwrap_or(0);
+                ParseError {
+                    line: input[..start].matches('\n').count() + 1,
+                    column: start - line_start + 1,
+                    snippet: trimmed.to_owned(),
+                    source,
+                }
+            })?;
+            values.push(parsed);
+        }
+
+        offset += value.len() + 1;
+    }
+
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_trimmed() {
+        assert_eq!(Ok(42u32), parse_trimmed("  42 ", 0));
+
+        let err = parse_trimmed::<u32>("  x3 ", 3).unwrap_err();
+        assert_eq!((4, 3), (err.line, err.column));
+        assert_eq!("x3", err.snippet);
+    }
+
+    #[test]
+    fn test_try_parse_csv() {
+        assert_eq!(Ok(vec![1u32, 2, 3]), try_parse_csv("1, 2,,3\n"));
+
+        let err = try_parse_csv::<_, u32>("1,2,\n 3,x4").unwrap_err();
+        assert_eq!((2, 4), (err.line, err.column));
+        assert_eq!("x4", err.snippet);
+    }
+
+    #[test]
+    fn test_error_chain() {
+        let err = parse_trimmed::<u32>("12a", 0).unwrap_err();
+        assert_eq!(
+            "line 1, column 1: cannot parse \"12a\": invalid digit found in string",
+            err.to_string()
+        );
+
+        let source = err.source().unwrap();
+        assert_eq!("invalid digit found in string", source.to_string());
+        assert!(source.downcast_ref::<std::num::ParseIntError>().is_some());
+
+        let err = ParseError {
+            line: 2,
+            column: 1,
+            snippet: "?".to_owned(),
+            source: "unknown operation".to_owned(),
+        };
+        let err: Box<dyn Error> = Box::new(err.into_boxed());
+        assert_eq!(
+            "line 2, column 1: cannot parse \"?\": unknown operation",
+            err.to_string()
+        );
+        assert_eq!("unknown operation", err.source().unwrap().to_string());
+    }
+}
@@ -0,0 +1,246 @@
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::iter::Peekable;
+use std::str::{FromStr, Split};
+
+/// Matches a line against a template, where each `{}` is a field, and parses
+/// the fields into a tuple of the given types:
+///
+/// ```
+/// use aoc_core::scan;
+///
+/// let (id, x, y) = scan!("#1 @ 3,4", "#{} @ {},{}" => (u32, i64, u8)).unwrap();
+/// assert_eq!((1, 3, 4), (id, x, y));
+/// ```
+///
+/// Each field extends up to the first occurrence of the literal text following
+/// it, so two fields cannot be next to each other. The whole input must match.
+///
+/// The number of fields in the template must be the number of types, which is
+/// checked when compiling:
+///
+/// ```compile_fail
+/// use aoc_core::scan;
+///
+/// let _ = scan!("1,2", "{},{}" => (u32,));
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($input:expr, $template:literal => ($($t:ty),+ $(,)?)) => {
+        (|| -> ::std::result::Result<($($t,)+), $crate::scan::ScanError> {
+            const _: () = assert!(
+                $crate::scan::count_fields($template) == [$(stringify!($t)),+].len(),
+                "the template must have one field for each type"
+            );
+
+            let mut matcher = $crate::scan::Matcher::new(
+                ::std::convert::AsRef::<str>::as_ref(&$input),
+                $template,
+            )?;
+
+            let values = ($(matcher.field::<$t>()?,)+);
+            matcher.finish()?;
+
+            Ok(values)
+        })()
+    };
+}
+
+/// Where and why [`scan!`](crate::scan!) failed
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ScanError {
+    /// The 0-based index of the field that failed. Errors in the text
+    /// after the last field use the number of fields.
+    pub field: usize,
+    pub kind: ScanErrorKind,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ScanErrorKind {
+    /// The input does not contain the literal text around the field
+    MissingLiteral(&'static str),
+    /// The field was found, but its value could not be parsed
+    InvalidValue { value: String, reason: String },
+}
+
+impl Display for ScanError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            ScanErrorKind::MissingLiteral(literal) => {
+                write!(f, "field {}: expected {:?}", self.field, literal)
+            }
+            ScanErrorKind::InvalidValue { value, reason } => {
+                write!(
+                    f,
+                    "field {}: cannot parse {:?}: {}",
+                    self.field, value, reason
+                )
+            }
+        }
+    }
+}
+
+impl Error for ScanError {}
+
+/// The number of `{}` fields in a template
+pub const fn count_fields(template: &str) -> usize {
+    let bytes = template.as_bytes();
+    let mut count = 0;
+    let mut idx = 0;
+    while idx + 1 < bytes.len() {
+        if bytes[idx] == b'{' && bytes[idx + 1] == b'}' {
+            count += 1;
+            idx += 2;
+        } else {
+            idx += 1;
+        }
+    }
+
+    count
+}
+
+/// The runtime part of [`scan!`](crate::scan!), which checks that the template
+/// has as many fields as `field` is called.
+pub struct Matcher<'a> {
+    input: &'a str,
+    literals: Peekable<Split<'static, &'static str>>,
+    field: usize,
+}
+
+impl<'a> Matcher<'a> {
+    pub fn new(input: &'a str, template: &'static str) -> Result<Self, ScanError> {
+        let mut literals = template.split("{}").peekable();
+
+        // `split` always yields at least one item
+        let prefix = literals.next().unwrap_or_default();
+        let Some(input) = input.strip_prefix(prefix) else {
+            return Err(ScanError {
+                field: 0,
+                kind: ScanErrorKind::MissingLiteral(prefix),
+            });
+        };
+
+        Ok(Self {
+            input,
+            literals,
+            field: 0,
+        })
+    }
+
+    /// Panics if the template has no more fields
+    pub fn field<T>(&mut self) -> Result<T, ScanError>
+    where
+        T: FromStr,
+        T::Err: Debug,
+    {
+        let error = |kind| ScanError {
+            field: self.field,
+            kind,
+        };
+
+        let literal = self
+            .literals
+            .next()
+            .expect("the template has no more fields");
+
+        // The last literal must be at the very end of the input
+        let (value, rest) = if self.literals.peek().is_none() {
+            match self.input.strip_suffix(literal) {
+                Some(value) => (value, ""),
+                None => return Err(error(ScanErrorKind::MissingLiteral(literal))),
+            }
+        } else {
+            match self.input.find(literal).filter(|_| !literal.is_empty()) {
+                Some(idx) => (&self.input[..idx], &self.input[idx + literal.len()..]),
+                None => return Err(error(ScanErrorKind::MissingLiteral(literal))),
+            }
+        };
+
+        let parsed = value.parse().map_err(|e| {
+            error(ScanErrorKind::InvalidValue {
+                value: value.to_owned(),
+                reason: format!("{:?}", e),
+            })
+        })?;
+
+        self.input = rest;
+        self.field += 1;
+        Ok(parsed)
+    }
+
+    pub fn finish(mut self) -> Result<(), ScanError> {
+        debug_assert!(self.literals.next().is_none() && self.input.is_empty());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_claim() {
+        let line = "#1 @ 1,3: 4x4";
+        let parsed = scan!(line, "#{} @ {},{}: {}x{}" => (u32, u32, u32, u32, u32));
+        assert_eq!(Ok((1, 1, 3, 4, 4)), parsed);
+    }
+
+    #[test]
+    fn test_signed_and_chars() {
+        let line = String::from("p=0,4 v=3,-3");
+        let parsed = scan!(line, "p={},{} v={},{}" => (i32, i32, i32, i32));
+        assert_eq!(Ok((0, 4, 3, -3)), parsed);
+
+        let line = "Step C must be finished before step A can begin.";
+        let parsed =
+            scan!(line, "Step {} must be finished before step {} can begin." => (char, char));
+        assert_eq!(Ok(('C', 'A')), parsed);
+    }
+
+    #[test]
+    fn test_invalid_value() {
+        let err = scan!("p=0,x v=3,-3", "p={},{} v={},{}" => (i32, i32, i32, i32)).unwrap_err();
+        assert_eq!(1, err.field);
+        assert!(matches!(err.kind, ScanErrorKind::InvalidValue { ref value, .. } if value == "x"));
+    }
+
+    #[test]
+    fn test_missing_literal() {
+        let err =
+            scan!("#1 @ 1,3 4x4", "#{} @ {},{}: {}x{}" => (u32, u32, u32, u32, u32)).unwrap_err();
+        assert_eq!(
+            ScanError {
+                field: 2,
+                kind: ScanErrorKind::MissingLiteral(": ")
+            },
+            err
+        );
+
+        let err = scan!("x1", "#{}" => (u32,)).unwrap_err();
+        assert_eq!(
+            ScanError {
+                field: 0,
+                kind: ScanErrorKind::MissingLiteral("#")
+            },
+            err
+        );
+
+        let err = scan!("1.", "{}!" => (u32,)).unwrap_err();
+        assert_eq!(
+            ScanError {
+                field: 0,
+                kind: ScanErrorKind::MissingLiteral("!")
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn test_count_fields() {
+        assert_eq!(0, count_fields("no fields"));
+        assert_eq!(1, count_fields("{}"));
+        assert_eq!(2, count_fields("{},{}"));
+        assert_eq!(1, count_fields("{{}}"));
+        assert_eq!(5, count_fields("#{} @ {},{}: {}x{}"));
+    }
+}
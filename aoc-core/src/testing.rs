@@ -0,0 +1,43 @@
+//! Helpers for the tests of the solutions
+
+/// A small xorshift generator, so that randomized tests are repeatable
+#[derive(Debug, Clone)]
+pub struct XorShift(u64);
+
+impl XorShift {
+    /// Panics if the seed is zero, as the generator would only return zeros
+    pub fn new(seed: u64) -> Self {
+        assert_ne!(0, seed, "the seed cannot be zero");
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A value in `0..bound`
+    pub fn below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeatable() {
+        let mut a = XorShift::new(42);
+        let mut b = XorShift::new(42);
+        for _ in 0..100 {
+            let value = a.below(10);
+            assert!(value < 10);
+            assert_eq!(value, b.below(10));
+        }
+
+        assert_ne!(a.next_u64(), XorShift::new(43).next_u64());
+    }
+}
@@ -74,6 +74,15 @@ pub struct UnionFindAny<T> {
     sizes: FxHashMap<T, usize>,
 }
 
+impl<T> Default for UnionFindAny<T>
+where
+    T: Eq + Hash + Copy,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> UnionFindAny<T>
 where
     T: Eq + Hash + Copy,
@@ -125,7 +134,7 @@ where
     }
 
     pub fn group_size(&self, group_root: &T) -> usize {
-        self.sizes.get(&group_root).copied().unwrap_or(1)
+        self.sizes.get(group_root).copied().unwrap_or(1)
     }
 
     pub fn find_size(&mut self, key: T) -> usize {
@@ -138,6 +147,27 @@ where
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_uf() {
+        let mut uf = UnionFind::new(5);
+        assert_eq!(5, uf.number_of_groups());
+
+        assert!(uf.union(0, 1));
+        assert!(uf.union(3, 1));
+        assert!(!uf.union(0, 3));
+
+        assert_eq!(uf.find(0), uf.find(3));
+        assert_ne!(uf.find(0), uf.find(2));
+        assert_eq!(3, uf.number_of_groups());
+
+        let root = uf.find(1);
+        assert_eq!(3, uf.group_size(root));
+
+        uf.reset();
+        assert_eq!(5, uf.number_of_groups());
+        assert_ne!(uf.find(0), uf.find(1));
+    }
+
     #[test]
     fn test_uf_any() {
         let mut uf = UnionFindAny::new();